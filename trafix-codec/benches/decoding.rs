@@ -0,0 +1,52 @@
+//! Benchmark of decoding a message into typed fields and into raw fields only, and of decoding
+//! into a reused field buffer.
+//!
+//! Run with `cargo bench --bench decoding`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use trafix_codec::message::{DecodeOptions, Message, decode_into};
+
+/// Number of times the message is decoded.
+const ITERATIONS: u32 = 100_000;
+
+/// Valid `Logon` message with a mix of field types.
+const INPUT: &[u8] = b"8=FIX.4.4\x019=148\x0135=A\x0134=1080\x0149=TESTBUY1\x0152=20180920-18:14:19.508\x0156=TESTSELL1\x0111=636730640278898634\x0115=USD\x0121=2\x0138=7000\x0140=1\x0154=1\x0155=MSFT\x0160=20180920-18:14:19.492\x0110=089\x01";
+
+/// Decodes the message repeatedly with the given options and returns the elapsed time.
+fn run(options: &DecodeOptions) -> Duration {
+    let start = Instant::now();
+
+    for _ in 0..ITERATIONS {
+        black_box(Message::decode_with(black_box(INPUT), options).unwrap());
+    }
+
+    start.elapsed()
+}
+
+/// Decodes the message repeatedly into the same field buffer and returns the elapsed time.
+fn run_reused() -> Duration {
+    let mut fields = Vec::new();
+    let start = Instant::now();
+
+    for _ in 0..ITERATIONS {
+        black_box(decode_into(black_box(INPUT), &mut fields).unwrap());
+    }
+
+    start.elapsed()
+}
+
+fn main() {
+    let typed = run(&DecodeOptions::default());
+    let raw = run(&DecodeOptions {
+        raw_fields: true,
+        ..DecodeOptions::default()
+    });
+    let reused = run_reused();
+
+    println!("decoded {ITERATIONS} messages");
+    println!("  typed:  {typed:?}");
+    println!("  raw:    {raw:?}");
+    println!("  reused: {reused:?}");
+}
@@ -0,0 +1,70 @@
+//! Benchmark of encoding a stream of heartbeats with the allocating `Message::encode` and with a
+//! reusable `Encoder`, each in two passes and in a single pass (`EncodeOptions::single_pass`).
+//!
+//! Run with `cargo bench --bench encoding`.
+
+use std::hint::black_box;
+use std::time::Instant;
+
+use trafix_codec::message::{
+    EncodeOptions, Encoder, Message,
+    field::{
+        Field,
+        value::{begin_string::BeginString, msg_type::MsgType},
+    },
+};
+
+/// Number of heartbeats in the encoded stream.
+const STREAM_LEN: u64 = 100_000;
+
+fn main() {
+    let stream: Vec<_> = (1..=STREAM_LEN)
+        .map(|seq_num| {
+            Message::builder(BeginString::FIX44, MsgType::Heartbeat)
+                .with_field(Field::MsgSeqNum(seq_num))
+                .with_field(Field::SenderCompID(b"TRAFIX-SENDER".into()))
+                .with_field(Field::TargetCompID(b"TRAFIX-TARGET".into()))
+                .build()
+        })
+        .collect();
+
+    // `Message::encode` consumes the message, so cloning is part of both runs
+    let start = Instant::now();
+    for message in &stream {
+        black_box(black_box(message).clone().encode());
+    }
+    let allocating = start.elapsed();
+
+    let mut encoder = Encoder::new();
+    let start = Instant::now();
+    for message in &stream {
+        let message = black_box(message).clone();
+        black_box(encoder.encode(&message));
+    }
+    let reusable = start.elapsed();
+
+    let options = EncodeOptions {
+        single_pass: true,
+        ..EncodeOptions::default()
+    };
+
+    let start = Instant::now();
+    for message in &stream {
+        black_box(black_box(message).clone().encode_with(&options));
+    }
+    let allocating_single_pass = start.elapsed();
+
+    let mut encoder = Encoder::with_options(options);
+    let start = Instant::now();
+    for message in &stream {
+        let message = black_box(message).clone();
+        black_box(encoder.encode(&message));
+    }
+    let reusable_single_pass = start.elapsed();
+
+    println!("encoded {STREAM_LEN} heartbeats");
+    println!("  allocating:             {allocating:?}");
+    println!("  reusable:               {reusable:?}");
+    println!("  allocating single pass: {allocating_single_pass:?}");
+    println!("  reusable single pass:   {reusable_single_pass:?}");
+}
@@ -0,0 +1,52 @@
+//! Benchmark of decoding a stream of heartbeats with and without interning of comp IDs.
+//!
+//! Run with `cargo bench --bench interning`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use trafix_codec::message::{
+    Message,
+    field::{
+        Field,
+        value::{begin_string::BeginString, comp_id::Interner, msg_type::MsgType},
+    },
+};
+
+/// Number of heartbeats in the decoded stream.
+const STREAM_LEN: u64 = 100_000;
+
+/// Runs `decode` over every message of the stream and returns the elapsed time.
+fn run(stream: &[bytes::Bytes], mut decode: impl FnMut(&[u8]) -> Message) -> Duration {
+    let start = Instant::now();
+
+    for message in stream {
+        black_box(decode(black_box(message)));
+    }
+
+    start.elapsed()
+}
+
+fn main() {
+    let stream: Vec<_> = (1..=STREAM_LEN)
+        .map(|seq_num| {
+            Message::builder(BeginString::FIX44, MsgType::Heartbeat)
+                .with_field(Field::MsgSeqNum(seq_num))
+                .with_field(Field::SenderCompID(b"TRAFIX-SENDER".into()))
+                .with_field(Field::TargetCompID(b"TRAFIX-TARGET".into()))
+                .build()
+                .encode()
+        })
+        .collect();
+
+    let plain = run(&stream, |bytes| Message::decode(bytes).unwrap());
+
+    let interner = Interner::new();
+    let interned = run(&stream, |bytes| {
+        Message::decode_with_interner(bytes, &interner).unwrap()
+    });
+
+    println!("decoded {STREAM_LEN} heartbeats");
+    println!("  plain:    {plain:?}");
+    println!("  interned: {interned:?}");
+}
@@ -0,0 +1,51 @@
+//! Benchmark of a transparent proxy decoding and re-encoding messages, encoding and summing the
+//! body again with `Message::encode` and forwarding the raw body with its decode-side digest
+//! with `Message::encode_with_body_digest`.
+//!
+//! Run with `cargo bench --bench proxying`.
+
+use std::hint::black_box;
+use std::time::Instant;
+
+use trafix_codec::message::{DecodeOptions, decode_with_digest};
+
+/// Number of times the message is proxied.
+const ITERATIONS: u32 = 100_000;
+
+/// Valid `NewOrderSingle` message with a large free-text field, so summing the body dominates.
+fn input() -> Vec<u8> {
+    let body = format!(
+        "35=D\x0134=1080\x0149=TESTBUY1\x0156=TESTSELL1\x0111=636730640278898634\x0155=MSFT\x01\
+        54=1\x0140=1\x0138=7000\x0158={}\x01",
+        "x".repeat(1024)
+    );
+    let mut message = format!("8=FIX.4.4\x019={}\x01{body}", body.len()).into_bytes();
+
+    let checksum = message.iter().map(|&byte| u32::from(byte)).sum::<u32>() % 256;
+    message.extend_from_slice(format!("10={checksum:03}\x01").as_bytes());
+
+    message
+}
+
+fn main() {
+    let input = input();
+    let options = DecodeOptions::default();
+
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        let (message, _) = decode_with_digest(black_box(&input), &options).unwrap();
+        black_box(message.encode());
+    }
+    let encoding = start.elapsed();
+
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        let (message, digest) = decode_with_digest(black_box(&input), &options).unwrap();
+        black_box(message.encode_with_body_digest(&digest));
+    }
+    let forwarding = start.elapsed();
+
+    println!("proxied {ITERATIONS} messages");
+    println!("  encoding body: {encoding:?}");
+    println!("  forwarding body: {forwarding:?}");
+}
@@ -5,3 +5,79 @@ pub(crate) const SOH: u8 = b'\x01';
 
 /// ASCII equals character (=) used as delimiter between tag and value in a single field.
 pub(crate) const EQUALS: u8 = b'=';
+
+/// Tags of the framing fields (`BeginString`, `BodyLength`, `CheckSum` and `MsgType`), which the
+/// encoder emits on its own and which must not be added as regular fields.
+pub(crate) const STRUCTURAL_TAGS: &[u32] = &[8, 9, 10, 35];
+
+/// Tags of the FIX 4.4 standard header fields.
+pub(crate) const HEADER_TAGS: &[u32] = &[
+    8, 9, 35, 49, 56, 115, 128, 90, 91, 34, 50, 142, 57, 143, 116, 144, 129, 145, 43, 97, 52, 122,
+    212, 213, 347, 369, 627, 628, 629, 630,
+];
+
+/// Tags of the header fields belonging to the entries of the `NoHops` (`627`) group, which occur
+/// once per hop.
+pub(crate) const HOP_TAGS: &[u32] = &[628, 629, 630];
+
+/// Tags of the header fields required in every message besides the framing fields:
+/// `SenderCompID` (`49`), `TargetCompID` (`56`), `MsgSeqNum` (`34`) and `SendingTime` (`52`).
+pub(crate) const REQUIRED_HEADER_TAGS: &[u32] = &[49, 56, 34, 52];
+
+/// Body fields required per message type, as the wire value of `MsgType` (`35`) and the tag of
+/// the field. Used by [`decode_validated`](crate::decoder::decode_validated).
+pub(crate) const MANDATORY_BODY_FIELDS: &[(&[u8], u32)] = &[(b"D", 60), (b"F", 60), (b"G", 60)];
+
+/// Tags of the FIX 4.4 standard trailer fields.
+pub(crate) const TRAILER_TAGS: &[u32] = &[93, 89, 10];
+
+/// Pairs of length and data field tags of the standard data fields. The value of a data field
+/// may contain SOH bytes, so it spans exactly as many bytes as given by the length field that
+/// precedes it. Besides the binary data fields, this covers the `Encoded*` fields carrying text in
+/// the encoding given by the `MessageEncoding` (`347`), e.g. the `EncodedText` (`355`).
+pub(crate) const DATA_FIELDS: &[(u32, u32)] = &[
+    (90, 91),
+    (93, 89),
+    (95, 96),
+    (212, 213),
+    (348, 349),
+    (350, 351),
+    (352, 353),
+    (354, 355),
+    (356, 357),
+    (358, 359),
+    (360, 361),
+    (362, 363),
+    (364, 365),
+    (445, 446),
+    (618, 619),
+    (621, 622),
+];
+
+/// Tags of the fields of the FIX 4.4 Instrument component block, including its nested
+/// `NoSecurityAltID` (`454`) and `NoEvents` (`864`) groups.
+pub(crate) const INSTRUMENT_TAGS: &[u32] = &[
+    55, 65, 48, 22, 454, 455, 456, 460, 461, 167, 762, 200, 541, 201, 224, 225, 239, 226, 227, 228,
+    255, 543, 470, 471, 472, 240, 202, 947, 206, 231, 223, 207, 106, 348, 349, 107, 350, 351, 691,
+    667, 875, 876, 873, 874, 864, 865, 866, 867, 868,
+];
+
+/// Tags of the fields of the FIX 4.4 market data entries, as used by both full and incremental
+/// refreshes, except for the Instrument component block.
+const MD_ENTRY_TAGS: &[u32] = &[
+    279, 285, 269, 278, 280, 291, 292, 270, 15, 271, 272, 273, 274, 275, 336, 625, 276, 277, 282,
+    283, 284, 286, 59, 432, 126, 110, 18, 287, 37, 299, 288, 289, 346, 290, 546, 811, 451, 58, 354,
+    355, 387,
+];
+
+/// Pairs of the `NumInGroup` tag of the known repeating groups, and the tags of the fields
+/// belonging to each group entry, including the fields of nested groups. Each entry starts with
+/// the same field, which is the first member found after the `NumInGroup` field.
+pub(crate) const REPEATING_GROUPS: &[(u32, &[&[u32]])] = &[
+    (146, &[INSTRUMENT_TAGS]),
+    // market data entries, starting with MDUpdateAction in incremental refreshes
+    (268, &[MD_ENTRY_TAGS, INSTRUMENT_TAGS]),
+    (453, &[&[448, 447, 452, 802, 523, 803]]),
+    // hops of the standard header
+    (627, &[HOP_TAGS]),
+];
@@ -1,11 +1,19 @@
 //! Decoder for messages in FIX protocol.
 
-use crate::decoder::num::ParseFixInt as _;
-use crate::digest::Digest;
+use std::fmt;
+use std::ops::Range;
+use std::sync::Arc;
+
+use crate::decoder::num::ParseFixInt;
+use crate::digest::{BodyDigest, Digest};
 use crate::message::field::Field;
 use crate::message::field::value::FromFixBytes;
+use crate::message::field::value::aliases::MsgSeqNum;
 use crate::message::field::value::begin_string::BeginString;
+use crate::message::field::value::comp_id::Interner;
 use crate::message::field::value::msg_type::MsgType;
+use crate::message::map::FixMap;
+use crate::message::{Body, Header};
 use crate::{constants, message::Message};
 
 /// Length of the SOH character.
@@ -17,6 +25,9 @@ const EQ_LEN: usize = 1;
 /// Length of the tag for checksum ('10').
 const CKSUM_TAG_LEN: usize = 2;
 
+/// UTF-8 byte order mark.
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+
 /// Extension trait for utility functions on [`Result`] type.
 trait ResultExt<T> {
     /// Wraps the inner [`Result::Err`] with [`Error::BadValue`].
@@ -32,17 +43,75 @@ where
     }
 }
 
+/// Returns `true` if the value is not formatted as a FIX integer, i.e. as ASCII digits with an
+/// optional leading `-`.
+fn is_malformed_int(value: &[u8]) -> bool {
+    let digits = value.strip_prefix(b"-").unwrap_or(value);
+
+    digits.is_empty() || !digits.iter().all(u8::is_ascii_digit)
+}
+
+/// Parses the value of the integer field with the given tag, reporting malformed values with
+/// [`Error::MalformedNumericField`], and values out of range with [`Error::BadValue`].
+fn parse_numeric<T: ParseFixInt>(tag: u32, value: &[u8]) -> Result<T, Error> {
+    if is_malformed_int(value) {
+        return Err(Error::MalformedNumericField { tag });
+    }
+
+    T::parse_fix_int(value).or_bad_value()
+}
+
 /// Possible errors during decoding of [`Message`]s.
-#[derive(Debug, Clone, thiserror::Error)]
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
 pub enum Error {
     /// Message did not contain mandatory field.
     #[error("message is missing mandatory field '{}'", .0)]
     MissingMandatoryField(&'static str),
 
+    /// Second field of the message is not the `BodyLength` (`9`).
+    #[error("expected BodyLength (9) as second field, but found tag {found}")]
+    BodyLengthNotSecond {
+        /// Tag of the field found in place of the `BodyLength`.
+        found: u32,
+    },
+
+    /// Value of a field is longer than [`DecodeOptions::max_field_value_len`].
+    #[error("value of field {tag} exceeds the limit of {limit} bytes")]
+    FieldValueTooLong {
+        /// Tag of the field with the oversized value.
+        tag: u32,
+
+        /// Maximum allowed length of a field value.
+        limit: usize,
+    },
+
+    /// Third field of the message is not the `MsgType` (`35`).
+    #[error("expected MsgType (35) as third field, but found tag {found}")]
+    MsgTypeNotThird {
+        /// Tag of the field found in place of the `MsgType`.
+        found: u32,
+    },
+
+    /// The value of an integer field is not formatted as bare digits, e.g. it is surrounded by
+    /// spaces.
+    #[error("value of numeric field {tag} is not formatted as bare digits")]
+    MalformedNumericField {
+        /// Tag of the malformed field.
+        tag: u32,
+    },
+
     /// Message contained checksum before end.
     #[error("checksum reached but message contains more fields")]
     UnexpectedChecksum,
 
+    /// Another message starts after the checksum, i.e. the input contains more than one
+    /// message.
+    #[error("another message starts after the checksum at offset {offset}")]
+    TrailingMessage {
+        /// Offset of the `BeginString` (`8`) of the trailing message.
+        offset: usize,
+    },
+
     /// Message checksum does not match with what we calculated.
     #[error(
         "calculated and expected checksums don't match 'calculated({calculated}) != ({expected})'"
@@ -57,7 +126,7 @@ pub enum Error {
 
     /// Message contains invalid tag values.
     #[error("invalid tag: {}", .0)]
-    BadTag(u16),
+    BadTag(u32),
 
     /// Message body length does not match what was received.
     #[error("expected body length {expected} but received {received} bytes")]
@@ -76,32 +145,227 @@ pub enum Error {
     /// Message contains invalid values.
     #[error("Invalid value: {}", .0)]
     BadValue(String),
+
+    /// Value of a field contains bytes outside of printable ASCII, see
+    /// [`DecodeOptions::validate_printable_ascii`].
+    #[error("value of field {tag} contains non-printable characters")]
+    NonPrintableValue {
+        /// Tag of the field with the non-printable value.
+        tag: u32,
+    },
+
+    /// Message `BeginString` contains a FIX version that is not recognized.
+    #[error("unsupported fix version: {}", String::from_utf8_lossy(.0))]
+    UnsupportedBeginString(Vec<u8>),
 }
 
 /// Errors that represent failures to decode symbols during lexing of FIX messages.
 #[derive(Debug, Clone, PartialEq, thiserror::Error)]
 pub enum LexError {
     /// Found different byte than what was expected.
-    #[error("Expected '{expected}' but got {but_got}")]
+    #[error("Expected '{expected}' but got {but_got} at byte offset {offset}")]
     Unexpected {
         /// Byte value that was expected at the current lexer position.
         expected: u8,
 
         /// Byte value that was encountered instead of the expected one.
         but_got: u8,
+
+        /// Offset of the unexpected byte from the start of the input.
+        offset: usize,
     },
 
     /// EOI reached but not expected.
-    #[error("Unexpected end of input")]
-    Eoi,
+    #[error("Unexpected end of input at byte offset {offset}")]
+    Eoi {
+        /// Offset of the end of input, i.e. the input length.
+        offset: usize,
+    },
 
     /// Expected EOI but more input was found.
-    #[error("Expected end of input, but got {}", .0)]
-    ExpectedEOI(u8),
+    #[error("Expected end of input, but got {but_got} at byte offset {offset}")]
+    ExpectedEOI {
+        /// Byte value that was encountered instead of the end of input.
+        but_got: u8,
+
+        /// Offset of the unexpected byte from the start of the input.
+        offset: usize,
+    },
 
     /// Tag contains bytes that are not ASCII decimal digits.
-    #[error("Tag contains characters other than ascii 0-9 digits.")]
-    MalformedTag,
+    #[error("Tag contains characters other than ascii 0-9 digits at byte offset {offset}")]
+    MalformedTag {
+        /// Offset of the first byte of the tag from the start of the input.
+        offset: usize,
+    },
+}
+
+impl LexError {
+    /// Returns the offset from the start of the input at which the error was encountered.
+    #[must_use]
+    pub const fn offset(&self) -> usize {
+        match self {
+            LexError::Unexpected { offset, .. }
+            | LexError::Eoi { offset }
+            | LexError::ExpectedEOI { offset, .. }
+            | LexError::MalformedTag { offset } => *offset,
+        }
+    }
+}
+
+/// Options configuring the strictness of the decoder.
+///
+/// The [`Default`] options are strict, i.e. any deviation from the FIX framing rules results in
+/// an error. Individual rules can be relaxed for diagnostic purposes, e.g. when dealing with
+/// poorly-behaved test tools:
+/// ```
+/// use trafix_codec::message::{DecodeOptions, Message};
+///
+/// let options = DecodeOptions {
+///     recompute_body_length: true,
+///     ..DecodeOptions::default()
+/// };
+///
+/// let msg = Message::decode_with("8=FIX.4.4\x0135=0\x0110=247\x01", &options);
+/// assert!(msg.is_ok());
+/// ```
+// each flag relaxes an independent rule, so they do not form a state machine
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Clone)]
+pub struct DecodeOptions {
+    /// When `BodyLength` (`9`) is absent or zero, derive it from the position of the `CheckSum`
+    /// (`10`) instead of returning an error.
+    pub recompute_body_length: bool,
+
+    /// Maximum length of a single field value in bytes. Values are rejected as soon as they
+    /// exceed the limit, without scanning for their end. Defaults to
+    /// [`DEFAULT_MAX_FIELD_VALUE_LEN`].
+    pub max_field_value_len: usize,
+
+    /// When a field limited to a fixed set of known values (e.g. the `OrdType` (`40`)) holds an
+    /// unknown value, store it as [`Field::Custom`] instead of returning an error. This keeps
+    /// messages with venue-specific extensions decodable.
+    pub lenient_enums: bool,
+
+    /// When the value of any typed field fails to parse, e.g. a `MsgSeqNum` (`34`) overflowing
+    /// its integer type, store it as [`Field::Custom`] instead of returning an error. This
+    /// implies [`lenient_enums`](Self::lenient_enums), and is meant for diagnostic tools that
+    /// inspect malformed messages. The affected fields are reported by
+    /// [`Message::degraded_tags`].
+    pub lenient_values: bool,
+
+    /// Store every field, except the framing fields (`8`, `9`, `35` and `10`), as
+    /// [`Field::Custom`], without parsing its value. This skips all value validation, and is
+    /// meant for forwarding messages that are only inspected for a few tags.
+    pub raw_fields: bool,
+
+    /// Validator invoked for every decoded field, except the framing fields (`8`, `9`, `35` and
+    /// `10`), to enforce custom business rules. A returned error message fails the decoding
+    /// with [`Error::BadValue`].
+    ///
+    /// Example usage:
+    /// ```
+    /// use std::sync::Arc;
+    ///
+    /// use trafix_codec::message::{DecodeOptions, Message, field::Field};
+    ///
+    /// let options = DecodeOptions {
+    ///     field_validator: Some(Arc::new(|field: &Field| match field {
+    ///         Field::MsgSeqNum(0) => Err("MsgSeqNum must be positive".to_string()),
+    ///         _ => Ok(()),
+    ///     })),
+    ///     ..DecodeOptions::default()
+    /// };
+    ///
+    /// let msg = Message::decode_with("8=FIX.4.4\x019=10\x0135=0\x0134=0\x0110=164\x01", &options);
+    /// assert!(msg.is_err());
+    /// ```
+    pub field_validator: Option<FieldValidator>,
+
+    /// Skip SOH bytes, ASCII whitespace and UTF-8 byte order marks preceding the `BeginString`
+    /// (`8`), as sometimes prepended by misbehaving senders, instead of returning an error.
+    pub skip_leading_noise: bool,
+
+    /// Reject field values containing bytes outside of printable ASCII (`0x20` to `0x7E`), which
+    /// usually indicate a framing bug on the sender side, with [`Error::NonPrintableValue`].
+    /// Binary data fields, e.g. the `RawData` (`96`), and encoded text fields, e.g. the
+    /// `EncodedText` (`355`), are exempt.
+    pub validate_printable_ascii: bool,
+
+    /// Strip `\r` and `\n` bytes immediately preceding the SOH terminating a field value, as
+    /// embedded by counterparties using Windows line endings, instead of keeping them as part
+    /// of the value. Binary data fields, e.g. the `RawData` (`96`), are kept as received.
+    pub strip_line_endings: bool,
+
+    /// Keep a single occurrence of each header field, e.g. the `SendingTime` (`52`), dropping
+    /// any repetitions emitted by buggy encoders. By default, all occurrences are stored, so
+    /// that [`Message::validate_header`] reports them as duplicates. The fields of the hops
+    /// (`627`) group, which repeat once per hop, are always kept. The tags of the dropped fields
+    /// are reported by [`Message::dropped_tags`].
+    pub dedup_header_fields: Option<Occurrence>,
+
+    /// Treat a `CheckSum` (`10`) at the end of the input that is not terminated with SOH as
+    /// incomplete, failing with [`LexError::Eoi`], instead of accepting it as the end of the
+    /// message. This is meant for input read from a stream, where a `CheckSum` cut right before
+    /// its SOH may still be followed by more digits, e.g. `10=1` of `10=163`, see
+    /// [`frame_iter_with`].
+    pub require_checksum_soh: bool,
+}
+
+/// Occurrence of a repeated field to keep, see [`DecodeOptions::dedup_header_fields`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Occurrence {
+    /// Keep the first occurrence, dropping later ones.
+    First,
+
+    /// Keep the value of the last occurrence, at the position of the first one.
+    Last,
+}
+
+/// Validator of decoded fields, see [`DecodeOptions::field_validator`].
+pub type FieldValidator = Arc<dyn Fn(&Field) -> Result<(), String> + Send + Sync>;
+
+impl fmt::Debug for DecodeOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DecodeOptions")
+            .field("recompute_body_length", &self.recompute_body_length)
+            .field("max_field_value_len", &self.max_field_value_len)
+            .field("lenient_enums", &self.lenient_enums)
+            .field("lenient_values", &self.lenient_values)
+            .field("raw_fields", &self.raw_fields)
+            .field("skip_leading_noise", &self.skip_leading_noise)
+            .field("validate_printable_ascii", &self.validate_printable_ascii)
+            .field("strip_line_endings", &self.strip_line_endings)
+            .field("dedup_header_fields", &self.dedup_header_fields)
+            .field("require_checksum_soh", &self.require_checksum_soh)
+            .field(
+                "field_validator",
+                &self.field_validator.as_ref().map(|_| "<validator>"),
+            )
+            .finish()
+    }
+}
+
+/// Default [`DecodeOptions::max_field_value_len`] (64 KiB). FIX does not define an upper bound
+/// for field values, but even large data fields rarely come close to it in practice.
+pub const DEFAULT_MAX_FIELD_VALUE_LEN: usize = 64 * 1024;
+
+impl Default for DecodeOptions {
+    fn default() -> Self {
+        Self {
+            recompute_body_length: false,
+            max_field_value_len: DEFAULT_MAX_FIELD_VALUE_LEN,
+            lenient_enums: false,
+            lenient_values: false,
+            raw_fields: false,
+            field_validator: None,
+            skip_leading_noise: false,
+            validate_printable_ascii: false,
+            strip_line_endings: false,
+            dedup_header_fields: None,
+            require_checksum_soh: false,
+        }
+    }
 }
 
 /// Lexer reads the FIX message bytes and extracts tags and values from them.
@@ -111,6 +375,16 @@ struct Lexer<'input> {
 
     /// Current position in the input byte slice.
     cursor: usize,
+
+    /// Maximum length of a single field value.
+    max_value_len: usize,
+
+    /// Whether `\r` and `\n` bytes at the end of values are stripped.
+    strip_line_endings: bool,
+
+    /// Data field expected to follow the field lexed last, along with its length, as given by
+    /// that length field.
+    pending_data: Option<(u32, usize)>,
 }
 
 impl<'input> Lexer<'input> {
@@ -129,6 +403,7 @@ impl<'input> Lexer<'input> {
             Some(byte) if *byte != expected => Err(LexError::Unexpected {
                 expected,
                 but_got: *byte,
+                offset: self.cursor,
             }),
 
             // got a byte and it matches the expected one, so skip it
@@ -138,7 +413,26 @@ impl<'input> Lexer<'input> {
             }
 
             // got EOI, but expected a byte
-            None => Err(LexError::Eoi),
+            None => Err(LexError::Eoi {
+                offset: self.cursor,
+            }),
+        }
+    }
+
+    /// Skips all SOH bytes, ASCII whitespace and UTF-8 byte order marks at the cursor.
+    fn skip_leading_noise(&mut self) {
+        loop {
+            let rest = &self.input[self.cursor..];
+
+            if rest.starts_with(UTF8_BOM) {
+                self.cursor += UTF8_BOM.len();
+            } else if let Some(byte) = rest.first()
+                && (*byte == constants::SOH || byte.is_ascii_whitespace())
+            {
+                self.cursor += 1;
+            } else {
+                break;
+            }
         }
     }
 
@@ -147,7 +441,7 @@ impl<'input> Lexer<'input> {
     /// # Errors
     ///
     /// Returns an error on invalid tag, or if some other token is encountered.
-    fn tag(&mut self) -> Result<u16, LexError> {
+    fn tag(&mut self) -> Result<u32, LexError> {
         let start = self.cursor;
 
         while let Some(byte) = self.input.get(self.cursor)
@@ -160,23 +454,32 @@ impl<'input> Lexer<'input> {
         let end = self.cursor;
         self.skip(constants::EQUALS)?;
 
-        let tag_bytes = self.input.get(start..end).ok_or(LexError::Eoi)?;
+        let tag_bytes = self.input.get(start..end).ok_or(LexError::Eoi {
+            offset: self.cursor,
+        })?;
 
-        u16::parse_fix_int(tag_bytes).map_err(|_| LexError::MalformedTag)
+        u32::parse_fix_int(tag_bytes).map_err(|_| LexError::MalformedTag { offset: start })
     }
 
-    /// Tries to lex out the value of field in FIX Message.
+    /// Tries to lex out the value of the field with the given tag in FIX Message.
     ///
     /// # Errors
     ///
-    /// Returns an error on invalid value, or if some other token is encountered.
-    fn value(&mut self) -> Result<&'input [u8], LexError> {
+    /// Returns an error on invalid or too long value, or if some other token is encountered.
+    fn value(&mut self, tag: u32) -> Result<&'input [u8], Error> {
         // INVARIANT: Cursor position right after '=' character
         let start = self.cursor;
 
         while let Some(byte) = self.input.get(self.cursor)
             && *byte != constants::SOH
         {
+            if self.cursor - start == self.max_value_len {
+                return Err(Error::FieldValueTooLong {
+                    tag,
+                    limit: self.max_value_len,
+                });
+            }
+
             self.cursor += 1;
         }
 
@@ -184,7 +487,83 @@ impl<'input> Lexer<'input> {
         let end = self.cursor;
         self.skip_or_eoi(constants::SOH)?;
 
-        self.input.get(start..end).ok_or(LexError::Eoi)
+        let mut value = self.input.get(start..end).ok_or(LexError::Eoi {
+            offset: self.cursor,
+        })?;
+
+        if self.strip_line_endings {
+            while let [rest @ .., b'\r' | b'\n'] = value {
+                value = rest;
+            }
+        }
+
+        Ok(value)
+    }
+
+    /// Tries to lex out the value of the data field with the given tag, spanning exactly `len`
+    /// bytes, as given by the preceding length field. The value may contain SOH bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error on too long value, if the input ends before `len` bytes, or if the value
+    /// is not followed by SOH.
+    fn data_value(&mut self, tag: u32, len: usize) -> Result<&'input [u8], Error> {
+        // INVARIANT: Cursor position right after '=' character
+        if len > self.max_value_len {
+            return Err(Error::FieldValueTooLong {
+                tag,
+                limit: self.max_value_len,
+            });
+        }
+
+        let start = self.cursor;
+        let end = start
+            .checked_add(len)
+            .filter(|end| *end <= self.input.len())
+            .ok_or(LexError::Eoi {
+                offset: self.input.len(),
+            })?;
+
+        self.cursor = end;
+        self.skip_or_eoi(constants::SOH)?;
+
+        Ok(&self.input[start..end])
+    }
+
+    /// Tries to lex out the value of the field with the given tag the same way as
+    /// [`Lexer::value`], unless the field is a data field following its length field, whose
+    /// value is lexed with [`Lexer::data_value`] instead, so it may contain SOH bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error on invalid or too long value, if some other token is encountered, or if
+    /// the value of a length field is not a valid number.
+    fn field_value(&mut self, tag: u32) -> Result<&'input [u8], Error> {
+        let value = match self.pending_data.take() {
+            Some((data_tag, len)) if data_tag == tag => self.data_value(tag, len)?,
+            _ => self.value(tag)?,
+        };
+
+        if let Some((_, data_tag)) = constants::DATA_FIELDS
+            .iter()
+            .find(|(length_tag, _)| *length_tag == tag)
+        {
+            self.pending_data = Some((*data_tag, parse_numeric(tag, value)?));
+        }
+
+        Ok(value)
+    }
+
+    /// Fails with [`LexError::Eoi`] unless the value lexed last was terminated with SOH, i.e.
+    /// if it was terminated by the end of input instead.
+    fn require_soh(&self) -> Result<(), LexError> {
+        if self.input[..self.cursor].last() == Some(&constants::SOH) {
+            Ok(())
+        } else {
+            Err(LexError::Eoi {
+                offset: self.cursor,
+            })
+        }
     }
 }
 
@@ -193,6 +572,9 @@ impl<'slice> From<&'slice [u8]> for Lexer<'slice> {
         Self {
             input: value,
             cursor: 0,
+            max_value_len: usize::MAX,
+            strip_line_endings: false,
+            pending_data: None,
         }
     }
 }
@@ -209,145 +591,1870 @@ impl<'slice> From<&'slice [u8]> for Lexer<'slice> {
 ///
 /// Returns an [`Error`] on malformed message formats.
 pub fn decode(bytes: impl AsRef<[u8]>) -> Result<Message, Error> {
-    let bytes = bytes.as_ref();
-    let mut lexer = Lexer::from(bytes);
-
-    let tag = lexer.tag()?;
-    let value = lexer.value()?;
+    decode_with(bytes, &DecodeOptions::default())
+}
 
-    if tag != BeginString::tag() {
-        return Err(Error::BadTag(tag));
-    }
+/// Decodes a [`Message`] the same way as [`decode`], with the strictness of the decoder
+/// configured by the given [`DecodeOptions`].
+///
+/// # Errors
+///
+/// Returns an [`Error`] on malformed message formats.
+pub fn decode_with(bytes: impl AsRef<[u8]>, options: &DecodeOptions) -> Result<Message, Error> {
+    decode_message(bytes.as_ref(), options, None)
+}
 
-    let begin_string = BeginString::from_fix_bytes(value).or_bad_value()?;
+/// Decodes a [`Message`] the same way as [`decode_with`], also returning the [`BodyDigest`] of
+/// its body, i.e. of the bytes counted by the `BodyLength` (`9`). The digest is calculated while
+/// validating the `CheckSum` (`10`) anyway, and can be passed to
+/// [`Message::encode_with_body_digest`] to forward the unchanged message without encoding or
+/// summing its body again.
+///
+/// # Errors
+///
+/// Returns an [`Error`] on malformed message formats.
+pub fn decode_with_digest<'input>(
+    bytes: &'input (impl AsRef<[u8]> + ?Sized),
+    options: &DecodeOptions,
+) -> Result<(Message, BodyDigest<'input>), Error> {
+    decode_message_with_digest(bytes.as_ref(), options, None)
+}
 
-    let tag = lexer.tag()?;
-    let value = lexer.value()?;
+/// Decodes a [`Message`] the same way as [`decode`], taking `SenderCompID` (`49`) and
+/// `TargetCompID` (`56`) values from the given [`Interner`], so that repeated identifiers share
+/// their storage.
+///
+/// # Errors
+///
+/// Returns an [`Error`] on malformed message formats.
+pub fn decode_with_interner(
+    bytes: impl AsRef<[u8]>,
+    interner: &Interner,
+) -> Result<Message, Error> {
+    decode_message(bytes.as_ref(), &DecodeOptions::default(), Some(interner))
+}
 
-    if tag != 9 {
-        return Err(Error::MissingMandatoryField("body length"));
+/// Decodes a [`Message`] the same way as [`decode`], additionally checking that the body
+/// fields mandatory for its [`MsgType`] are present, e.g. `TransactTime` (`60`) on order
+/// messages.
+///
+/// # Errors
+///
+/// Returns an [`Error`] on malformed message formats, or
+/// [`Error::MissingMandatoryField`] naming the first mandatory field that is absent.
+pub fn decode_validated(bytes: impl AsRef<[u8]>) -> Result<Message, Error> {
+    let message = decode(bytes)?;
+
+    let mandatory = constants::MANDATORY_BODY_FIELDS
+        .iter()
+        .filter(|(msg_type, _)| *msg_type == message.msg_type().as_bytes());
+
+    for (_, tag) in mandatory {
+        if !message.fields().any(|field| field.tag() == *tag) {
+            // all mandatory fields have a typed variant, and thus a name
+            let name = Field::tag_name(*tag).unwrap_or_default();
+            return Err(Error::MissingMandatoryField(name));
+        }
     }
 
-    let body_length = usize::parse_fix_int(value).or_bad_value()?;
-    let body_start_cursor = lexer.cursor;
-
-    let tag = lexer.tag()?;
+    Ok(message)
+}
 
-    if tag != MsgType::tag() {
-        return Err(Error::MissingMandatoryField("message type"));
-    }
+/// Decodes the fields of a single FIX message the same way as [`decode`], appending them to
+/// the caller-provided `fields` buffer instead of allocating a new one. The buffer is cleared
+/// first, so a single buffer can be reused across many decodes, keeping its capacity.
+///
+/// Returns the [`BeginString`] and [`MsgType`] of the message, which are not stored as fields.
+///
+/// Example usage:
+/// ```
+/// use trafix_codec::message::{
+///     decode_into,
+///     field::{Field, value::msg_type::MsgType},
+/// };
+///
+/// let mut fields = Vec::with_capacity(16);
+///
+/// for input in [
+///     "8=FIX.4.4\x019=10\x0135=0\x0134=1\x0110=165\x01",
+///     "8=FIX.4.4\x019=10\x0135=0\x0134=2\x0110=166\x01",
+/// ] {
+///     let (_, msg_type) = decode_into(input, &mut fields).unwrap();
+///
+///     assert_eq!(msg_type, MsgType::Heartbeat);
+///     assert_eq!(fields.len(), 1);
+/// }
+///
+/// assert_eq!(fields, vec![Field::MsgSeqNum(2)]);
+/// ```
+///
+/// # Errors
+///
+/// Returns an [`Error`] on malformed message formats. The contents of `fields` are unspecified
+/// in that case.
+pub fn decode_into(
+    bytes: impl AsRef<[u8]>,
+    fields: &mut Vec<Field>,
+) -> Result<(BeginString, MsgType), Error> {
+    fields.clear();
+    let (begin_string, msg_type, _) = decode_fields(
+        bytes.as_ref(),
+        &DecodeOptions::default(),
+        None,
+        fields,
+        &mut Vec::new(),
+    )?;
+
+    Ok((begin_string, msg_type))
+}
 
-    let value = lexer.value()?;
-    let msg_type = MsgType::from_fix_bytes(value).or_bad_value()?;
+/// Decodes a [`Message`] with the given [`DecodeOptions`], interning comp IDs if an
+/// [`Interner`] is given.
+fn decode_message(
+    bytes: &[u8],
+    options: &DecodeOptions,
+    interner: Option<&Interner>,
+) -> Result<Message, Error> {
+    decode_message_with_digest(bytes, options, interner).map(|(message, _)| message)
+}
 
-    let builder = Message::builder(begin_string, msg_type);
+/// Same as [`decode_message`], but also returns the [`BodyDigest`] of the body of the message.
+fn decode_message_with_digest<'input>(
+    bytes: &'input [u8],
+    options: &DecodeOptions,
+    interner: Option<&Interner>,
+) -> Result<(Message, BodyDigest<'input>), Error> {
+    let mut fields = Vec::new();
+    let mut dropped_tags = Vec::new();
+    let (begin_string, msg_type, digest) =
+        decode_fields(bytes, options, interner, &mut fields, &mut dropped_tags)?;
+
+    let header = Header {
+        begin_string,
+        msg_type,
+        fields: Vec::new(),
+    };
 
-    let mut builder = match (lexer.tag(), lexer.value()) {
-        (Ok(tag), Ok(value)) => builder.with_field(Field::try_new(tag, value).or_bad_value()?),
-        (Err(error), _) | (Ok(_), Err(error)) => return Err(Error::Lexer(error)),
+    let message = Message {
+        header,
+        body: Body { fields },
+        dropped_tags,
     };
 
-    while let Ok(tag) = lexer.tag() {
-        let value = lexer.value()?;
+    Ok((message, digest))
+}
 
-        if tag == 10 {
-            // checksum reached
-            if lexer.tag().is_ok() {
-                // there must be no fields after checksum!
-                return Err(Error::UnexpectedChecksum);
+/// Decodes the fields of a single FIX message, appending them to `fields` and the tags of the
+/// header fields dropped by [`DecodeOptions::dedup_header_fields`] to `dropped_tags`, and returns
+/// its [`BeginString`], [`MsgType`] and the [`BodyDigest`] of its body.
+fn decode_fields<'input>(
+    bytes: &'input [u8],
+    options: &DecodeOptions,
+    interner: Option<&Interner>,
+    fields: &mut Vec<Field>,
+    dropped_tags: &mut Vec<u32>,
+) -> Result<(BeginString, MsgType, BodyDigest<'input>), Error> {
+    let mut begin_string = None;
+    let mut msg_type = None;
+
+    let digest = walk_fields(bytes, options, |tag, value| {
+        let field = match tag {
+            // BodyLength and CheckSum are validated while walking, not stored
+            9 | 10 => return Ok(()),
+            8 if begin_string.is_none() => {
+                let parsed = BeginString::from_fix_bytes(value)
+                    .map_err(|_| Error::UnsupportedBeginString(value.to_vec()))?;
+                begin_string = Some(parsed);
+                return Ok(());
+            }
+            35 if msg_type.is_none() => {
+                msg_type = Some(MsgType::from_fix_bytes(value).or_bad_value()?);
+                return Ok(());
             }
+            _ if options.raw_fields => Field::Custom {
+                tag,
+                value: value.to_vec(),
+            },
+            _ if !options.lenient_values && Field::is_numeric(tag) && is_malformed_int(value) => {
+                return Err(Error::MalformedNumericField { tag });
+            }
+            49 if let Some(interner) = interner => Field::SenderCompID(interner.intern(value)),
+            56 if let Some(interner) = interner => Field::TargetCompID(interner.intern(value)),
+            _ => match Field::try_new(tag, value) {
+                Ok(field) => field,
+                Err(_)
+                    if options.lenient_values
+                        || (options.lenient_enums && Field::is_enumerated(tag)) =>
+                {
+                    Field::Custom {
+                        tag,
+                        value: value.to_vec(),
+                    }
+                }
+                Err(error) => return Err(Error::BadValue(error.to_string())),
+            },
+        };
+
+        if let Some(validator) = &options.field_validator {
+            validator(&field).map_err(Error::BadValue)?;
+        }
 
-            let cursor_before_checksum =
-                lexer.cursor - SOH_LEN - value.len() - EQ_LEN - CKSUM_TAG_LEN;
+        let repeated = options
+            .dedup_header_fields
+            // the hop fields repeat once per hop
+            .filter(|_| {
+                constants::HEADER_TAGS.contains(&tag) && !constants::HOP_TAGS.contains(&tag)
+            })
+            .and_then(|keep| {
+                let index = fields.iter().position(|field| field.tag() == tag)?;
+                Some((keep, index))
+            });
+
+        match repeated {
+            Some((Occurrence::First, _)) => dropped_tags.push(tag),
+            Some((Occurrence::Last, index)) => {
+                fields[index] = field;
+                dropped_tags.push(tag);
+            }
+            None => fields.push(field),
+        }
 
-            // at this point we can calculate the body length:
-            let received_body_length = cursor_before_checksum - body_start_cursor;
+        Ok(())
+    })?;
 
-            if received_body_length != body_length {
-                return Err(Error::BodyLength {
-                    received: received_body_length,
-                    expected: body_length,
-                });
-            }
+    Ok((
+        begin_string.ok_or(Error::MissingMandatoryField("begin string"))?,
+        msg_type.ok_or(Error::MissingMandatoryField("message type"))?,
+        digest,
+    ))
+}
 
-            let calculated_checksum = {
-                let mut digest = Digest::default();
-                // cursor is right after the value of checksum, so for checksum we calculate all
-                // bytes up to cursor - number of digits in value - 1 equals sign - 2 digits (10)
-                let bytes_up_to_checksum = &bytes[..cursor_before_checksum];
-                digest.push(&bytes_up_to_checksum);
+/// Decodes a [`FixMap`] from a byte array-like object. The same framing rules as for [`decode`]
+/// apply, including validation of `BodyLength` and `CheckSum`, but field values are stored as
+/// raw bytes without any typing.
+///
+/// # Errors
+///
+/// Returns an [`Error`] on malformed message formats.
+pub fn decode_map(bytes: impl AsRef<[u8]>) -> Result<FixMap, Error> {
+    let mut map = FixMap::default();
 
-                digest.checksum()
-            };
+    walk_fields(bytes.as_ref(), &DecodeOptions::default(), |tag, value| {
+        map.insert(tag, value.to_vec());
+        Ok(())
+    })?;
 
-            let expected_checksum = u8::parse_fix_int(value).or_bad_value()?;
+    Ok(map)
+}
 
-            if calculated_checksum != expected_checksum {
-                return Err(Error::ChecksumMismatch {
-                    calculated: calculated_checksum,
-                    expected: expected_checksum,
-                });
-            }
-        } else {
-            builder = builder.with_field(Field::try_new(tag, value).or_bad_value()?);
+/// Byte slices of the header, body and trailer of a FIX message, in that order, as returned by
+/// [`split_sections`].
+pub type Sections<'input> = (&'input [u8], &'input [u8], &'input [u8]);
+
+/// Splits a single FIX message into the byte slices of its header, body and trailer, without
+/// decoding the values of its fields.
+///
+/// The header spans all leading fields belonging to the standard header, the trailer starts
+/// with the first standard trailer field (e.g. the `CheckSum` (`10`)), and the body spans
+/// everything in between. The framing of the message is validated the same way as by
+/// [`decode`], and the three slices concatenate back to the input.
+///
+/// Example usage:
+/// ```
+/// use trafix_codec::message::split_sections;
+///
+/// let input = "8=FIX.4.4\x019=17\x0135=5\x0134=2\x0158=Bye\x0110=125\x01";
+/// let (header, body, trailer) = split_sections(input.as_bytes()).unwrap();
+///
+/// assert_eq!(header, b"8=FIX.4.4\x019=17\x0135=5\x0134=2\x01");
+/// assert_eq!(body, b"58=Bye\x01");
+/// assert_eq!(trailer, b"10=125\x01");
+/// ```
+///
+/// # Errors
+///
+/// Returns an [`Error`] on malformed message formats.
+pub fn split_sections(bytes: &[u8]) -> Result<Sections<'_>, Error> {
+    let mut field_start = 0;
+    let mut header_end = None;
+    let mut trailer_start = None;
+
+    walk_fields(bytes, &DecodeOptions::default(), |tag, value| {
+        let in_header = constants::HEADER_TAGS.contains(&tag);
+        let in_trailer = constants::TRAILER_TAGS.contains(&tag);
+
+        if header_end.is_none() && !in_header {
+            header_end = Some(field_start);
         }
-    }
 
-    let message = builder.build();
-    Ok(message)
+        if trailer_start.is_none() && in_trailer {
+            trailer_start = Some(field_start);
+        }
+
+        // values borrow from the input, so their position gives the end of the field
+        let value_start = value.as_ptr().addr() - bytes.as_ptr().addr();
+        field_start = (value_start + value.len() + SOH_LEN).min(bytes.len());
+
+        Ok(())
+    })?;
+
+    // a valid message always ends with the checksum, which is a trailer field
+    let trailer_start = trailer_start.unwrap_or(bytes.len());
+    let header_end = header_end.unwrap_or(trailer_start);
+
+    Ok((
+        &bytes[..header_end],
+        &bytes[header_end..trailer_start],
+        &bytes[trailer_start..],
+    ))
 }
 
-#[cfg(test)]
-mod tests {
-    use crate::decoder::decode::Error;
-    use crate::message::Message;
+/// Indexes all fields of a single FIX message, returning the tag of each field along with the
+/// byte range of its value within the input, in order of appearance. Values are not decoded, so
+/// callers can slice out and parse only the fields they are interested in.
+///
+/// The framing of the message is validated the same way as by [`decode`], and the framing
+/// fields (`8`, `9`, `35` and `10`) are included in the index.
+///
+/// Example usage:
+/// ```
+/// use trafix_codec::message::field_offsets;
+///
+/// let input = b"8=FIX.4.4\x019=17\x0135=5\x0134=2\x0158=Bye\x0110=125\x01";
+/// let offsets = field_offsets(input).unwrap();
+///
+/// let (tag, range) = offsets[4].clone();
+/// assert_eq!(tag, 58);
+/// assert_eq!(&input[range], b"Bye");
+/// ```
+///
+/// # Errors
+///
+/// Returns an [`Error`] on malformed message formats.
+pub fn field_offsets(bytes: &[u8]) -> Result<Vec<(u32, Range<usize>)>, Error> {
+    let mut offsets = Vec::new();
 
-    #[test]
-    fn parse_valid_message() {
-        let input = "8=FIX.4.4\x019=148\x0135=A\x0134=1080\x0149=TESTBUY1\x0152=20180920-18:14:19.508\x0156=TESTSELL1\x0111=636730640278898634\x0115=USD\x0121=2\x0138=7000\x0140=1\x0154=1\x0155=MSFT\x0160=20180920-18:14:19.492\x0110=089\x01";
+    walk_fields(bytes, &DecodeOptions::default(), |tag, value| {
+        // values borrow from the input, so their position gives the range within it
+        let value_start = value.as_ptr().addr() - bytes.as_ptr().addr();
+        offsets.push((tag, value_start..value_start + value.len()));
 
-        let decode_result = Message::decode(input);
+        Ok(())
+    })?;
 
-        assert!(
-            decode_result.is_ok(),
-            "message decoding failed: {}",
-            decode_result.unwrap_err()
-        );
-    }
+    Ok(offsets)
+}
 
-    #[test]
-    fn bad_checksum() {
-        let input = "8=FIX.4.4\x019=148\x0135=A\x0134=1080\x0149=TESTBUY1\x0152=20180920-18:14:19.508\x0156=TESTSELL1\x0111=636730640278898634\x0115=USD\x0121=2\x0138=7000\x0140=1\x0154=1\x0155=MSFT\x0160=20180920-18:14:19.492\x0110=000\x01";
+/// Reads the [`BeginString`] of a FIX message, without looking at the rest of the bytes. The
+/// bytes do not need to contain a complete, nor a valid message.
+///
+/// # Errors
+///
+/// Returns an [`Error`] if the message does not start with a `BeginString` field, or if the
+/// FIX version is not recognized.
+pub fn peek_begin_string(bytes: impl AsRef<[u8]>) -> Result<BeginString, Error> {
+    let mut lexer = Lexer::from(bytes.as_ref());
 
-        let error = Message::decode(input).expect_err("checksum is not valid");
+    let tag = lexer.tag()?;
 
-        assert!(matches!(error, Error::ChecksumMismatch { .. }));
+    if tag != BeginString::tag() {
+        return Err(Error::BadTag(tag));
     }
 
-    #[test]
-    fn missing_msg_type() {
-        let input = "8=FIX.4.4\x019=148\x0134=1080\x0149=TESTBUY1\x0152=20180920-18:14:19.508\x0156=TESTSELL1\x0111=636730640278898634\x0115=USD\x0121=2\x0138=7000\x0140=1\x0154=1\x0155=MSFT\x0160=20180920-18:14:19.492\x0110=114\x01";
+    let value = lexer.value(tag)?;
 
-        let error = Message::decode(input).expect_err("message type is missing");
+    BeginString::from_fix_bytes(value).map_err(|_| Error::UnsupportedBeginString(value.to_vec()))
+}
 
-        assert!(matches!(
-            error,
-            Error::MissingMandatoryField("message type")
-        ));
-    }
+/// Reads the [`MsgType`] of a FIX message, looking only at the leading `BeginString`,
+/// `BodyLength` and `MsgType` fields. The bytes do not need to contain a complete, nor a valid
+/// message.
+///
+/// # Errors
+///
+/// Returns an [`Error`] if the message does not start with the `BeginString`, `BodyLength` and
+/// `MsgType` fields, or if the message type is not recognized.
+pub fn peek_msg_type(bytes: impl AsRef<[u8]>) -> Result<MsgType, Error> {
+    let mut lexer = Lexer::from(bytes.as_ref());
 
-    #[test]
-    fn bad_body_length() {
-        let input = "8=FIX.4.4\x019=042\x0135=A\x0134=1080\x0149=TESTBUY1\x0152=20180920-18:14:19.508\x0156=TESTSELL1\x0111=636730640278898634\x0115=USD\x0121=2\x0138=7000\x0140=1\x0154=1\x0155=MSFT\x0160=20180920-18:14:19.492\x0110=089\x01";
+    MsgType::from_fix_bytes(lex_leading_fields(&mut lexer)?).or_bad_value()
+}
 
-        let error = Message::decode(input).expect_err("body length does not match");
+/// Reads the raw value of the `MsgType` (`35`) of a FIX message, the same way as
+/// [`peek_msg_type`], but without parsing it into a [`MsgType`]. The value is borrowed from the
+/// input, so routing on it does not allocate, even for message types without a dedicated
+/// variant.
+///
+/// Example usage:
+/// ```
+/// use trafix_codec::message::Message;
+///
+/// let input = b"8=FIX.4.4\x019=9\x0135=UX\x0110=000\x01";
+/// assert_eq!(Message::peek_msg_type_bytes(input), Ok(&b"UX"[..]));
+/// ```
+///
+/// # Errors
+///
+/// Returns an [`Error`] if the message does not start with the `BeginString`, `BodyLength` and
+/// `MsgType` fields.
+pub fn peek_msg_type_bytes(bytes: &[u8]) -> Result<&[u8], Error> {
+    lex_leading_fields(&mut Lexer::from(bytes))
+}
 
-        assert!(matches!(
-            error,
-            Error::BodyLength {
-                expected: 42,
-                received: 148
-            }
+/// Reads the `MsgSeqNum` (`34`) of a FIX message, looking only at its header. The header is
+/// lexed up to the `MsgSeqNum`, or up to the first field that does not belong to the standard
+/// header. The bytes do not need to contain a complete, nor a valid message.
+///
+/// Returns [`None`] if the header does not contain a `MsgSeqNum`.
+///
+/// Example usage:
+/// ```
+/// use trafix_codec::message::Message;
+///
+/// let input = "8=FIX.4.4\x019=17\x0135=5\x0134=2\x0158=Bye\x0110=125\x01";
+/// assert_eq!(Message::peek_seq_num(input), Ok(Some(2)));
+///
+/// let input = "8=FIX.4.4\x019=11\x0135=4\x0136=10\x0110=220\x01";
+/// assert_eq!(Message::peek_seq_num(input), Ok(None));
+/// ```
+///
+/// # Errors
+///
+/// Returns an [`Error`] if the message does not start with the `BeginString`, `BodyLength` and
+/// `MsgType` fields, if a header field is malformed, or if the `MsgSeqNum` is not a valid
+/// number.
+pub fn peek_seq_num(bytes: impl AsRef<[u8]>) -> Result<Option<MsgSeqNum>, Error> {
+    let bytes = bytes.as_ref();
+    let mut lexer = Lexer::from(bytes);
+
+    lex_leading_fields(&mut lexer)?;
+
+    while lexer.cursor < bytes.len() {
+        let tag = lexer.tag()?;
+
+        if !constants::HEADER_TAGS.contains(&tag) {
+            break;
+        }
+
+        let value = lexer.field_value(tag)?;
+
+        if tag == 34 {
+            return parse_numeric(tag, value).map(Some);
+        }
+    }
+
+    Ok(None)
+}
+
+/// Lexes the leading `BeginString`, `BodyLength` and `MsgType` fields, in that order, and
+/// returns the raw value of the `MsgType`.
+fn lex_leading_fields<'input>(lexer: &mut Lexer<'input>) -> Result<&'input [u8], Error> {
+    let tag = lexer.tag()?;
+    lexer.value(tag)?;
+
+    if tag != BeginString::tag() {
+        return Err(Error::BadTag(tag));
+    }
+
+    let tag = lexer.tag()?;
+
+    if tag != 9 {
+        return Err(Error::BodyLengthNotSecond { found: tag });
+    }
+
+    lexer.value(tag)?;
+
+    let tag = lexer.tag()?;
+
+    if tag != MsgType::tag() {
+        return Err(Error::MsgTypeNotThird { found: tag });
+    }
+
+    lexer.value(tag)
+}
+
+/// Splits a buffer of concatenated FIX messages into the byte slices of the individual
+/// messages, without decoding them.
+///
+/// Frame boundaries are found using the `BodyLength` (`9`) of each message, so fields are not
+/// parsed, and the `CheckSum` (`10`) is not validated. Each yielded frame can then be passed to
+/// [`decode`], e.g. on another thread. The iterator stops after the first error, as the start of
+/// the next frame cannot be determined anymore.
+///
+/// Each frame spans up to and including the SOH terminating its `CheckSum`, so the next frame
+/// starts right after it. A `CheckSum` at the end of the buffer is accepted without its SOH. Use
+/// [`frame_iter_with`] and [`DecodeOptions::require_checksum_soh`] to treat it as incomplete
+/// instead, e.g. when framing chunks read from a stream.
+///
+/// Example usage:
+/// ```
+/// use trafix_codec::message::{Message, frame_iter};
+///
+/// let input = "8=FIX.4.4\x019=5\x0135=0\x0110=163\x018=FIX.4.4\x019=5\x0135=1\x0110=164\x01";
+///
+/// let frames: Vec<&[u8]> = frame_iter(input.as_bytes()).collect::<Result<_, _>>().unwrap();
+/// assert_eq!(frames.len(), 2);
+///
+/// for frame in frames {
+///     assert!(Message::decode(frame).is_ok());
+/// }
+/// ```
+pub fn frame_iter(bytes: &[u8]) -> impl Iterator<Item = Result<&[u8], Error>> {
+    frame_iter_with(bytes, &DecodeOptions::default())
+}
+
+/// Splits a buffer of concatenated FIX messages the same way as [`frame_iter`], with the
+/// framing configured by the given [`DecodeOptions`], of which only
+/// [`max_field_value_len`](DecodeOptions::max_field_value_len) and
+/// [`require_checksum_soh`](DecodeOptions::require_checksum_soh) apply.
+///
+/// With `require_checksum_soh`, a message at the end of the buffer that is incomplete, even if
+/// only its last SOH is missing, fails with [`LexError::Eoi`], so a stream reader can keep the
+/// bytes following the last complete frame and retry once more bytes arrived.
+///
+/// Example usage:
+/// ```
+/// use trafix_codec::message::{DecodeOptions, frame_iter_with};
+///
+/// let options = DecodeOptions {
+///     require_checksum_soh: true,
+///     ..DecodeOptions::default()
+/// };
+///
+/// let mut frames = frame_iter_with(b"8=FIX.4.4\x019=5\x0135=0\x0110=163", &options);
+/// assert!(frames.next().unwrap().is_err());
+/// ```
+pub fn frame_iter_with<'input>(
+    bytes: &'input [u8],
+    options: &DecodeOptions,
+) -> impl Iterator<Item = Result<&'input [u8], Error>> + use<'input> {
+    let max_value_len = options.max_field_value_len;
+    let require_checksum_soh = options.require_checksum_soh;
+    let mut cursor = 0;
+
+    std::iter::from_fn(move || {
+        if cursor >= bytes.len() {
+            return None;
+        }
+
+        let lexer = Lexer {
+            cursor,
+            max_value_len,
+            ..Lexer::from(bytes)
+        };
+        let frame = next_frame(lexer, require_checksum_soh);
+
+        cursor = match frame {
+            Ok(frame) => cursor + frame.len(),
+            // the next frame boundary is unknown, so stop iterating
+            Err(_) => bytes.len(),
+        };
+
+        Some(frame)
+    })
+}
+
+/// Decodes newline-separated FIX messages, as found in most FIX data and log files, yielding
+/// the 1-based line number of each message together with its decoding result.
+///
+/// Each line is trimmed of surrounding whitespace (including a trailing `\r`) and decoded as
+/// exactly one message, the same way as [`decode`]. Blank lines are skipped.
+///
+/// Example usage:
+/// ```
+/// use trafix_codec::message::decode_all_lines;
+///
+/// let input = "8=FIX.4.4\x019=5\x0135=0\x0110=163\x01\r\n\n8=FIX.4.4\x019=5\x0135=1\x0110=000\x01\n";
+///
+/// let results: Vec<_> = decode_all_lines(input).collect();
+///
+/// assert_eq!(results.len(), 2);
+/// assert!(matches!(results[0], (1, Ok(_))));
+/// assert!(matches!(results[1], (3, Err(_))));
+/// ```
+pub fn decode_all_lines(input: &str) -> impl Iterator<Item = (usize, Result<Message, Error>)> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(line_number, line)| (line_number, decode(line)))
+}
+
+/// Returns the frame of the message starting at the cursor of the given [`Lexer`], spanning up
+/// to and including its `CheckSum` (`10`) field.
+///
+/// # Errors
+///
+/// Returns an [`Error`] if the message does not start with the `BeginString` and `BodyLength`
+/// fields, if no `CheckSum` field follows the body, or if the `CheckSum` is not terminated with
+/// SOH while `require_checksum_soh` is set.
+fn next_frame(mut lexer: Lexer<'_>, require_checksum_soh: bool) -> Result<&[u8], Error> {
+    let (bytes, start) = (lexer.input, lexer.cursor);
+
+    let tag = lexer.tag()?;
+    lexer.value(tag)?;
+
+    if tag != BeginString::tag() {
+        return Err(Error::BadTag(tag));
+    }
+
+    let tag = lexer.tag()?;
+
+    if tag != 9 {
+        return Err(Error::BodyLengthNotSecond { found: tag });
+    }
+
+    let body_length = parse_numeric(tag, lexer.value(tag)?)?;
+
+    // skip the body without looking at it
+    lexer.cursor = lexer.cursor.saturating_add(body_length).min(bytes.len());
+
+    if lexer.tag()? != 10 {
+        return Err(Error::MissingMandatoryField("checksum"));
+    }
+
+    lexer.value(10)?;
+
+    if require_checksum_soh {
+        lexer.require_soh()?;
+    }
+
+    Ok(&bytes[start..lexer.cursor])
+}
+
+/// Walks over all fields of a single FIX message, passing each of them, including `BeginString`
+/// (`8`), `BodyLength` (`9`), `MsgType` (`35`) and `CheckSum` (`10`), to `on_field` in order of
+/// appearance.
+///
+/// The framing of the message is validated along the way: the message must start with
+/// `BeginString`, `BodyLength` and `MsgType` fields, in that order, and the `BodyLength` and
+/// `CheckSum` values must match the received bytes. See [`DecodeOptions`] for ways to relax
+/// these rules.
+///
+/// Returns the [`BodyDigest`] of the body, i.e. of the bytes counted by the `BodyLength`, which is
+/// calculated while validating the `CheckSum` anyway.
+///
+/// # Errors
+///
+/// Returns an [`Error`] on malformed message formats, or the first error returned by
+/// `on_field`.
+fn walk_fields<'input>(
+    bytes: &'input [u8],
+    options: &DecodeOptions,
+    mut on_field: impl FnMut(u32, &'input [u8]) -> Result<(), Error>,
+) -> Result<BodyDigest<'input>, Error> {
+    let mut lexer = Lexer {
+        max_value_len: options.max_field_value_len,
+        strip_line_endings: options.strip_line_endings,
+        ..Lexer::from(bytes)
+    };
+
+    if options.skip_leading_noise {
+        lexer.skip_leading_noise();
+    }
+
+    // the checksum covers the message only, without any skipped noise
+    let message_start = lexer.cursor;
+
+    let tag = lexer.tag()?;
+    let value = lexer.value(tag)?;
+
+    if tag != BeginString::tag() {
+        return Err(Error::BadTag(tag));
+    }
+
+    on_field(tag, value)?;
+
+    let mut body_start_cursor = lexer.cursor;
+    let mut tag = lexer.tag()?;
+    let mut value = lexer.value(tag)?;
+
+    let body_length = if tag == 9 {
+        let body_length = parse_numeric(tag, value)?;
+        on_field(tag, value)?;
+
+        body_start_cursor = lexer.cursor;
+        tag = lexer.tag()?;
+        value = lexer.value(tag)?;
+
+        // zero length is treated as absent when recomputing
+        Some(body_length).filter(|len| !(options.recompute_body_length && *len == 0))
+    } else if options.recompute_body_length {
+        None
+    } else {
+        return Err(Error::BodyLengthNotSecond { found: tag });
+    };
+
+    if tag != MsgType::tag() {
+        return Err(Error::MsgTypeNotThird { found: tag });
+    }
+
+    on_field(tag, value)?;
+
+    let mut body_digest = Digest::default();
+    let mut body: &[u8] = &[];
+
+    while lexer.cursor < bytes.len() {
+        let tag = lexer.tag()?;
+        let value = lexer.field_value(tag)?;
+
+        if options.validate_printable_ascii
+            && !constants::DATA_FIELDS
+                .iter()
+                .any(|(_, data_tag)| *data_tag == tag)
+            && !value.iter().all(|byte| (0x20..=0x7E).contains(byte))
+        {
+            return Err(Error::NonPrintableValue { tag });
+        }
+
+        if tag == 10 {
+            if options.require_checksum_soh {
+                lexer.require_soh()?;
+            }
+
+            // checksum reached
+            if lexer.cursor < bytes.len() {
+                if bytes[lexer.cursor..].starts_with(b"8=") {
+                    return Err(Error::TrailingMessage {
+                        offset: lexer.cursor,
+                    });
+                }
+
+                // there must be no fields after checksum!
+                return Err(Error::UnexpectedChecksum);
+            }
+
+            // the value borrows from the input, so its position does not depend on whether the
+            // checksum is terminated with SOH or by the end of input
+            let value_start = value.as_ptr().addr() - bytes.as_ptr().addr();
+            let cursor_before_checksum = value_start - EQ_LEN - CKSUM_TAG_LEN;
+
+            // at this point we can calculate the body length:
+            let received_body_length = cursor_before_checksum - body_start_cursor;
+
+            if let Some(body_length) = body_length
+                && received_body_length != body_length
+            {
+                return Err(Error::BodyLength {
+                    received: received_body_length,
+                    expected: body_length,
+                });
+            }
+
+            let calculated_checksum = {
+                // checksum is calculated over all bytes up to the checksum tag (10), summed
+                // separately for the framing and the body so the latter can be reused
+                let mut digest = Digest::default();
+                digest.push(&bytes[message_start..body_start_cursor]);
+                body = &bytes[body_start_cursor..cursor_before_checksum];
+                body_digest.push(body);
+                digest.merge(&body_digest);
+
+                digest.checksum()
+            };
+
+            let expected_checksum = parse_numeric(tag, value)?;
+
+            if calculated_checksum != expected_checksum {
+                return Err(Error::ChecksumMismatch {
+                    calculated: calculated_checksum,
+                    expected: expected_checksum,
+                });
+            }
+        }
+
+        on_field(tag, value)?;
+    }
+
+    Ok(BodyDigest::new(body, body_digest))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::constants;
+    use crate::decoder::decode::{DecodeOptions, Error, LexError, Lexer, Occurrence};
+    use crate::message::Message;
+    use crate::message::field::Field;
+    use crate::message::field::value::FromFixBytes;
+    use crate::message::field::value::begin_string::BeginString;
+    use crate::message::field::value::comp_id::{CompID, Interner};
+    use crate::message::field::value::local_mkt_date::LocalMktDate;
+    use crate::message::field::value::msg_type::MsgType;
+    use crate::message::field::value::security_id_source::SecurityIDSource;
+    use crate::message::field::value::security_type::SecurityType;
+    use crate::message::field::value::utc_timestamp::UtcTimestamp;
+    use crate::message::header::HeaderError;
+
+    #[test]
+    fn parse_valid_message() {
+        let input = "8=FIX.4.4\x019=148\x0135=A\x0134=1080\x0149=TESTBUY1\x0152=20180920-18:14:19.508\x0156=TESTSELL1\x0111=636730640278898634\x0115=USD\x0121=2\x0138=7000\x0140=1\x0154=1\x0155=MSFT\x0160=20180920-18:14:19.492\x0110=089\x01";
+
+        let decode_result = Message::decode(input);
+
+        assert!(
+            decode_result.is_ok(),
+            "message decoding failed: {}",
+            decode_result.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn checksum_without_trailing_soh() {
+        let input = "8=FIX.4.4\x019=148\x0135=A\x0134=1080\x0149=TESTBUY1\x0152=20180920-18:14:19.508\x0156=TESTSELL1\x0111=636730640278898634\x0115=USD\x0121=2\x0138=7000\x0140=1\x0154=1\x0155=MSFT\x0160=20180920-18:14:19.492\x0110=089";
+
+        let message = Message::decode(input).expect("trailing SOH is optional");
+        assert_eq!(message.msg_type(), &MsgType::Logon);
+
+        // body length and checksum are still verified
+        let input = "8=FIX.4.4\x019=147\x0135=A\x0134=1080\x0149=TESTBUY1\x0152=20180920-18:14:19.508\x0156=TESTSELL1\x0111=636730640278898634\x0115=USD\x0121=2\x0138=7000\x0140=1\x0154=1\x0155=MSFT\x0160=20180920-18:14:19.492\x0110=089";
+        assert!(matches!(
+            Message::decode(input),
+            Err(Error::BodyLength {
+                received: 148,
+                expected: 147
+            })
         ));
+
+        let input = "8=FIX.4.4\x019=5\x0135=0\x0110=000";
+        assert!(matches!(
+            Message::decode(input),
+            Err(Error::ChecksumMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn bad_checksum() {
+        let input = "8=FIX.4.4\x019=148\x0135=A\x0134=1080\x0149=TESTBUY1\x0152=20180920-18:14:19.508\x0156=TESTSELL1\x0111=636730640278898634\x0115=USD\x0121=2\x0138=7000\x0140=1\x0154=1\x0155=MSFT\x0160=20180920-18:14:19.492\x0110=000\x01";
+
+        let error = Message::decode(input).expect_err("checksum is not valid");
+
+        assert!(matches!(error, Error::ChecksumMismatch { .. }));
+    }
+
+    #[test]
+    fn missing_msg_type() {
+        let input = "8=FIX.4.4\x019=148\x0134=1080\x0149=TESTBUY1\x0152=20180920-18:14:19.508\x0156=TESTSELL1\x0111=636730640278898634\x0115=USD\x0121=2\x0138=7000\x0140=1\x0154=1\x0155=MSFT\x0160=20180920-18:14:19.492\x0110=114\x01";
+
+        let error = Message::decode(input).expect_err("message type is missing");
+
+        assert!(matches!(error, Error::MsgTypeNotThird { found: 34 }));
+    }
+
+    #[test]
+    fn header_order() {
+        for (input, expected) in [
+            // 8, 35, 9
+            (
+                "8=FIX.4.4\x0135=0\x019=5\x0110=000\x01",
+                Error::BodyLengthNotSecond { found: 35 },
+            ),
+            // 9, 8, 35
+            ("9=5\x018=FIX.4.4\x0135=0\x0110=000\x01", Error::BadTag(9)),
+            // 9, 35, 8
+            ("9=5\x0135=0\x018=FIX.4.4\x0110=000\x01", Error::BadTag(9)),
+            // 35, 8, 9
+            ("35=0\x018=FIX.4.4\x019=5\x0110=000\x01", Error::BadTag(35)),
+            // 35, 9, 8
+            ("35=0\x019=5\x018=FIX.4.4\x0110=000\x01", Error::BadTag(35)),
+            // 8, 9, other
+            (
+                "8=FIX.4.4\x019=10\x0134=1\x0135=0\x0110=000\x01",
+                Error::MsgTypeNotThird { found: 34 },
+            ),
+        ] {
+            let error = Message::decode(input).expect_err("header fields are out of order");
+            assert_eq!(error, expected, "{input:?}");
+        }
+    }
+
+    #[test]
+    fn bad_body_length() {
+        let input = "8=FIX.4.4\x019=042\x0135=A\x0134=1080\x0149=TESTBUY1\x0152=20180920-18:14:19.508\x0156=TESTSELL1\x0111=636730640278898634\x0115=USD\x0121=2\x0138=7000\x0140=1\x0154=1\x0155=MSFT\x0160=20180920-18:14:19.492\x0110=089\x01";
+
+        let error = Message::decode(input).expect_err("body length does not match");
+
+        assert!(matches!(
+            error,
+            Error::BodyLength {
+                expected: 42,
+                received: 148
+            }
+        ));
+    }
+
+    #[test]
+    fn tag_without_equals() {
+        let input = "8=FIX.4.4\x019=10\x0135=0\x0149\x0110=000\x01";
+
+        let error = Message::decode(input).expect_err("tag 49 has no value");
+
+        // offset points right at the SOH following the '49' tag
+        assert!(matches!(
+            error,
+            Error::Lexer(LexError::Unexpected {
+                expected: constants::EQUALS,
+                but_got: constants::SOH,
+                offset: 22,
+            })
+        ));
+    }
+
+    #[test]
+    fn embedded_equals() {
+        // only the first '=' separates the tag from the value
+        let mut lexer = Lexer::from(b"58=a=b=c\x01".as_slice());
+        assert_eq!(lexer.tag(), Ok(58));
+        assert_eq!(lexer.value(58), Ok(b"a=b=c".as_slice()));
+
+        let input = "8=FIX.4.4\x019=14\x0135=0\x0158=a=b=c\x0110=030\x01";
+        let message = Message::decode(input).expect("equals signs are part of the value");
+        assert_eq!(message.body.fields, vec![Field::Text(b"a=b=c".to_vec())]);
+        assert_eq!(message.encode(), input.as_bytes());
+
+        // base64 padding at the end of the value
+        let input =
+            "8=FIX.4.4\x019=43\x0135=0\x0158=aHR0cHM6Ly9leGFtcGxlLmNvbS8/cT0x==\x0110=169\x01";
+        let message = Message::decode(input).expect("equals signs are part of the value");
+        assert_eq!(
+            message.body.fields,
+            vec![Field::Text(b"aHR0cHM6Ly9leGFtcGxlLmNvbS8/cT0x==".to_vec())]
+        );
+    }
+
+    #[test]
+    fn lex_error_offsets() {
+        let input = "8=FIX.4.4\x019=5\x0135";
+
+        let Error::Lexer(error) = Message::decode(input).expect_err("input is truncated") else {
+            panic!("expected lexer error");
+        };
+
+        assert_eq!(error, LexError::Eoi { offset: 16 });
+        assert_eq!(error.offset(), input.len());
+        assert_eq!(
+            error.to_string(),
+            "Unexpected end of input at byte offset 16"
+        );
+    }
+
+    #[test]
+    fn frame_iter() {
+        let heartbeat = "8=FIX.4.4\x019=5\x0135=0\x0110=163\x01";
+        let logout = "8=FIX.4.4\x019=12\x0135=5\x0158=Bye\x0110=161\x01";
+        let input = format!("{heartbeat}{logout}{heartbeat}");
+
+        let frames = super::frame_iter(input.as_bytes())
+            .collect::<Result<Vec<_>, _>>()
+            .expect("frames are valid");
+
+        assert_eq!(
+            frames,
+            vec![
+                heartbeat.as_bytes(),
+                logout.as_bytes(),
+                heartbeat.as_bytes()
+            ]
+        );
+
+        assert_eq!(super::frame_iter(b"").count(), 0);
+
+        // body length points past the end of the input
+        let input = format!("{heartbeat}8=FIX.4.4\x019=50\x0135=0\x0110=163\x01");
+        let mut frames = super::frame_iter(input.as_bytes());
+
+        assert_eq!(frames.next().unwrap().unwrap(), heartbeat.as_bytes());
+        assert!(matches!(
+            frames.next(),
+            Some(Err(Error::Lexer(LexError::Eoi { .. })))
+        ));
+        assert!(frames.next().is_none());
+
+        // frame cut between the checksum value and its terminating SOH, which is accepted by
+        // default, see the frame_iter_chunked test for treating it as incomplete
+        let input = format!("{heartbeat}{logout}");
+        let first = &input[..input.len() - 1];
+
+        let frames = super::frame_iter(first.as_bytes())
+            .collect::<Result<Vec<_>, _>>()
+            .expect("frames are valid");
+
+        assert_eq!(
+            frames,
+            vec![heartbeat.as_bytes(), &logout.as_bytes()[..logout.len() - 1]]
+        );
+
+        // body length does not end right before the checksum
+        let input = "8=FIX.4.4\x019=0\x0135=0\x0110=163\x01";
+        let error = super::frame_iter(input.as_bytes()).next().unwrap();
+
+        assert!(matches!(
+            error,
+            Err(Error::MissingMandatoryField("checksum"))
+        ));
+    }
+
+    #[test]
+    fn frame_iter_chunked() {
+        let options = DecodeOptions {
+            require_checksum_soh: true,
+            ..DecodeOptions::default()
+        };
+
+        let heartbeat = "8=FIX.4.4\x019=5\x0135=0\x0110=163\x01";
+        let logout = "8=FIX.4.4\x019=12\x0135=5\x0158=Bye\x0110=161\x01";
+        let input = format!("{heartbeat}{logout}");
+
+        // the stream is cut within the checksum value, and right before its SOH
+        for split in [input.len() - 2, input.len() - 1] {
+            let (first, second) = input.as_bytes().split_at(split);
+
+            let mut buffer = first.to_vec();
+            let mut frames = Vec::new();
+            let mut consumed = 0;
+
+            for frame in super::frame_iter_with(&buffer, &options) {
+                match frame {
+                    Ok(frame) => {
+                        consumed += frame.len();
+                        frames.push(frame.to_vec());
+                    }
+                    Err(error) => {
+                        assert!(matches!(error, Error::Lexer(LexError::Eoi { .. })));
+                    }
+                }
+            }
+
+            assert_eq!(frames, vec![heartbeat.as_bytes()]);
+
+            // the incomplete frame is retried once the second chunk arrived
+            buffer.drain(..consumed);
+            buffer.extend_from_slice(second);
+
+            let frames = super::frame_iter_with(&buffer, &options)
+                .collect::<Result<Vec<_>, _>>()
+                .expect("frames are complete");
+
+            assert_eq!(frames, vec![logout.as_bytes()]);
+        }
+
+        // decoding a message cut before its final SOH fails the same way
+        let error = Message::decode_with(&heartbeat[..heartbeat.len() - 1], &options).unwrap_err();
+        assert!(matches!(error, Error::Lexer(LexError::Eoi { .. })));
+    }
+
+    #[test]
+    fn mixed_versions() {
+        let fix42 = "8=FIX.4.2\x019=10\x0135=0\x0134=1\x0110=163\x01";
+        let fix44 = "8=FIX.4.4\x019=17\x0135=5\x0134=2\x0158=Bye\x0110=125\x01";
+        let fixt11 = "8=FIXT.1.1\x019=10\x0135=0\x0134=3\x0110=245\x01";
+        let input = format!("{fix42}{fix44}{fixt11}");
+
+        // the framing does not depend on the length of the version string
+        let versions = super::frame_iter(input.as_bytes())
+            .map(|frame| {
+                let frame = frame.expect("frame is valid");
+                Message::decode(frame)
+                    .expect("message is valid")
+                    .begin_string()
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            versions,
+            vec![BeginString::FIX42, BeginString::FIX44, BeginString::FIXT11]
+        );
+
+        let input = format!("{fix42}\n{fix44}\n{fixt11}\n");
+        let versions = super::decode_all_lines(&input)
+            .map(|(_, message)| message.expect("message is valid").begin_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            versions,
+            vec![BeginString::FIX42, BeginString::FIX44, BeginString::FIXT11]
+        );
+    }
+
+    #[test]
+    fn decode_all_lines() {
+        let heartbeat = "8=FIX.4.4\x019=5\x0135=0\x0110=163\x01";
+        let bad_checksum = "8=FIX.4.4\x019=5\x0135=0\x0110=000\x01";
+        let input = format!("{heartbeat}\n  {heartbeat}\r\n\n \t\n{bad_checksum}\n{heartbeat}");
+
+        let results: Vec<_> = super::decode_all_lines(&input).collect();
+
+        let line_numbers: Vec<usize> = results.iter().map(|(line, _)| *line).collect();
+        assert_eq!(line_numbers, vec![1, 2, 5, 6]);
+
+        assert!(results[0].1.is_ok());
+        assert!(results[1].1.is_ok());
+        assert!(matches!(
+            results[2].1,
+            Err(Error::ChecksumMismatch { expected: 0, .. })
+        ));
+        assert!(results[3].1.is_ok());
+    }
+
+    #[test]
+    fn decode_map() {
+        let input = "8=FIX.4.4\x019=148\x0135=A\x0134=1080\x0149=TESTBUY1\x0152=20180920-18:14:19.508\x0156=TESTSELL1\x0111=636730640278898634\x0115=USD\x0121=2\x0138=7000\x0140=1\x0154=1\x0155=MSFT\x0160=20180920-18:14:19.492\x0110=089\x01";
+
+        let map = super::decode_map(input).expect("message is valid");
+
+        assert_eq!(map.get(8), Some(&b"FIX.4.4"[..]));
+        assert_eq!(map.get(35), Some(&b"A"[..]));
+        assert_eq!(map.get(55), Some(&b"MSFT"[..]));
+        assert_eq!(map.get(10), Some(&b"089"[..]));
+        assert_eq!(map.get(9999), None);
+        assert!(map.get_all(9999).is_empty());
+    }
+
+    #[test]
+    fn decode_map_bad_checksum() {
+        let input = "8=FIX.4.4\x019=148\x0135=A\x0134=1080\x0149=TESTBUY1\x0152=20180920-18:14:19.508\x0156=TESTSELL1\x0111=636730640278898634\x0115=USD\x0121=2\x0138=7000\x0140=1\x0154=1\x0155=MSFT\x0160=20180920-18:14:19.492\x0110=000\x01";
+
+        let error = super::decode_map(input).expect_err("checksum is not valid");
+
+        assert!(matches!(error, Error::ChecksumMismatch { .. }));
+    }
+
+    #[test]
+    fn recompute_body_length() {
+        let options = DecodeOptions {
+            recompute_body_length: true,
+            ..DecodeOptions::default()
+        };
+
+        let input = "8=FIX.4.4\x0135=0\x0134=2\x0110=206\x01";
+
+        let error = Message::decode(input).expect_err("body length is missing");
+        assert!(matches!(error, Error::BodyLengthNotSecond { found: 35 }));
+
+        let message = Message::decode_with(input, &options).expect("body length is recomputed");
+        assert_eq!(message.body.fields.len(), 1);
+
+        let input = "8=FIX.4.4\x019=0\x0135=0\x0134=2\x0110=117\x01";
+
+        let error = Message::decode(input).expect_err("body length does not match");
+        assert!(matches!(
+            error,
+            Error::BodyLength {
+                expected: 0,
+                received: 10
+            }
+        ));
+
+        assert!(Message::decode_with(input, &options).is_ok());
+
+        // lenient mode still rejects a present, but wrong body length
+        let input = "8=FIX.4.4\x019=7\x0135=0\x0134=2\x0110=124\x01";
+        assert!(matches!(
+            Message::decode_with(input, &options),
+            Err(Error::BodyLength { .. })
+        ));
+    }
+
+    #[test]
+    fn max_field_value_len() {
+        let input = "8=FIX.4.4\x019=17\x0135=0\x0158=01234567\x0110=029\x01";
+
+        let options = |max_field_value_len| DecodeOptions {
+            max_field_value_len,
+            ..DecodeOptions::default()
+        };
+
+        assert!(Message::decode(input).is_ok());
+        assert!(Message::decode_with(input, &options(8)).is_ok());
+
+        assert_eq!(
+            Message::decode_with(input, &options(7)).expect_err("text is too long"),
+            Error::FieldValueTooLong { tag: 58, limit: 7 }
+        );
+
+        // the limit applies to the framing fields as well
+        assert_eq!(
+            Message::decode_with(input, &options(2)).expect_err("begin string is too long"),
+            Error::FieldValueTooLong { tag: 8, limit: 2 }
+        );
+
+        // the value is rejected before its end is found
+        let input = format!("8=FIX.4.4\x019=5\x0135=0\x0158={}", "x".repeat(100_000));
+
+        assert_eq!(
+            Message::decode(input).expect_err("text is too long"),
+            Error::FieldValueTooLong {
+                tag: 58,
+                limit: super::DEFAULT_MAX_FIELD_VALUE_LEN
+            }
+        );
+    }
+
+    #[test]
+    fn split_sections() {
+        let input = "8=FIX.4.4\x019=148\x0135=A\x0134=1080\x0149=TESTBUY1\x0152=20180920-18:14:19.508\x0156=TESTSELL1\x0111=636730640278898634\x0115=USD\x0121=2\x0138=7000\x0140=1\x0154=1\x0155=MSFT\x0160=20180920-18:14:19.492\x0110=089\x01";
+
+        let (header, body, trailer) = super::split_sections(input.as_bytes()).unwrap();
+
+        assert_eq!(
+            header,
+            b"8=FIX.4.4\x019=148\x0135=A\x0134=1080\x0149=TESTBUY1\x0152=20180920-18:14:19.508\x0156=TESTSELL1\x01"
+        );
+        assert_eq!(
+            body,
+            b"11=636730640278898634\x0115=USD\x0121=2\x0138=7000\x0140=1\x0154=1\x0155=MSFT\x0160=20180920-18:14:19.492\x01"
+        );
+        assert_eq!(trailer, b"10=089\x01");
+        assert_eq!([header, body, trailer].concat(), input.as_bytes());
+
+        // message without body fields
+        let input = "8=FIX.4.4\x019=5\x0135=0\x0110=163\x01";
+        let (header, body, trailer) = super::split_sections(input.as_bytes()).unwrap();
+
+        assert_eq!(header, b"8=FIX.4.4\x019=5\x0135=0\x01");
+        assert!(body.is_empty());
+        assert_eq!([header, body, trailer].concat(), input.as_bytes());
+
+        // header tags after the first body field belong to the body
+        let input = "8=FIX.4.4\x019=15\x0135=0\x0158=x\x0134=1\x0110=205\x01";
+        let (header, body, trailer) = super::split_sections(input.as_bytes()).unwrap();
+
+        assert_eq!(header, b"8=FIX.4.4\x019=15\x0135=0\x01");
+        assert_eq!(body, b"58=x\x0134=1\x01");
+        assert_eq!(trailer, b"10=205\x01");
+
+        assert!(super::split_sections(b"8=FIX.4.4\x019=5\x0135=0\x0110=000\x01").is_err());
+    }
+
+    #[test]
+    fn field_offsets() {
+        let input = b"8=FIX.4.4\x019=148\x0135=A\x0134=1080\x0149=TESTBUY1\x0152=20180920-18:14:19.508\x0156=TESTSELL1\x0111=636730640278898634\x0115=USD\x0121=2\x0138=7000\x0140=1\x0154=1\x0155=MSFT\x0160=20180920-18:14:19.492\x0110=089\x01";
+
+        let offsets = super::field_offsets(input).unwrap();
+        let fields: Vec<_> = offsets
+            .into_iter()
+            .map(|(tag, range)| (tag, &input[range]))
+            .collect();
+
+        assert_eq!(
+            fields,
+            [
+                (8, &b"FIX.4.4"[..]),
+                (9, b"148"),
+                (35, b"A"),
+                (34, b"1080"),
+                (49, b"TESTBUY1"),
+                (52, b"20180920-18:14:19.508"),
+                (56, b"TESTSELL1"),
+                (11, b"636730640278898634"),
+                (15, b"USD"),
+                (21, b"2"),
+                (38, b"7000"),
+                (40, b"1"),
+                (54, b"1"),
+                (55, b"MSFT"),
+                (60, b"20180920-18:14:19.492"),
+                (10, b"089"),
+            ]
+        );
+
+        // data values containing SOH are covered by a single range
+        let input = b"8=FIX.4.4\x019=24\x0135=0\x01212=8\x01213=<x>\x01</x>\x0110=199\x01";
+        let offsets = super::field_offsets(input).unwrap();
+
+        assert_eq!(offsets[4].0, 213);
+        assert_eq!(&input[offsets[4].1.clone()], b"<x>\x01</x>");
+
+        assert!(super::field_offsets(b"8=FIX.4.4\x019=5\x0135=0\x0110=000\x01").is_err());
+    }
+
+    #[test]
+    fn lenient_enums() {
+        // 40=Z is not a known order type
+        let input = "8=FIX.4.4\x019=10\x0135=D\x0140=Z\x0110=223\x01";
+
+        let error = Message::decode(input).expect_err("order type is unknown");
+        assert!(matches!(error, Error::BadValue(_)));
+
+        let options = DecodeOptions {
+            lenient_enums: true,
+            ..DecodeOptions::default()
+        };
+
+        let message = Message::decode_with(input, &options).expect("order type is kept");
+        assert_eq!(
+            message.body.fields,
+            vec![Field::Custom {
+                tag: 40,
+                value: b"Z".to_vec()
+            }]
+        );
+
+        // values of fields that are not enumerated are still validated
+        let input = "8=FIX.4.4\x019=15\x0135=D\x0140=Z\x0134=x\x0110=001\x01";
+
+        let error = Message::decode_with(input, &options).expect_err("sequence number is invalid");
+        assert_eq!(error, Error::MalformedNumericField { tag: 34 });
+    }
+
+    #[test]
+    fn lenient_values() {
+        let input = "8=FIX.4.4\x019=32\x0135=0\x0134=99999999999999999999999\x0110=151\x01";
+
+        let error = Message::decode(input).expect_err("sequence number overflows");
+        assert!(matches!(error, Error::BadValue(_)));
+
+        let options = DecodeOptions {
+            lenient_values: true,
+            ..DecodeOptions::default()
+        };
+
+        let message = Message::decode_with(input, &options).expect("sequence number is kept");
+        assert_eq!(
+            message.body.fields,
+            vec![Field::Custom {
+                tag: 34,
+                value: b"99999999999999999999999".to_vec()
+            }]
+        );
+        assert_eq!(message.degraded_tags().collect::<Vec<_>>(), vec![34]);
+
+        // malformed values are kept as well
+        let input = "8=FIX.4.4\x019=15\x0135=D\x0140=Z\x0134=x\x0110=001\x01";
+
+        let message = Message::decode_with(input, &options).expect("values are kept");
+        assert_eq!(message.degraded_tags().collect::<Vec<_>>(), vec![40, 34]);
+    }
+
+    #[test]
+    fn isin_instrument() {
+        let input = "8=FIX.4.4\x019=40\x0135=V\x0155=SAP\x0148=DE0007164600\x0122=4\x01167=CS\x0110=247\x01";
+
+        let message = Message::decode(input).expect("instrument fields are valid");
+
+        assert_eq!(
+            message.fields().cloned().collect::<Vec<_>>(),
+            vec![
+                Field::Symbol(b"SAP".to_vec()),
+                Field::SecurityID(b"DE0007164600".to_vec()),
+                Field::SecurityIDSource(SecurityIDSource::Isin),
+                Field::SecurityType(SecurityType::CommonStock),
+            ]
+        );
+        assert_eq!(message.encode(), input.as_bytes());
+
+        // private identification schemes from the user-defined range are accepted
+        let input = "8=FIX.4.4\x019=42\x0135=V\x0155=SAP\x0148=DE0007164600\x0122=100\x01167=CS\x0110=086\x01";
+        let message = Message::decode(input).expect("user-defined sources are valid");
+        assert!(
+            message
+                .fields()
+                .any(|field| *field == Field::SecurityIDSource(SecurityIDSource::UserDefined(100)))
+        );
+        assert_eq!(message.encode(), input.as_bytes());
+
+        // unknown identification schemes are rejected
+        let input = "8=FIX.4.4\x019=40\x0135=V\x0155=SAP\x0148=DE0007164600\x0122=Z\x01167=CS\x0110=029\x01";
+        assert!(matches!(Message::decode(input), Err(Error::BadValue(_))));
+    }
+
+    #[test]
+    fn raw_fields() {
+        let options = DecodeOptions {
+            raw_fields: true,
+            ..DecodeOptions::default()
+        };
+
+        // values are not validated, so an invalid sequence number is kept as well
+        let input = "8=FIX.4.4\x019=15\x0135=D\x0140=Z\x0134=x\x0110=001\x01";
+
+        let message = Message::decode_with(input, &options).expect("values are not parsed");
+        assert_eq!(message.msg_type(), &MsgType::NewOrderSingle);
+        assert_eq!(
+            message.fields().cloned().collect::<Vec<_>>(),
+            vec![
+                Field::Custom {
+                    tag: 40,
+                    value: b"Z".to_vec()
+                },
+                Field::Custom {
+                    tag: 34,
+                    value: b"x".to_vec()
+                },
+            ]
+        );
+
+        // framing is still validated
+        let input = "8=FIX.4.4\x019=15\x0135=D\x0140=Z\x0134=x\x0110=002\x01";
+        assert!(Message::decode_with(input, &options).is_err());
+    }
+
+    #[test]
+    fn field_validator() {
+        let options = DecodeOptions {
+            field_validator: Some(Arc::new(|field: &Field| match field {
+                Field::Symbol(symbol) if symbol == b"BANNED" => {
+                    Err("symbol is not tradable".to_string())
+                }
+                _ => Ok(()),
+            })),
+            ..DecodeOptions::default()
+        };
+
+        let input = "8=FIX.4.4\x019=13\x0135=V\x0155=MSFT\x0110=218\x01";
+        assert!(Message::decode_with(input, &options).is_ok());
+
+        let input = "8=FIX.4.4\x019=15\x0135=V\x0155=BANNED\x0110=074\x01";
+        assert_eq!(
+            Message::decode_with(input, &options).unwrap_err(),
+            Error::BadValue("symbol is not tradable".to_string())
+        );
+    }
+
+    #[test]
+    fn skip_leading_noise() {
+        let message = "8=FIX.4.4\x019=5\x0135=0\x0110=163\x01";
+        let options = DecodeOptions {
+            skip_leading_noise: true,
+            ..DecodeOptions::default()
+        };
+
+        for noise in [&b"\x01"[..], b"\xEF\xBB\xBF", b"\x01 \r\n\xEF\xBB\xBF\x01"] {
+            let input = [noise, message.as_bytes()].concat();
+
+            // strict decoding points at the first unexpected byte
+            assert_eq!(
+                Message::decode(&input).unwrap_err(),
+                Error::Lexer(LexError::Unexpected {
+                    expected: b'=',
+                    but_got: noise[0],
+                    offset: 0
+                })
+            );
+
+            let message = Message::decode_with(&input, &options).expect("noise is skipped");
+            assert_eq!(message.msg_type(), &MsgType::Heartbeat);
+        }
+
+        // only leading noise is skipped
+        let input = "\x018=FIX.4.4\x019=5\x01\x0135=0\x0110=164\x01";
+        assert!(Message::decode_with(input, &options).is_err());
+    }
+
+    #[test]
+    fn validate_printable_ascii() {
+        let options = DecodeOptions {
+            validate_printable_ascii: true,
+            ..DecodeOptions::default()
+        };
+
+        let input = "8=FIX.4.4\x019=13\x0135=5\x0158=B\x07ye\x0110=169\x01";
+        assert!(Message::decode(input).is_ok());
+        assert_eq!(
+            Message::decode_with(input, &options).unwrap_err(),
+            Error::NonPrintableValue { tag: 58 }
+        );
+
+        // binary data fields are exempt
+        let input = b"8=FIX.4.4\x019=17\x0135=5\x0195=3\x0196=\x07\x01\xFF\x0110=110\x01";
+        let msg = Message::decode_with(input, &options).expect("data fields may be binary");
+        assert_eq!(msg.msg_type(), &MsgType::Logout);
+
+        // so are the encoded text fields, which may contain SOH and non-ASCII bytes
+        let input =
+            b"8=FIX.4.4\x019=22\x0135=5\x01354=6\x01355=\xC3\xA9t\x01\xC3\xA9\x0110=015\x01";
+        let msg = Message::decode_with(input, &options).expect("encoded fields may be binary");
+        assert_eq!(msg.fields().map(Field::tag).collect::<Vec<_>>(), [354, 355]);
+        assert_eq!(msg.encode(), input.as_slice());
+    }
+
+    #[test]
+    fn strip_line_endings() {
+        let options = DecodeOptions {
+            strip_line_endings: true,
+            ..DecodeOptions::default()
+        };
+
+        let input = "8=FIX.4.4\x019=16\x0135=5\x0158=hello\r\n\x0110=176\x01";
+
+        let strict = Message::decode(input).expect("message is valid");
+        assert_eq!(strict.body.fields, vec![Field::Text(b"hello\r\n".to_vec())]);
+
+        let lenient = Message::decode_with(input, &options).expect("message is valid");
+        assert_eq!(lenient.body.fields, vec![Field::Text(b"hello".to_vec())]);
+
+        // line endings inside of values are kept
+        let input = "8=FIX.4.4\x019=16\x0135=5\x0158=he\r\nllo\x0110=176\x01";
+        let lenient = Message::decode_with(input, &options).expect("message is valid");
+        assert_eq!(
+            lenient.body.fields,
+            vec![Field::Text(b"he\r\nllo".to_vec())]
+        );
+
+        // including the trailer
+        let input = "8=FIX.4.4\x019=16\x0135=5\x0158=hello\r\n\x0110=176\r\n";
+        assert!(Message::decode(input).is_err());
+        assert!(Message::decode_with(input, &options).is_ok());
+    }
+
+    #[test]
+    fn dedup_header_fields() {
+        let input = "8=FIX.4.4\x019=58\x0135=0\x0134=2\x0152=20180920-18:14:19\x0152=20180920-18:14:20\x0158=Hi\x0110=046\x01";
+        let sending_time =
+            |value: &[u8]| Field::SendingTime(UtcTimestamp::from_fix_bytes(value).unwrap());
+
+        // strict decoding keeps both, which the header validation reports
+        let strict = Message::decode(input).expect("message is valid");
+        assert_eq!(strict.body.fields.len(), 4);
+        assert_eq!(
+            strict.validate_header().unwrap_err(),
+            vec![
+                HeaderError::MissingField(49),
+                HeaderError::MissingField(56),
+                HeaderError::DuplicateField(52)
+            ]
+        );
+
+        let decode = |keep| {
+            let options = DecodeOptions {
+                dedup_header_fields: Some(keep),
+                ..DecodeOptions::default()
+            };
+            Message::decode_with(input, &options).expect("message is valid")
+        };
+
+        assert_eq!(
+            decode(Occurrence::First).body.fields,
+            vec![
+                Field::MsgSeqNum(2),
+                sending_time(b"20180920-18:14:19"),
+                Field::Text(b"Hi".to_vec())
+            ]
+        );
+        assert_eq!(
+            decode(Occurrence::Last).body.fields,
+            vec![
+                Field::MsgSeqNum(2),
+                sending_time(b"20180920-18:14:20"),
+                Field::Text(b"Hi".to_vec())
+            ]
+        );
+
+        // the dropped fields are reported
+        assert!(strict.dropped_tags().is_empty());
+        assert_eq!(decode(Occurrence::First).dropped_tags(), [52]);
+        assert_eq!(decode(Occurrence::Last).dropped_tags(), [52]);
+
+        // the hop fields are kept, as they repeat once per hop
+        let input = "8=FIX.4.4\x019=60\x0135=0\x0134=2\x0152=20180920-18:14:19\x01627=2\x01628=HOP1\x01628=HOP2\x0134=3\x0110=147\x01";
+        let options = DecodeOptions {
+            dedup_header_fields: Some(Occurrence::First),
+            ..DecodeOptions::default()
+        };
+        let msg = Message::decode_with(input, &options).expect("message is valid");
+
+        assert_eq!(
+            msg.fields().map(Field::tag).collect::<Vec<_>>(),
+            [34, 52, 627, 628, 628]
+        );
+        assert_eq!(msg.dropped_tags(), [34]);
+    }
+
+    #[test]
+    fn trailing_message() {
+        let message = "8=FIX.4.4\x019=10\x0135=0\x0134=1\x0110=165\x01";
+        let input = format!("{message}{message}");
+
+        assert_eq!(
+            Message::decode(&input).unwrap_err(),
+            Error::TrailingMessage {
+                offset: message.len()
+            }
+        );
+
+        // other fields after the checksum are not a message
+        let input = format!("{message}58=Hi\x01");
+        assert_eq!(
+            Message::decode(&input).unwrap_err(),
+            Error::UnexpectedChecksum
+        );
+    }
+
+    #[test]
+    fn peek_msg_type_bytes() {
+        let input = b"8=FIX.4.4\x019=5\x0135=0\x0110=000\x01";
+        let msg_type = super::peek_msg_type_bytes(input).expect("leading fields are valid");
+
+        // the value is borrowed from the input
+        assert_eq!(msg_type, b"0");
+        assert_eq!(msg_type.as_ptr(), input[17..].as_ptr());
+
+        assert_eq!(
+            super::peek_msg_type_bytes(b"8=FIX.4.4\x019=5\x0134=1\x01").unwrap_err(),
+            Error::MsgTypeNotThird { found: 34 }
+        );
+    }
+
+    #[test]
+    fn malformed_numeric_field() {
+        for input in [
+            "8=FIX.4.4\x019=11\x0135=0\x0134= 5\x0110=202\x01",
+            "8=FIX.4.4\x019=11\x0135=0\x0134=5 \x0110=202\x01",
+        ] {
+            assert_eq!(
+                Message::decode(input).unwrap_err(),
+                Error::MalformedNumericField { tag: 34 }
+            );
+        }
+
+        // framing fields are checked as well
+        let input = "8=FIX.4.4\x019= 10\x0135=0\x0134=5\x0110=197\x01";
+        assert_eq!(
+            Message::decode(input).unwrap_err(),
+            Error::MalformedNumericField { tag: 9 }
+        );
+
+        // values out of range are not malformed
+        let input = "8=FIX.4.4\x019=29\x0135=0\x0134=99999999999999999999\x0110=242\x01";
+        assert!(matches!(
+            Message::decode(input).unwrap_err(),
+            Error::BadValue(_)
+        ));
+    }
+
+    #[test]
+    fn decode_into() {
+        let mut fields = vec![Field::MsgSeqNum(99)];
+
+        let input = "8=FIX.4.4\x019=17\x0135=5\x0134=2\x0158=Bye\x0110=125\x01";
+        let (begin_string, msg_type) =
+            super::decode_into(input, &mut fields).expect("message is valid");
+
+        assert_eq!(begin_string, BeginString::FIX44);
+        assert_eq!(msg_type, MsgType::Logout);
+        assert_eq!(
+            fields,
+            vec![Field::MsgSeqNum(2), Field::Text(b"Bye".to_vec())]
+        );
+
+        // the buffer keeps its capacity for the next decode
+        let capacity = fields.capacity();
+        let input = "8=FIX.4.4\x019=10\x0135=0\x0134=1\x0110=165\x01";
+        super::decode_into(input, &mut fields).expect("message is valid");
+
+        assert_eq!(fields, vec![Field::MsgSeqNum(1)]);
+        assert_eq!(fields.capacity(), capacity);
+    }
+
+    #[test]
+    fn peek() {
+        let input = "8=FIX.4.4\x019=148\x0135=A\x0134=10";
+
+        assert!(matches!(
+            super::peek_begin_string(input),
+            Ok(BeginString::FIX44)
+        ));
+        assert!(matches!(super::peek_msg_type(input), Ok(MsgType::Logon)));
+
+        // only the beginning of the message is needed
+        assert!(matches!(
+            super::peek_begin_string("8=FIX.4.4\x01"),
+            Ok(BeginString::FIX44)
+        ));
+
+        let error = super::peek_begin_string("8=FIX.9.9\x019=5\x01").expect_err("unknown version");
+        assert!(matches!(error, Error::UnsupportedBeginString(raw) if raw == b"FIX.9.9"));
+
+        let error = super::peek_begin_string("9=5\x01").expect_err("missing begin string");
+        assert!(matches!(error, Error::BadTag(9)));
+
+        let error = super::peek_msg_type("8=FIX.4.4\x019=5\x0134=1\x01").expect_err("no msg type");
+        assert!(matches!(error, Error::MsgTypeNotThird { found: 34 }));
+    }
+
+    #[test]
+    fn peek_seq_num() {
+        let input = "8=FIX.4.4\x019=148\x0135=A\x0134=1080\x0149=TESTBUY1";
+        assert_eq!(super::peek_seq_num(input), Ok(Some(1080)));
+
+        // later in the header, after a data field containing a SOH
+        let input = "8=FIX.4.4\x019=5\x0135=0\x0149=S\x0190=3\x0191=\x0134\x0134=7\x01";
+        assert_eq!(super::peek_seq_num(input), Ok(Some(7)));
+
+        // lexing stops at the first body field
+        let input = "8=FIX.4.4\x019=5\x0135=4\x0136=10\x0134=7\x01";
+        assert_eq!(super::peek_seq_num(input), Ok(None));
+
+        let input = "8=FIX.4.4\x019=5\x0135=0\x0149=S\x01";
+        assert_eq!(super::peek_seq_num(input), Ok(None));
+
+        let input = "8=FIX.4.4\x019=5\x0135=0\x0134=x\x01";
+        assert_eq!(
+            super::peek_seq_num(input),
+            Err(Error::MalformedNumericField { tag: 34 })
+        );
+
+        let input = "8=FIX.4.4\x0135=0\x0134=1\x01";
+        assert_eq!(
+            super::peek_seq_num(input),
+            Err(Error::BodyLengthNotSecond { found: 35 })
+        );
+    }
+
+    /// Calculates the body length as defined by the FIX specification: number of bytes starting
+    /// with the first byte of tag `35`, up to and including the SOH before tag `10`.
+    fn spec_body_length(bytes: &[u8]) -> usize {
+        let position = |pattern: &[u8]| {
+            bytes
+                .windows(pattern.len())
+                .rposition(|window| window == pattern)
+                .expect("pattern is present")
+        };
+
+        let first_byte_of_msg_type = position(b"\x0135=") + 1;
+        let soh_before_checksum = position(b"\x0110=");
+
+        soh_before_checksum + 1 - first_byte_of_msg_type
+    }
+
+    #[test]
+    fn body_length_boundaries() {
+        // value lengths chosen to move body length across digit boundaries
+        for value_len in [0, 1, 9, 10, 85, 86, 99, 100, 985] {
+            for field_count in 0..5 {
+                let mut builder = Message::builder(BeginString::FIX44, MsgType::Heartbeat)
+                    .with_field(Field::MsgSeqNum(1));
+
+                for tag in 0..field_count {
+                    builder = builder.with_field(Field::Custom {
+                        tag: 5000 + tag,
+                        value: vec![b'x'; value_len],
+                    });
+                }
+
+                let encoded = builder.build().encode();
+                let expected = spec_body_length(&encoded);
+
+                let map = super::decode_map(&encoded).expect("encoded message is valid");
+                assert_eq!(map.get(9), Some(expected.to_string().as_bytes()));
+
+                // off-by-one body lengths must be rejected, reporting the exact received length
+                let encoded = String::from_utf8(encoded.to_vec()).unwrap();
+
+                for wrong in [expected - 1, expected + 1] {
+                    let input = encoded.replacen(
+                        &format!("\x019={expected}\x01"),
+                        &format!("\x019={wrong}\x01"),
+                        1,
+                    );
+
+                    let error = Message::decode(input).expect_err("body length is off by one");
+
+                    assert!(
+                        matches!(
+                            error,
+                            Error::BodyLength { received, expected: got } if received == expected && got == wrong
+                        ),
+                        "{error}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn decode_with_interner() {
+        let interner = Interner::new();
+
+        let heartbeat = |seq_num| {
+            Message::builder(BeginString::FIX44, MsgType::Heartbeat)
+                .with_field(Field::MsgSeqNum(seq_num))
+                .with_field(Field::SenderCompID(b"SENDER".into()))
+                .with_field(Field::TargetCompID(b"TARGET".into()))
+                .build()
+                .encode()
+        };
+
+        let first = super::decode_with_interner(heartbeat(1), &interner).unwrap();
+        let second = super::decode_with_interner(heartbeat(2), &interner).unwrap();
+
+        assert_eq!(interner.len(), 2);
+
+        let (Field::SenderCompID(first_sender), Field::SenderCompID(second_sender)) =
+            (&first.body.fields[1], &second.body.fields[1])
+        else {
+            panic!("sender comp ids are decoded");
+        };
+
+        assert_eq!(first_sender, &CompID::from(b"SENDER"));
+        assert!(CompID::ptr_eq(first_sender, second_sender));
+
+        // interning does not change the decoded contents
+        let plain = Message::decode(heartbeat(2)).unwrap();
+        assert_eq!(plain.body.fields, second.body.fields);
+    }
+
+    #[test]
+    fn large_tag() {
+        let encoded = Message::builder(BeginString::FIX44, MsgType::Heartbeat)
+            .with_field(Field::Custom {
+                tag: 100_000,
+                value: b"x".to_vec(),
+            })
+            .build()
+            .encode();
+
+        assert!(encoded.windows(8).any(|window| window == b"\x01100000="));
+
+        let message = Message::decode(&encoded).expect("encoded message is valid");
+
+        assert_eq!(
+            message.body.fields,
+            vec![Field::Custom {
+                tag: 100_000,
+                value: b"x".to_vec()
+            }]
+        );
+
+        // tags beyond u32 are still rejected
+        let input = "8=FIX.4.4\x019=20\x0135=0\x014294967296=x\x0110=000\x01";
+        let error = Message::decode(input).expect_err("tag overflows");
+
+        assert!(matches!(
+            error,
+            Error::Lexer(LexError::MalformedTag { offset: 20 })
+        ));
+    }
+
+    #[test]
+    fn decode_validated() {
+        let replace = Message::builder(BeginString::FIX44, MsgType::OrderCancelReplaceRequest)
+            .with_field(Field::ClOrdID(b"ORDER-2".to_vec()));
+
+        let missing = replace.clone().build().encode();
+        assert!(super::decode(&missing).is_ok());
+        assert!(matches!(
+            super::decode_validated(&missing),
+            Err(Error::MissingMandatoryField("TransactTime"))
+        ));
+
+        let date = LocalMktDate::new(2018, 9, 20).unwrap();
+        let present = replace
+            .with_field(Field::TransactTime(
+                UtcTimestamp::new(date, 18, 14, 19).unwrap(),
+            ))
+            .build()
+            .encode();
+        let message = super::decode_validated(&present).expect("TransactTime is present");
+        assert_eq!(message.msg_type(), &MsgType::OrderCancelReplaceRequest);
+
+        // message types without mandatory body fields are not affected
+        let heartbeat = Message::builder(BeginString::FIX44, MsgType::Heartbeat)
+            .with_field(Field::MsgSeqNum(1))
+            .build()
+            .encode();
+        assert!(super::decode_validated(heartbeat).is_ok());
     }
 }
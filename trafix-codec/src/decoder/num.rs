@@ -19,6 +19,10 @@ pub(crate) enum ParseIntError {
 /// Helper trait for parsing of integers from byte slices directly. Standard library exposes
 /// parsing of integers for [`str`], but not for [`&[u8]`]. That is taken care of with this
 /// extension trait.
+///
+/// A leading `-` sign is accepted for signed types only, and must be followed by at least one
+/// digit (`-0` parses as `0`). Unlike the standard library, a leading `+` sign is rejected with
+/// [`ParseIntError::InvalidDigit`], as FIX integer values never carry it.
 pub(crate) trait ParseFixInt {
     /// Parses integer from byte slice, or returns a [`ParseIntError`] if byte slice does not
     /// contain valid integer.
@@ -42,7 +46,7 @@ macro_rules! impl_for {
                 let mut value: $type = 0;
                 let is_negative = if bytes.starts_with(b"-") {
                     if $is_signed {
-                        bytes = bytes.get(1..).ok_or(ParseIntError::Empty)?;
+                        bytes = &bytes[1..];
                         true
                     } else {
                         return Err(ParseIntError::Overflow);
@@ -51,6 +55,11 @@ macro_rules! impl_for {
                     false
                 };
 
+                // no digits at all, or just the sign
+                if bytes.is_empty() {
+                    return Err(ParseIntError::Empty);
+                }
+
                 for byte in bytes {
                     value = value.checked_mul(10).ok_or(ParseIntError::Overflow)?;
 
@@ -140,6 +149,29 @@ mod tests {
         assert_eq!(res, Ok(-100));
     }
 
+    #[test]
+    fn signs() {
+        assert_eq!(i8::parse_fix_int(b"-0"), Ok(0));
+        assert_eq!(i64::parse_fix_int(b"-0"), Ok(0));
+        assert_eq!(u8::parse_fix_int(b"-0"), Err(ParseIntError::Overflow));
+
+        // sign without digits
+        assert_eq!(i8::parse_fix_int(b"-"), Err(ParseIntError::Empty));
+        assert_eq!(u8::parse_fix_int(b"-"), Err(ParseIntError::Overflow));
+
+        // leading plus is not accepted
+        assert_eq!(i8::parse_fix_int(b"+5"), Err(ParseIntError::InvalidDigit));
+        assert_eq!(u8::parse_fix_int(b"+5"), Err(ParseIntError::InvalidDigit));
+        assert_eq!(i8::parse_fix_int(b"-+5"), Err(ParseIntError::InvalidDigit));
+        assert_eq!(i8::parse_fix_int(b"--5"), Err(ParseIntError::InvalidDigit));
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(u8::parse_fix_int(b""), Err(ParseIntError::Empty));
+        assert_eq!(i8::parse_fix_int(b""), Err(ParseIntError::Empty));
+    }
+
     #[test]
     fn non_digits() {
         let res = u8::parse_fix_int(b"abc");
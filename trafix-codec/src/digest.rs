@@ -6,38 +6,108 @@
 ///
 /// # Example
 ///
-/// ```ignore
+/// ```
+/// use trafix_codec::digest::Digest;
+///
 /// let mut digest = Digest::default();
 /// digest.push(&[1, 2, 3]);
-/// let checksum = digest.checksum();
 ///
 /// // (1 + 2 + 3) % 256 = 6
-/// assert_eq!(checksum, 6);
+/// assert_eq!(digest.checksum(), 6);
 ///
 /// // (1 + 2 + 3 + 251) % 256 = 257 % 256 = 1
-/// digest.push(251);
-/// assert_eq!(checksum, 1);
+/// digest.push(&[251]);
+/// assert_eq!(digest.checksum(), 1);
+/// assert_eq!(digest.sum_u32(), 257);
 /// ```
-#[derive(Default)]
-pub(crate) struct Digest {
-    /// Accumulated checksum value computed as the modulo-256 sum of all bytes
-    /// processed, per the FIX protocol checksum definition.
-    checksum: u8,
+#[derive(Default, Debug, Clone)]
+pub struct Digest {
+    /// Accumulated sum of all bytes processed, wrapping on overflow. As 2^32 is a multiple of
+    /// 256, wrapping preserves the modulo-256 checksum.
+    sum: u32,
 }
 
 impl Digest {
-    /// Updates the running checksum using the contents of a [`BytesMut`].
+    /// Updates the running checksum with the given bytes, e.g. a byte slice or a
+    /// [`BytesMut`](bytes::BytesMut).
     ///
     /// This performs modulo-256 addition across all bytes, matching the FIX
     /// checksum algorithm.
-    pub fn push(&mut self, input: &impl AsRef<[u8]>) {
+    pub fn push(&mut self, input: impl AsRef<[u8]>) {
         for &b in input.as_ref() {
-            self.checksum = self.checksum.wrapping_add(b);
+            self.sum = self.sum.wrapping_add(u32::from(b));
         }
     }
 
+    /// Adds the bytes summed by another [`Digest`] to this one, as if they had been pushed to
+    /// it, e.g. to combine the digest of a body with the digest of a newly encoded header.
+    ///
+    /// ```
+    /// use trafix_codec::digest::Digest;
+    ///
+    /// let mut header = Digest::default();
+    /// header.push(b"8=FIX.4.4\x01");
+    ///
+    /// let mut body = Digest::default();
+    /// body.push(b"35=0\x01");
+    ///
+    /// let mut whole = Digest::default();
+    /// whole.push(b"8=FIX.4.4\x0135=0\x01");
+    ///
+    /// header.merge(&body);
+    /// assert_eq!(header.checksum(), whole.checksum());
+    /// ```
+    pub fn merge(&mut self, other: &Digest) {
+        self.sum = self.sum.wrapping_add(other.sum);
+    }
+
     /// Returns the calculated checksum of bytes pushed so far.
+    #[must_use]
     pub fn checksum(&self) -> u8 {
-        self.checksum
+        // the lowest byte of the sum is its remainder of a division by 256
+        self.sum.to_le_bytes()[0]
+    }
+
+    /// Returns the raw sum of bytes pushed so far, before taking it modulo 256, e.g. to
+    /// inspect intermediate sums when debugging checksum mismatches. The sum wraps around on
+    /// overflow, i.e. after roughly 16 MiB of `0xFF` bytes.
+    #[must_use]
+    pub fn sum_u32(&self) -> u32 {
+        self.sum
+    }
+}
+
+/// The [`Digest`] of the body of a decoded message, i.e. of the bytes counted by the
+/// `BodyLength` (`9`), along with the raw body bytes it was calculated over.
+///
+/// Keeping the raw bytes allows forwarding an unchanged message without encoding or summing its
+/// body again, see [`Message::encode_with_body_digest`].
+///
+/// [`Message::encode_with_body_digest`]: crate::message::Message::encode_with_body_digest
+#[derive(Debug, Clone)]
+pub struct BodyDigest<'input> {
+    /// Raw bytes of the body, from the `MsgType` (`35`) up to the `CheckSum` (`10`).
+    body: &'input [u8],
+
+    /// Digest of `body`.
+    digest: Digest,
+}
+
+impl<'input> BodyDigest<'input> {
+    /// Creates a new [`BodyDigest`] from the raw body bytes and their digest.
+    pub(crate) fn new(body: &'input [u8], digest: Digest) -> Self {
+        Self { body, digest }
+    }
+
+    /// Returns the raw bytes of the body the digest was calculated over.
+    #[must_use]
+    pub fn body(&self) -> &'input [u8] {
+        self.body
+    }
+
+    /// Returns the digest of the body.
+    #[must_use]
+    pub fn digest(&self) -> &Digest {
+        &self.digest
     }
 }
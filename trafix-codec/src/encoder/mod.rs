@@ -6,26 +6,276 @@ use bytes::{BufMut, Bytes, BytesMut};
 
 use crate::{
     constants,
-    digest::Digest,
-    message::{Body, Header, field::Field},
+    digest::{BodyDigest, Digest},
+    message::{Body, Header, Message, field::Field},
 };
 
 /// Average bytes per field in a FIX Message. We can safely assume that the average number of bytes
 /// per field is around 15 bytes as per our measurements.
 const AVERAGE_BYTES_PER_FIELD: usize = 15;
 
+/// Maximum `BodyLength` (16 MiB) of messages produced by [`try_encode`]. FIX does not define an
+/// upper bound itself, but counterparties commonly reject messages beyond a few megabytes, and
+/// the limit keeps the length well within 32-bit counters used by other implementations.
+pub const MAX_BODY_LENGTH: usize = 16 * 1024 * 1024;
+
+/// Number of digits reserved for the `BodyLength` by [`EncodeOptions::single_pass`], fitting
+/// bodies of up to 9999 bytes, which covers most session and order messages.
+const SINGLE_PASS_BODY_LENGTH_DIGITS: usize = 4;
+
+/// Possible errors during encoding of [`Message`]s.
+///
+/// [`Message`]: crate::message::Message
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum Error {
+    /// Message body is longer than [`MAX_BODY_LENGTH`].
+    #[error("body length {length} exceeds the maximum of {max} bytes")]
+    BodyTooLong {
+        /// `BodyLength` of the message that was being encoded.
+        length: usize,
+
+        /// Maximum allowed `BodyLength`.
+        max: usize,
+    },
+
+    /// Message contains a regular field with one of the framing tags (`8`, `9`, `10` or `35`),
+    /// which the encoder emits on its own.
+    #[error("field with reserved tag {0} must not be added to the message")]
+    ReservedTag(u32),
+
+    /// Message contains a `Text` (`58`) field longer than [`EncodeOptions::max_text_len`].
+    #[error("text length {length} exceeds the maximum of {max} bytes")]
+    TextTooLong {
+        /// Length of the `Text` value that was being encoded.
+        length: usize,
+
+        /// Maximum allowed length of the `Text` value.
+        max: usize,
+    },
+}
+
+/// Values used instead of the calculated `BodyLength` and `CheckSum` fields when encoding a
+/// message with [`Message::encode_with_overrides`].
+///
+/// This is strictly a testing aid, used to craft malformed messages, e.g. for validating how a
+/// counterparty handles them.
+///
+/// [`Message::encode_with_overrides`]: crate::message::Message::encode_with_overrides
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct FramingOverrides {
+    /// Value emitted as `BodyLength` (`9`), if set.
+    pub body_length: Option<usize>,
+
+    /// Value emitted as `CheckSum` (`10`), if set.
+    pub checksum: Option<u8>,
+}
+
+/// Options configuring the wire format produced by the encoder.
+///
+/// The [`Default`] options produce standard FIX messages. Individual aspects can be adjusted for
+/// transports with their own framing, e.g. length-prefixed transports that do not expect a SOH
+/// after the `CheckSum`:
+/// ```
+/// use trafix_codec::message::{
+///     EncodeOptions, Message,
+///     field::{
+///         Field,
+///         value::{begin_string::BeginString, msg_type::MsgType},
+///     },
+/// };
+///
+/// let options = EncodeOptions {
+///     trailing_soh: false,
+///     ..EncodeOptions::default()
+/// };
+///
+/// let encoded = Message::builder(BeginString::FIX44, MsgType::Heartbeat)
+///     .with_field(Field::MsgSeqNum(1))
+///     .build()
+///     .encode_with(&options);
+///
+/// assert!(encoded.ends_with(b"\x0110=165"));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct EncodeOptions {
+    /// Whether the `CheckSum` (`10`) field is terminated with a SOH, as required by the FIX
+    /// specification. The SOH is not part of the `BodyLength` nor the `CheckSum`, so omitting it
+    /// does not affect them.
+    pub trailing_soh: bool,
+
+    /// Byte terminating each field, SOH (`0x01`) by default. Other delimiters, e.g. `|`, produce
+    /// non-standard messages for debugging or for dialects using a different separator. The
+    /// `BodyLength` and `CheckSum` are calculated over the emitted bytes, so they match the
+    /// chosen delimiter.
+    pub delimiter: u8,
+
+    /// Maximum length of `Text` (`58`) values in bytes, if any. Many venues silently drop
+    /// over-long texts, so [`Message::try_encode_with`] fails with [`Error::TextTooLong`] instead
+    /// of emitting them, see [`text::truncated`] for shortening texts to the limit. The
+    /// infallible encoding functions ignore the limit.
+    ///
+    /// [`Message::try_encode_with`]: crate::message::Message::try_encode_with
+    /// [`text::truncated`]: crate::message::field::value::text::truncated
+    pub max_text_len: Option<usize>,
+
+    /// Whether the message is written in a single pass, reserving a fixed number of digits for
+    /// the `BodyLength` (`9`) and backfilling them once the body is written, instead of
+    /// encoding the body into a separate buffer first. This saves copying the body for small
+    /// messages. The output is byte-identical, and bodies too long for the reserved digits fall
+    /// back to the regular encoding.
+    pub single_pass: bool,
+}
+
+impl Default for EncodeOptions {
+    fn default() -> Self {
+        Self {
+            trailing_soh: true,
+            delimiter: constants::SOH,
+            max_text_len: None,
+            single_pass: false,
+        }
+    }
+}
+
 /// Encodes a full FIX message (header + body + trailer) into a final wire-format `Bytes` buffer
 /// during which fields `BodyLength` and `Checksum` are calculated and set.
 pub(crate) fn encode(header: &Header, body: &Body) -> Bytes {
-    let regular_fields = encode_regular_fields(header, body);
-    let message = encode_framing_headers(header, &regular_fields);
-    finalize_message(message)
+    encode_with(header, body, &EncodeOptions::default())
+}
+
+/// Same as [`encode`], with the wire format configured by the given [`EncodeOptions`].
+pub(crate) fn encode_with(header: &Header, body: &Body, options: &EncodeOptions) -> Bytes {
+    let regular_fields = if options.single_pass {
+        match encode_single_pass(header, body, options) {
+            Ok(message) => return message,
+            Err(regular_fields) => regular_fields,
+        }
+    } else {
+        encode_regular_fields(header, body, options)
+    };
+    let message = encode_framing_headers(header, &regular_fields, regular_fields.len(), options);
+    finalize_message(message, None, options)
+}
+
+/// Same as [`encode`], but emits the `BodyLength` and `Checksum` values set in the given
+/// [`FramingOverrides`] instead of the calculated ones.
+pub(crate) fn encode_with_overrides(
+    header: &Header,
+    body: &Body,
+    overrides: FramingOverrides,
+) -> Bytes {
+    let options = EncodeOptions::default();
+    let regular_fields = encode_regular_fields(header, body, &options);
+    let body_length = overrides.body_length.unwrap_or(regular_fields.len());
+    let message = encode_framing_headers(header, &regular_fields, body_length, &options);
+    finalize_message(message, overrides.checksum, &options)
+}
+
+/// Encodes a full FIX message from the `BeginString` of the given header and the raw body the
+/// given [`BodyDigest`] was calculated over, e.g. when forwarding a message decoded with
+/// [`decode_with_digest`] unchanged. The body is copied as it is, and the `CheckSum` is
+/// calculated from the digest, so the body is neither encoded nor summed again.
+///
+/// [`decode_with_digest`]: crate::decoder::decode_with_digest
+pub(crate) fn encode_with_body_digest(header: &Header, body_digest: &BodyDigest<'_>) -> Bytes {
+    let options = EncodeOptions::default();
+    let body = body_digest.body();
+
+    let mut message = BytesMut::with_capacity(body.len() + (3 * AVERAGE_BYTES_PER_FIELD));
+    write_framing_headers(header, body.len(), &options, &mut message);
+
+    let mut digest = Digest::default();
+    digest.push(&message);
+    digest.merge(body_digest.digest());
+
+    message.extend_from_slice(body);
+    finalize_message(message, Some(digest.checksum()), &options)
+}
+
+/// Encodes only the header section, i.e. the `BeginString` (`8`), the `MsgType` (`35`) and the
+/// optional header fields, each terminated with SOH. The `BodyLength` (`9`) is omitted, as it
+/// depends on the body.
+pub(crate) fn encode_header(header: &Header) -> Bytes {
+    let options = EncodeOptions::default();
+    let mut message = BytesMut::with_capacity((header.fields.len() + 2) * AVERAGE_BYTES_PER_FIELD);
+
+    let begin_string = Field::Custom {
+        tag: 8,
+        value: header.begin_string.into(),
+    };
+    write_field(&begin_string, &options, &mut message);
+
+    write_regular_fields(header, &Body::default(), &options, &mut message);
+
+    message.freeze()
+}
+
+/// Same as [`encode`], but fails if the message contains fields with reserved tags, or if the
+/// `BodyLength` of the message exceeds [`MAX_BODY_LENGTH`].
+pub(crate) fn try_encode(header: &Header, body: &Body) -> Result<Bytes, Error> {
+    try_encode_with(header, body, &EncodeOptions::default())
+}
+
+/// Same as [`try_encode`], with the wire format configured by the given [`EncodeOptions`]. Also
+/// fails if a `Text` (`58`) field exceeds [`EncodeOptions::max_text_len`].
+pub(crate) fn try_encode_with(
+    header: &Header,
+    body: &Body,
+    options: &EncodeOptions,
+) -> Result<Bytes, Error> {
+    if let Some(tag) = find_reserved_tag(header, body) {
+        return Err(Error::ReservedTag(tag));
+    }
+
+    if let Some(max) = options.max_text_len {
+        for field in header.fields.iter().chain(&body.fields) {
+            if let Field::Text(text) = field
+                && text.len() > max
+            {
+                return Err(Error::TextTooLong {
+                    length: text.len(),
+                    max,
+                });
+            }
+        }
+    }
+
+    // messages fitting the reserved digits of the single pass are well within the limit
+    let regular_fields = if options.single_pass {
+        match encode_single_pass(header, body, options) {
+            Ok(message) => return Ok(message),
+            Err(regular_fields) => regular_fields,
+        }
+    } else {
+        encode_regular_fields(header, body, options)
+    };
+
+    if regular_fields.len() > MAX_BODY_LENGTH {
+        return Err(Error::BodyTooLong {
+            length: regular_fields.len(),
+            max: MAX_BODY_LENGTH,
+        });
+    }
+
+    let message = encode_framing_headers(header, &regular_fields, regular_fields.len(), options);
+    Ok(finalize_message(message, None, options))
+}
+
+/// Returns the tag of the first header or body field that uses one of the reserved framing tags,
+/// if any.
+pub(crate) fn find_reserved_tag(header: &Header, body: &Body) -> Option<u32> {
+    header
+        .fields
+        .iter()
+        .chain(&body.fields)
+        .map(Field::tag)
+        .find(|tag| constants::STRUCTURAL_TAGS.contains(tag))
 }
 
 /// Encodes all regular fields (`MsgType`, optional header fields, body fields)
 /// starting at tag 35 and ending before tag 10.
 #[must_use]
-fn encode_regular_fields(header: &Header, body: &Body) -> BytesMut {
+fn encode_regular_fields(header: &Header, body: &Body, options: &EncodeOptions) -> BytesMut {
     // reserving the capacity, counting that each field has AT LEAST 4 bytes b"X=Y\x01" to
     // reduce the number of resizings.
     //
@@ -34,67 +284,137 @@ fn encode_regular_fields(header: &Header, body: &Body) -> BytesMut {
         (header.fields.len() + body.fields.len() + 1) * AVERAGE_BYTES_PER_FIELD,
     );
 
-    // MsgType with included SOH char
-    message.extend_from_slice(
-        Field::Custom {
-            tag: 35,
-            value: header.msg_type.into(),
-        }
-        .encode()
-        .as_ref(),
-    );
-    message.put_u8(constants::SOH);
+    write_regular_fields(header, body, options, &mut message);
 
-    // Optional header fields
-    for field in &header.fields {
-        // field with included SOH char.. x=ab\x01
-        let mut field_soh = field.encode();
-        field_soh.push(constants::SOH);
+    message
+}
 
-        // encode the field into the message
-        message.extend_from_slice(field_soh.as_ref());
+/// Appends all regular fields (`MsgType`, optional header fields, body fields) to the provided
+/// bytes buffer.
+fn write_regular_fields(
+    header: &Header,
+    body: &Body,
+    options: &EncodeOptions,
+    message: &mut BytesMut,
+) {
+    // MsgType with included delimiter
+    let msg_type = Field::Custom {
+        tag: 35,
+        value: Vec::from(&header.msg_type),
+    };
+    write_field(&msg_type, options, message);
+
+    // Optional header fields, each with included delimiter.. x=ab\x01
+    for field in &header.fields {
+        write_field(field, options, message);
     }
 
-    // Body fields
+    // Body fields, each with included delimiter.. x=ab\x01
     for field in &body.fields {
-        // field with included SOH char.. x=ab\x01
-        let mut field_soh = field.encode();
-        field_soh.push(constants::SOH);
+        write_field(field, options, message);
+    }
+}
 
-        // encode the field into the message
-        message.extend_from_slice(field_soh.as_ref());
+/// Appends a single field, terminated with the [`EncodeOptions::delimiter`], to the provided
+/// bytes buffer.
+fn write_field(field: &Field, options: &EncodeOptions, message: &mut BytesMut) {
+    match field {
+        // pre-encoded fields are copied as given
+        Field::Raw(raw) => message.extend_from_slice(raw.as_bytes()),
+        field => message.extend_from_slice(&field.encode()),
     }
+    message.put_u8(options.delimiter);
+}
 
-    message
+/// Encodes a full FIX message in a single pass, see [`EncodeOptions::single_pass`].
+///
+/// Returns the encoded regular fields as error if the body length does not fit the reserved
+/// digits, so they are not encoded again when falling back.
+fn encode_single_pass(
+    header: &Header,
+    body: &Body,
+    options: &EncodeOptions,
+) -> Result<Bytes, BytesMut> {
+    // +4 for the MsgType and the framing fields BeginString, BodyLength and Checksum
+    let mut message = BytesMut::with_capacity(
+        (header.fields.len() + body.fields.len() + 4) * AVERAGE_BYTES_PER_FIELD,
+    );
+
+    match write_single_pass(header, body, options, &mut message) {
+        Ok(start) => Ok(message.freeze().slice(start..)),
+        Err(body_start) => Err(message.split_off(body_start)),
+    }
+}
+
+/// Writes a full FIX message into the empty bytes buffer in a single pass, reserving
+/// [`SINGLE_PASS_BODY_LENGTH_DIGITS`] digits for the `BodyLength` and backfilling them once the
+/// body is written. Unused digits are dropped by moving the preceding bytes over them, so the
+/// message starts at the returned offset into the buffer.
+///
+/// Returns the offset at which the regular fields start as error if the body length does not
+/// fit the reserved digits, in which case no trailer is written.
+fn write_single_pass(
+    header: &Header,
+    body: &Body,
+    options: &EncodeOptions,
+    message: &mut BytesMut,
+) -> Result<usize, usize> {
+    // BeginString with included delimiter
+    let begin_string = Field::Custom {
+        tag: 8,
+        value: header.begin_string.into(),
+    };
+    write_field(&begin_string, options, message);
+
+    // BodyLength placeholder with included delimiter
+    message.extend_from_slice(b"9=");
+    let digits_start = message.len();
+    message.put_bytes(b'0', SINGLE_PASS_BODY_LENGTH_DIGITS);
+    message.put_u8(options.delimiter);
+
+    let body_start = message.len();
+    write_regular_fields(header, body, options, message);
+
+    // backfill the digits right-aligned, from the last one, then close the gap before them
+    let mut body_length = message.len() - body_start;
+    let mut position = body_start - 1;
+
+    loop {
+        if position == digits_start {
+            return Err(body_start);
+        }
+
+        position -= 1;
+        message[position] = b'0' + (body_length % 10).to_le_bytes()[0];
+        body_length /= 10;
+
+        if body_length == 0 {
+            break;
+        }
+    }
+
+    let start = position - digits_start;
+    message.copy_within(..digits_start, start);
+
+    let mut digest = Digest::default();
+    digest.push(&message[start..]);
+    write_trailer(message, Some(digest.checksum()), options);
+
+    Ok(start)
 }
 
 /// Prepends `8=BeginString` and `9=BodyLength` fields to the provided bytes buffer.
 #[must_use]
-fn encode_framing_headers(header: &Header, regular_fields: &BytesMut) -> BytesMut {
+fn encode_framing_headers(
+    header: &Header,
+    regular_fields: &BytesMut,
+    body_length: usize,
+    options: &EncodeOptions,
+) -> BytesMut {
     // 3 * the average bytes per field representing fields: BeginString, BodyLength, Checksum
     let mut message = BytesMut::with_capacity(regular_fields.len() + (3 * AVERAGE_BYTES_PER_FIELD));
 
-    // BeginString with included SOH char
-    message.extend_from_slice(
-        Field::Custom {
-            tag: 8,
-            value: header.begin_string.into(),
-        }
-        .encode()
-        .as_ref(),
-    );
-    message.put_u8(constants::SOH);
-
-    // BodyLength with included SOH char
-    message.extend_from_slice(
-        Field::Custom {
-            tag: 9,
-            value: format!("{}", regular_fields.len()).into_bytes(),
-        }
-        .encode()
-        .as_ref(),
-    );
-    message.put_u8(constants::SOH);
+    write_framing_headers(header, body_length, options, &mut message);
 
     // append the all the regular fields
     message.extend_from_slice(regular_fields);
@@ -102,35 +422,190 @@ fn encode_framing_headers(header: &Header, regular_fields: &BytesMut) -> BytesMu
     message
 }
 
+/// Appends `8=BeginString` and `9=BodyLength` fields to the provided bytes buffer.
+fn write_framing_headers(
+    header: &Header,
+    body_length: usize,
+    options: &EncodeOptions,
+    message: &mut BytesMut,
+) {
+    // BeginString with included delimiter
+    let begin_string = Field::Custom {
+        tag: 8,
+        value: header.begin_string.into(),
+    };
+    write_field(&begin_string, options, message);
+
+    // BodyLength with included delimiter
+    let body_length = Field::Custom {
+        tag: 9,
+        value: format!("{body_length}").into_bytes(),
+    };
+    write_field(&body_length, options, message);
+}
+
 /// Appends the trailer (`10=CheckSum` field) to the provided bytes buffer and finalizes the
-/// FIX message buffer.
-fn finalize_message(mut message: BytesMut) -> Bytes {
-    let mut digest = Digest::default();
-    digest.push(&message);
+/// FIX message buffer. The checksum is calculated, unless an explicit one is given, and the
+/// trailing delimiter is only kept if [`EncodeOptions::trailing_soh`] is set.
+fn finalize_message(mut message: BytesMut, checksum: Option<u8>, options: &EncodeOptions) -> Bytes {
+    write_trailer(&mut message, checksum, options);
+
+    message.freeze()
+}
 
-    // Checksum with included SOH char
-    let mut checksum_soh = Field::Custom {
+/// Appends the trailer (`10=CheckSum` field) to the provided bytes buffer. The checksum is
+/// calculated over all bytes in the buffer, unless an explicit one is given, and the trailing
+/// delimiter is only kept if [`EncodeOptions::trailing_soh`] is set.
+fn write_trailer(message: &mut BytesMut, checksum: Option<u8>, options: &EncodeOptions) {
+    let checksum = checksum.unwrap_or_else(|| {
+        let mut digest = Digest::default();
+        digest.push(&message);
+        digest.checksum()
+    });
+
+    // Checksum with included delimiter, always three digits
+    let checksum = Field::Custom {
         tag: 10,
-        value: format!("{}", digest.checksum()).into_bytes(),
+        value: format!("{checksum:03}").into_bytes(),
+    };
+    write_field(&checksum, options, message);
+
+    if !options.trailing_soh {
+        message.truncate(message.len() - 1);
     }
-    .encode();
-    checksum_soh.push(constants::SOH);
+}
 
-    // encode the Checksum into the message
-    message.put(checksum_soh.as_ref());
+/// Computes the FIX checksum over the current contents of the buffer and appends the
+/// `CheckSum` (`10`) field, zero-padded to three digits and terminated with SOH.
+///
+/// This is the final step of encoding, for messages assembled outside of the encoder, e.g. from
+/// cached fragments. The buffer must contain the complete message up to the checksum, including
+/// the `BeginString` and `BodyLength` fields.
+///
+/// Example usage:
+/// ```
+/// use bytes::BytesMut;
+/// use trafix_codec::message::append_checksum;
+///
+/// let mut buf = BytesMut::from(&b"8=FIX.4.4\x019=5\x0135=0\x01"[..]);
+/// append_checksum(&mut buf);
+///
+/// assert_eq!(&buf[..], b"8=FIX.4.4\x019=5\x0135=0\x0110=163\x01");
+/// ```
+pub fn append_checksum(buf: &mut BytesMut) {
+    write_trailer(buf, None, &EncodeOptions::default());
+}
 
-    message.freeze()
+/// Reusable encoder of [`Message`]s, which writes every message into the same internal buffers
+/// instead of allocating new ones per message.
+///
+/// Encoding produces the same bytes as [`Message::encode`]. The encoded message is borrowed from
+/// the encoder, and stays valid until the next call to [`Encoder::encode`].
+///
+/// Example usage:
+/// ```
+/// use trafix_codec::message::{
+///     Encoder, Message,
+///     field::{
+///         Field,
+///         value::{begin_string::BeginString, msg_type::MsgType},
+///     },
+/// };
+///
+/// let mut encoder = Encoder::new();
+///
+/// for seq_num in 1..=3 {
+///     let heartbeat = Message::builder(BeginString::FIX44, MsgType::Heartbeat)
+///         .with_field(Field::MsgSeqNum(seq_num))
+///         .build();
+///
+///     let encoded = encoder.encode(&heartbeat);
+///     assert!(encoded.starts_with(b"8=FIX.4.4\x01"));
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct Encoder {
+    /// Buffer holding the last encoded message.
+    message: BytesMut,
+
+    /// Scratch buffer holding the regular fields of the message being encoded, needed to
+    /// calculate the `BodyLength` before writing the framing headers.
+    regular_fields: BytesMut,
+
+    /// Wire format of the encoded messages.
+    options: EncodeOptions,
+}
+
+impl Encoder {
+    /// Creates a new [`Encoder`] producing messages in the default wire format.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new [`Encoder`] producing messages in the wire format configured by the given
+    /// [`EncodeOptions`].
+    #[must_use]
+    pub fn with_options(options: EncodeOptions) -> Self {
+        Self {
+            options,
+            ..Self::default()
+        }
+    }
+
+    /// Encodes the given message, auto populating fields `BodyLength` and `Checksum`, and
+    /// returns the encoded bytes. The internal buffers are cleared beforehand, so previously
+    /// returned bytes must not be used anymore, which the borrow checker enforces.
+    pub fn encode(&mut self, message: &Message) -> &[u8] {
+        if self.options.single_pass {
+            self.message.clear();
+
+            if let Ok(start) = write_single_pass(
+                &message.header,
+                &message.body,
+                &self.options,
+                &mut self.message,
+            ) {
+                return &self.message[start..];
+            }
+        }
+
+        self.regular_fields.clear();
+        write_regular_fields(
+            &message.header,
+            &message.body,
+            &self.options,
+            &mut self.regular_fields,
+        );
+
+        self.message.clear();
+        write_framing_headers(
+            &message.header,
+            self.regular_fields.len(),
+            &self.options,
+            &mut self.message,
+        );
+        self.message.extend_from_slice(&self.regular_fields);
+        write_trailer(&mut self.message, None, &self.options);
+
+        &self.message
+    }
 }
 
 #[cfg(test)]
 mod test {
-    use bytes::Bytes;
+    use bytes::{Bytes, BytesMut};
 
     use crate::{
         constants,
-        encoder::encode,
+        decoder::{DecodeOptions, decode_with_digest},
+        encoder::{
+            EncodeOptions, Encoder, Error, FramingOverrides, MAX_BODY_LENGTH, encode,
+            encode_header, encode_with, encode_with_body_digest, encode_with_overrides, try_encode,
+            try_encode_with,
+        },
         message::{
-            Body, Header,
+            Body, Header, Message,
             field::{
                 Field,
                 value::{begin_string::BeginString, msg_type::MsgType},
@@ -144,6 +619,20 @@ mod test {
         String::from_utf8_lossy(encoded_message).replace(constants::SOH as char, "|")
     }
 
+    #[test]
+    fn header_only() {
+        let header = Header {
+            begin_string: BeginString::FIX44,
+            msg_type: MsgType::Heartbeat,
+            fields: vec![
+                Field::SenderCompID(b"SENDER".into()),
+                Field::TargetCompID(b"TARGET".into()),
+            ],
+        };
+
+        insta::assert_snapshot!(humanize(&encode_header(&header)), @"8=FIX.4.4|35=0|49=SENDER|56=TARGET|");
+    }
+
     #[test]
     fn message_with_minimal_header() {
         let header = Header {
@@ -212,4 +701,336 @@ mod test {
 
         insta::assert_snapshot!(humanize(&encoded_message), @"8=FIX.4.4|9=50|35=A|144=value144|1234=value1234|12345=value12345|10=185|");
     }
+
+    #[test]
+    fn trailing_soh() {
+        let header = Header {
+            begin_string: BeginString::FIX44,
+            msg_type: MsgType::Logon,
+            fields: Vec::new(),
+        };
+
+        let body = Body {
+            fields: vec![Field::MsgSeqNum(1)],
+        };
+
+        let with_soh = encode_with(&header, &body, &EncodeOptions::default());
+        insta::assert_snapshot!(humanize(&with_soh), @"8=FIX.4.4|9=10|35=A|34=1|10=182|");
+
+        let options = EncodeOptions {
+            trailing_soh: false,
+            ..EncodeOptions::default()
+        };
+
+        // body length and checksum are the same, only the last SOH is gone
+        let without_soh = encode_with(&header, &body, &options);
+        insta::assert_snapshot!(humanize(&without_soh), @"8=FIX.4.4|9=10|35=A|34=1|10=182");
+
+        assert_eq!(without_soh, with_soh[..with_soh.len() - 1]);
+    }
+
+    #[test]
+    fn delimiter() {
+        let header = Header {
+            begin_string: BeginString::FIX44,
+            msg_type: MsgType::Logon,
+            fields: Vec::new(),
+        };
+
+        let body = Body {
+            fields: vec![Field::MsgSeqNum(1)],
+        };
+
+        let options = EncodeOptions {
+            delimiter: b'|',
+            ..EncodeOptions::default()
+        };
+
+        let encoded = encode_with(&header, &body, &options);
+        assert_eq!(encoded, b"8=FIX.4.4|9=10|35=A|34=1|10=162|"[..]);
+
+        // the checksum covers the emitted delimiters, not SOH
+        let checksum = encoded[..encoded.len() - b"10=162|".len()]
+            .iter()
+            .fold(0u8, |sum, byte| sum.wrapping_add(*byte));
+        assert_eq!(checksum, 162);
+    }
+
+    #[test]
+    fn append_checksum() {
+        let header = Header {
+            begin_string: BeginString::FIX44,
+            msg_type: MsgType::Logout,
+            fields: Vec::new(),
+        };
+
+        let body = Body {
+            fields: vec![
+                Field::MsgSeqNum(3),
+                Field::Text(b"one".to_vec()),
+                Field::Text(b"two".to_vec()),
+                Field::PnL(-5),
+            ],
+        };
+
+        let encoded = encode(&header, &body);
+        assert!(encoded.ends_with(b"\x0110=053\x01"));
+
+        // assembling the same message from fragments yields the same, zero-padded trailer
+        let mut buf = BytesMut::from(&encoded[..encoded.len() - b"10=053\x01".len()]);
+        super::append_checksum(&mut buf);
+
+        assert_eq!(buf, encoded);
+    }
+
+    #[test]
+    fn reusable_encoder() {
+        let messages = [
+            Message::builder(BeginString::FIX44, MsgType::Logon)
+                .with_header(Field::SenderCompID(b"SENDER".into()))
+                .with_field(Field::HeartBtInt(30))
+                .build(),
+            Message::builder(BeginString::FIX44, MsgType::Heartbeat)
+                .with_field(Field::MsgSeqNum(2))
+                .build(),
+            Message::builder(BeginString::FIX44, MsgType::Logout)
+                .with_field(Field::Text(b"Bye".to_vec()))
+                .build(),
+        ];
+
+        let mut encoder = Encoder::new();
+
+        // the buffers are cleared between messages
+        for message in &messages {
+            assert_eq!(encoder.encode(message), message.clone().encode());
+        }
+
+        let options = EncodeOptions {
+            trailing_soh: false,
+            ..EncodeOptions::default()
+        };
+        let mut encoder = Encoder::with_options(options.clone());
+
+        for message in &messages {
+            assert_eq!(
+                encoder.encode(message),
+                message.clone().encode_with(&options)
+            );
+        }
+    }
+
+    #[test]
+    fn body_length_limit() {
+        let header = Header {
+            begin_string: BeginString::FIX44,
+            msg_type: MsgType::Heartbeat,
+            fields: Vec::new(),
+        };
+
+        // "35=0|" + "58=" + value + "|"
+        let overhead = 5 + 3 + 1;
+
+        let mut body = Body {
+            fields: vec![Field::Custom {
+                tag: 58,
+                value: vec![b'x'; MAX_BODY_LENGTH - overhead],
+            }],
+        };
+
+        let encoded_message = try_encode(&header, &body).expect("body fits exactly");
+        assert!(
+            encoded_message.starts_with(format!("8=FIX.4.4\x019={MAX_BODY_LENGTH}\x01").as_bytes())
+        );
+
+        body.fields = vec![Field::Custom {
+            tag: 58,
+            value: vec![b'x'; MAX_BODY_LENGTH - overhead + 1],
+        }];
+
+        assert_eq!(
+            try_encode(&header, &body),
+            Err(Error::BodyTooLong {
+                length: MAX_BODY_LENGTH + 1,
+                max: MAX_BODY_LENGTH
+            })
+        );
+    }
+
+    #[test]
+    fn reserved_tags() {
+        let header = Header {
+            begin_string: BeginString::FIX44,
+            msg_type: MsgType::Heartbeat,
+            fields: Vec::new(),
+        };
+
+        for tag in [8, 9, 10, 35] {
+            let body = Body {
+                fields: vec![
+                    Field::MsgSeqNum(1),
+                    Field::Custom {
+                        tag,
+                        value: b"X".to_vec(),
+                    },
+                ],
+            };
+
+            assert_eq!(try_encode(&header, &body), Err(Error::ReservedTag(tag)));
+        }
+
+        let header = Header {
+            fields: vec![Field::Custom {
+                tag: 35,
+                value: b"A".to_vec(),
+            }],
+            ..header
+        };
+
+        assert_eq!(
+            try_encode(&header, &Body::default()),
+            Err(Error::ReservedTag(35))
+        );
+    }
+
+    #[test]
+    fn max_text_len() {
+        let header = Header {
+            begin_string: BeginString::FIX44,
+            msg_type: MsgType::Logout,
+            fields: Vec::new(),
+        };
+        let body = Body {
+            fields: vec![Field::Text(b"Bye".to_vec())],
+        };
+        let options = |max| EncodeOptions {
+            max_text_len: Some(max),
+            ..EncodeOptions::default()
+        };
+
+        // exactly at the limit
+        assert_eq!(
+            try_encode_with(&header, &body, &options(3)),
+            Ok(encode(&header, &body))
+        );
+
+        assert_eq!(
+            try_encode_with(&header, &body, &options(2)),
+            Err(Error::TextTooLong { length: 3, max: 2 })
+        );
+
+        // no limit by default
+        assert!(try_encode(&header, &body).is_ok());
+    }
+
+    #[test]
+    fn framing_overrides() {
+        let header = Header {
+            begin_string: BeginString::FIX44,
+            msg_type: MsgType::Logon,
+            fields: Vec::new(),
+        };
+
+        let body = Body { fields: Vec::new() };
+
+        let encoded_message = encode_with_overrides(&header, &body, FramingOverrides::default());
+        insta::assert_snapshot!(humanize(&encoded_message), @"8=FIX.4.4|9=5|35=A|10=180|");
+
+        let overrides = FramingOverrides {
+            body_length: Some(42),
+            checksum: None,
+        };
+
+        // checksum is calculated over the overridden body length
+        let encoded_message = encode_with_overrides(&header, &body, overrides);
+        insta::assert_snapshot!(humanize(&encoded_message), @"8=FIX.4.4|9=42|35=A|10=229|");
+
+        let overrides = FramingOverrides {
+            body_length: Some(42),
+            checksum: Some(7),
+        };
+
+        let encoded_message = encode_with_overrides(&header, &body, overrides);
+        insta::assert_snapshot!(humanize(&encoded_message), @"8=FIX.4.4|9=42|35=A|10=007|");
+    }
+
+    #[test]
+    fn reused_body_digest() {
+        let input = "8=FIX.4.2\x019=59\x0135=D\x0149=SENDER\x0156=TARGET\x0134=2\x0111=ORDER-1\x01\
+            55=MSFT\x0154=1\x0140=1\x0110=168\x01";
+
+        let (mut message, digest) =
+            decode_with_digest(input, &DecodeOptions::default()).expect("message is valid");
+
+        // a proxy only rewriting the framing reuses the digest of the unchanged body
+        message.header.begin_string = BeginString::FIX44;
+
+        let reencoded = encode_with_body_digest(&message.header, &digest);
+        assert_eq!(reencoded, encode(&message.header, &message.body));
+        insta::assert_snapshot!(humanize(&reencoded), @"8=FIX.4.4|9=59|35=D|49=SENDER|56=TARGET|34=2|11=ORDER-1|55=MSFT|54=1|40=1|10=170|");
+
+        // the raw body is forwarded as it is, keeping e.g. the leading zeros of the MsgSeqNum
+        let input = "8=FIX.4.4\x019=62\x0135=D\x0149=SENDER\x0156=TARGET\x0134=0002\x0111=ORDER-1\x01\
+            55=MSFT\x0154=1\x0140=1\x0110=052\x01";
+
+        let (message, digest) =
+            decode_with_digest(input, &DecodeOptions::default()).expect("message is valid");
+
+        let reencoded = encode_with_body_digest(&message.header, &digest);
+        assert_eq!(reencoded, input.as_bytes());
+    }
+
+    #[test]
+    fn single_pass() {
+        let message = |text_len| {
+            Message::builder(BeginString::FIX44, MsgType::Logout)
+                .with_header(Field::SenderCompID(b"SENDER".into()))
+                .with_field(Field::Text(vec![b'x'; text_len]))
+                .build()
+        };
+
+        let single_pass = |options: &EncodeOptions| EncodeOptions {
+            single_pass: true,
+            ..options.clone()
+        };
+
+        let formats = [
+            EncodeOptions::default(),
+            EncodeOptions {
+                delimiter: b'|',
+                trailing_soh: false,
+                ..EncodeOptions::default()
+            },
+        ];
+
+        // body lengths of 1 to 4 digits, up to the largest fitting the reserved digits, and
+        // ones exceeding them
+        for text_len in [0, 50, 500, 5000, 9980, 9981, 50_000] {
+            let message = message(text_len);
+
+            for options in &formats {
+                let expected = encode_with(&message.header, &message.body, options);
+
+                assert_eq!(
+                    encode_with(&message.header, &message.body, &single_pass(options)),
+                    expected
+                );
+                assert_eq!(
+                    try_encode_with(&message.header, &message.body, &single_pass(options)),
+                    Ok(expected.clone())
+                );
+
+                let mut encoder = Encoder::with_options(single_pass(options));
+                assert_eq!(encoder.encode(&message), expected);
+                // the reused buffer does not leak into the next message
+                assert_eq!(encoder.encode(&message), expected);
+            }
+        }
+
+        let encoded = encode_with(
+            &message(0).header,
+            &message(0).body,
+            &single_pass(&formats[1]),
+        );
+        insta::assert_snapshot!(String::from_utf8_lossy(&encoded), @"8=FIX.4.4|9=19|35=5|49=SENDER|58=|10=091");
+    }
 }
@@ -6,7 +6,7 @@
 //! `trafix-codec` is a low-level library for high-performance parsing,
 //! encoding, and validation of FIX messages.
 
-mod digest;
+pub mod digest;
 
 pub(crate) mod constants;
 pub(crate) mod decoder;
@@ -0,0 +1,270 @@
+//! Implementation of structured access to `ExecutionReport` (`35=8`) and `OrderCancelReject`
+//! (`35=9`) messages.
+
+use crate::message::{
+    Message,
+    field::{
+        Field,
+        value::{
+            aliases::{AvgPx, CumQty, ExecID, LastPx, LastQty, LeavesQty},
+            cxl_rej_reason::CxlRejReason,
+            cxl_rej_response_to::CxlRejResponseTo,
+            msg_type::MsgType,
+            ord_rej_reason::OrdRejReason,
+        },
+    },
+};
+
+/// Quantities describing the fill state of an order, as reported by an `ExecutionReport`
+/// (`35=8`) message.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExecQuantities {
+    /// Quantity still open for further execution (`151`).
+    pub leaves_qty: LeavesQty,
+
+    /// Total quantity filled so far (`14`).
+    pub cum_qty: CumQty,
+
+    /// Average price of all fills so far (`6`).
+    pub avg_px: AvgPx,
+}
+
+/// The last fill of an order, as reported by an `ExecutionReport` (`35=8`) message.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fill {
+    /// Identifier of the execution report (`17`).
+    pub exec_id: ExecID,
+
+    /// Price of the fill (`31`).
+    pub last_px: LastPx,
+
+    /// Quantity of the fill (`32`).
+    pub last_qty: LastQty,
+}
+
+impl Message {
+    /// Returns the [`ExecQuantities`] of an `ExecutionReport` (`35=8`) message.
+    ///
+    /// Returns [`None`] if this message is not an `ExecutionReport`, or if any of the
+    /// `LeavesQty` (`151`), `CumQty` (`14`) and `AvgPx` (`6`) fields is missing.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::{Message, field::value::fix_decimal::FixDecimal};
+    ///
+    /// let input = "8=FIX.4.4\x019=27\x0135=8\x01151=30\x0114=70\x016=101.25\x0110=188\x01";
+    /// let quantities = Message::decode(input).unwrap().exec_quantities().unwrap();
+    ///
+    /// assert_eq!(quantities.cum_qty, FixDecimal::new(70, 0));
+    /// assert_eq!(quantities.avg_px, FixDecimal::new(10_125, 2));
+    /// ```
+    #[must_use]
+    pub fn exec_quantities(&self) -> Option<ExecQuantities> {
+        if self.header.msg_type != MsgType::ExecutionReport {
+            return None;
+        }
+
+        let (mut leaves_qty, mut cum_qty, mut avg_px) = (None, None, None);
+
+        for field in self.fields() {
+            match field {
+                Field::LeavesQty(value) => leaves_qty = Some(*value),
+                Field::CumQty(value) => cum_qty = Some(*value),
+                Field::AvgPx(value) => avg_px = Some(*value),
+                _ => {}
+            }
+        }
+
+        Some(ExecQuantities {
+            leaves_qty: leaves_qty?,
+            cum_qty: cum_qty?,
+            avg_px: avg_px?,
+        })
+    }
+
+    /// Returns the last [`Fill`] reported by an `ExecutionReport` (`35=8`) message.
+    ///
+    /// Returns [`None`] if this message is not an `ExecutionReport`, or if any of the `ExecID`
+    /// (`17`), `LastPx` (`31`) and `LastQty` (`32`) fields is missing, e.g. for reports not
+    /// caused by a fill.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::{Message, field::value::fix_decimal::FixDecimal};
+    ///
+    /// let input = "8=FIX.4.4\x019=32\x0135=8\x0117=EXEC-7\x0131=101.25\x0132=300\x0110=009\x01";
+    /// let fill = Message::decode(input).unwrap().last_fill().unwrap();
+    ///
+    /// assert_eq!(fill.last_px, FixDecimal::new(10_125, 2));
+    /// assert_eq!(fill.last_qty, FixDecimal::new(300, 0));
+    /// ```
+    #[must_use]
+    pub fn last_fill(&self) -> Option<Fill> {
+        if self.header.msg_type != MsgType::ExecutionReport {
+            return None;
+        }
+
+        let (mut exec_id, mut last_px, mut last_qty) = (None, None, None);
+
+        for field in self.fields() {
+            match field {
+                Field::ExecID(value) => exec_id = Some(value),
+                Field::LastPx(value) => last_px = Some(*value),
+                Field::LastQty(value) => last_qty = Some(*value),
+                _ => {}
+            }
+        }
+
+        Some(Fill {
+            exec_id: exec_id?.clone(),
+            last_px: last_px?,
+            last_qty: last_qty?,
+        })
+    }
+
+    /// Returns the `OrdRejReason` (`103`) of this message, if present. Carried by an
+    /// `ExecutionReport` (`35=8`) rejecting an order.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::{
+    ///     Message, field::value::ord_rej_reason::OrdRejReason,
+    /// };
+    ///
+    /// let input = "8=FIX.4.4\x019=16\x0135=8\x0139=8\x01103=1\x0110=194\x01";
+    /// let msg = Message::decode(input).unwrap();
+    ///
+    /// assert_eq!(msg.ord_rej_reason(), Some(OrdRejReason::UnknownSymbol));
+    /// ```
+    #[must_use]
+    pub fn ord_rej_reason(&self) -> Option<OrdRejReason> {
+        self.fields().find_map(|field| match field {
+            Field::OrdRejReason(value) => Some(*value),
+            _ => None,
+        })
+    }
+
+    /// Returns the `CxlRejReason` (`102`) of this message, if present. Carried by an
+    /// `OrderCancelReject` (`35=9`).
+    #[must_use]
+    pub fn cxl_rej_reason(&self) -> Option<CxlRejReason> {
+        self.fields().find_map(|field| match field {
+            Field::CxlRejReason(value) => Some(*value),
+            _ => None,
+        })
+    }
+
+    /// Returns the `CxlRejResponseTo` (`434`) of this message, if present. Carried by an
+    /// `OrderCancelReject` (`35=9`) to tell whether a cancel or a cancel/replace request was
+    /// rejected.
+    #[must_use]
+    pub fn cxl_rej_response_to(&self) -> Option<CxlRejResponseTo> {
+        self.fields().find_map(|field| match field {
+            Field::CxlRejResponseTo(value) => Some(*value),
+            _ => None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::decoder::Error;
+    use crate::message::{
+        Message,
+        execution::{ExecQuantities, Fill},
+        field::{
+            Field,
+            value::{
+                begin_string::BeginString, cxl_rej_reason::CxlRejReason,
+                cxl_rej_response_to::CxlRejResponseTo, fix_decimal::FixDecimal, msg_type::MsgType,
+                ord_rej_reason::OrdRejReason,
+            },
+        },
+    };
+
+    #[test]
+    fn exec_quantities() {
+        let input = "8=FIX.4.4\x019=236\x0135=8\x0134=12\x0149=SELLER\x0152=20180920-18:14:19.612\x0156=BUYER\x016=101.25\x0111=636730640278898634\x0114=7000\x0117=EXEC-7\x0131=101.25\x0132=3000\x0137=ORD-1\x0138=10000\x0139=1\x0140=2\x0144=101.50\x0154=1\x0155=MSFT\x0160=20180920-18:14:19.492\x01150=F\x01151=3000\x0115=USD\x0158=partial fill\x0110=141\x01";
+
+        let msg = Message::decode(input).expect("execution report is valid");
+
+        assert_eq!(
+            msg.exec_quantities(),
+            Some(ExecQuantities {
+                leaves_qty: FixDecimal::new(3000, 0),
+                cum_qty: FixDecimal::new(7000, 0),
+                avg_px: FixDecimal::new(10_125, 2),
+            })
+        );
+    }
+
+    #[test]
+    fn last_fill() {
+        let input = "8=FIX.4.4\x019=236\x0135=8\x0134=12\x0149=SELLER\x0152=20180920-18:14:19.612\x0156=BUYER\x016=101.25\x0111=636730640278898634\x0114=7000\x0117=EXEC-7\x0131=101.25\x0132=3000\x0137=ORD-1\x0138=10000\x0139=1\x0140=2\x0144=101.50\x0154=1\x0155=MSFT\x0160=20180920-18:14:19.492\x01150=F\x01151=3000\x0115=USD\x0158=partial fill\x0110=141\x01";
+
+        let msg = Message::decode(input).expect("execution report is valid");
+
+        assert_eq!(
+            msg.last_fill(),
+            Some(Fill {
+                exec_id: b"EXEC-7".to_vec(),
+                last_px: FixDecimal::new(10_125, 2),
+                last_qty: FixDecimal::new(3000, 0),
+            })
+        );
+
+        // LastQty is missing
+        let msg = Message::builder(BeginString::FIX44, MsgType::ExecutionReport)
+            .with_field(Field::ExecID(b"EXEC-8".to_vec()))
+            .with_field(Field::LastPx(FixDecimal::new(10_125, 2)))
+            .build();
+
+        assert_eq!(msg.last_fill(), None);
+    }
+
+    #[test]
+    fn reject_reasons() {
+        let input = "8=FIX.4.4\x019=45\x0135=9\x0111=CANCEL-1\x0141=ORDER-1\x0139=8\x01102=0\x01434=1\x0110=238\x01";
+        let msg = Message::decode(input).expect("order cancel reject is valid");
+
+        assert_eq!(msg.msg_type(), &MsgType::OrderCancelReject);
+        assert_eq!(msg.cxl_rej_reason(), Some(CxlRejReason::TooLateToCancel));
+        assert_eq!(
+            msg.cxl_rej_response_to(),
+            Some(CxlRejResponseTo::OrderCancelRequest)
+        );
+        assert_eq!(msg.ord_rej_reason(), None);
+
+        let encoded = Message::builder(BeginString::FIX44, MsgType::ExecutionReport)
+            .with_field(Field::OrdRejReason(OrdRejReason::Other))
+            .build()
+            .encode();
+        let msg = Message::decode(encoded).expect("encoded message is valid");
+
+        assert_eq!(msg.ord_rej_reason(), Some(OrdRejReason::Other));
+        assert_eq!(msg.cxl_rej_reason(), None);
+
+        // unknown reasons are rejected, unless decoding with lenient enums
+        let input = "8=FIX.4.4\x019=12\x0135=8\x01103=42\x0110=017\x01";
+        assert!(matches!(Message::decode(input), Err(Error::BadValue(_))));
+    }
+
+    #[test]
+    fn not_an_execution_report() {
+        // AvgPx is missing
+        let msg = Message::builder(BeginString::FIX44, MsgType::ExecutionReport)
+            .with_field(Field::LeavesQty(FixDecimal::new(0, 0)))
+            .with_field(Field::CumQty(FixDecimal::new(100, 0)))
+            .build();
+
+        assert_eq!(msg.exec_quantities(), None);
+
+        let msg = Message::builder(BeginString::FIX44, MsgType::Heartbeat)
+            .with_field(Field::LeavesQty(FixDecimal::new(0, 0)))
+            .with_field(Field::CumQty(FixDecimal::new(100, 0)))
+            .with_field(Field::AvgPx(FixDecimal::new(15, 1)))
+            .build();
+
+        assert_eq!(msg.exec_quantities(), None);
+    }
+}
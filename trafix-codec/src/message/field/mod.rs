@@ -1,8 +1,49 @@
 //! Implementation of the field module.
 
+pub mod raw;
 pub mod value;
 
-use crate::message::field::value::aliases::{MsgSeqNum, SenderCompID, SendingTime, TargetCompID};
+use std::borrow::Cow;
+
+use bytes::{BufMut, BytesMut};
+
+use crate::constants;
+use crate::message::field::raw::RawField;
+use crate::message::field::value::{
+    aliases::{
+        AvgPx, BidPx, ClOrdID, CumQty, ExecID, ExecInst, ExpireTime, GapFillFlag, HeartBtInt,
+        LastMsgSeqNumProcessed, LastPx, LastQty, LeavesQty, MDReqID, MarketDepth, MaxFloor,
+        MaxMessageSize, MinQty, MsgSeqNum, NewSeqNo, NoMDEntries, NoPartyIDs, NoPartySubIDs,
+        NoRelatedSym, OfferPx, OrderQty, OrigClOrdID, PartyID, PartySubID, PartySubIDType, PnL,
+        Price, QuoteID, RefSeqNum, RefTagID, SecurityID, SenderCompID, SendingTime, Symbol,
+        TargetCompID, TestMessageIndicator, TestReqID, Text, TradeDate, TransactTime, Username,
+        XmlData, XmlDataLen,
+    },
+    business_reject_reason::BusinessRejectReason,
+    cfi_code::CFICode,
+    currency::Currency,
+    cxl_rej_reason::CxlRejReason,
+    cxl_rej_response_to::CxlRejResponseTo,
+    encrypt_method::EncryptMethod,
+    handl_inst::HandlInst,
+    md_req_rej_reason::MDReqRejReason,
+    md_update_action::MDUpdateAction,
+    md_update_type::MDUpdateType,
+    msg_type::MsgType,
+    ord_rej_reason::OrdRejReason,
+    ord_type::OrdType,
+    order_capacity::OrderCapacity,
+    order_restrictions::OrderRestrictions,
+    party_id_source::PartyIDSource,
+    party_role::PartyRole,
+    password::Password,
+    security_id_source::SecurityIDSource,
+    security_type::SecurityType,
+    session_reject_reason::SessionRejectReason,
+    side::Side,
+    subscription_request_type::SubscriptionRequestType,
+    time_in_force::TimeInForce,
+};
 
 /// Macro that generates the [`Field`] enum and its core utility methods.
 ///
@@ -15,8 +56,15 @@ use crate::message::field::value::aliases::{MsgSeqNum, SenderCompID, SendingTime
 /// The macro expands into:
 /// - the [`Field`] enum,
 /// - a [`Field::tag`] method returning the tag number,
+/// - a [`Field::tag_name`] function returning the FIX name of a tag,
 /// - a [`Field::value`] method returning the encoded byte value,
 /// - and a [`Field::encode`] method producing the `"tag=value"` byte sequence.
+///
+/// Numeric value types are parsed through [`ParseFixInt`], so signed fields are added the same
+/// way as unsigned ones: use a signed integer type (e.g. `i64`) for the value and format it with
+/// `format!`, which emits the leading `-` for negative values. See [`Field::PnL`].
+///
+/// [`ParseFixInt`]: crate::decoder::num::ParseFixInt
 macro_rules! fields_macro {
     ($($(#[$($attrs:tt)*])* $variant:ident($type:ty) = $tag:literal => $match:ident $expr:expr),+) => {
         /// Represents a single FIX field.
@@ -24,7 +72,10 @@ macro_rules! fields_macro {
         /// Each variant corresponds to a strongly-typed FIX tag, such as
         /// `MsgSeqNum(34)` or `SenderCompID(49)`. Fields not covered by
         /// predefined variants can be represented using [`Field::Custom`].
-        #[derive(Debug, Clone, PartialEq)]
+        ///
+        /// The [`Debug`](std::fmt::Debug) representation redacts the password (`554`), also when
+        /// it is held by a [`Field::Custom`].
+        #[derive(Clone, PartialEq)]
         pub enum Field {
             $(
             $(#[$($attrs)*])*
@@ -38,9 +89,34 @@ macro_rules! fields_macro {
             /// working with non-standard message structures.
             Custom {
                 /// Tag of the custom field.
-                tag: u16,
+                tag: u32,
                 /// Contents of the custom field.
                 value: Vec<u8>
+            },
+
+            /// Represents a pre-encoded `tag=value` field, emitted as given, see
+            /// [`MessageBuilder::with_raw_field`](crate::message::MessageBuilder::with_raw_field).
+            Raw(RawField),
+        }
+
+        impl std::fmt::Debug for Field {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match self {
+                    $(
+                    Self::$variant(value) => f.debug_tuple(stringify!($variant)).field(value).finish(),
+                    )*
+                    Self::Custom { tag, .. } if *tag == Password::tag() => f
+                        .debug_struct("Custom")
+                        .field("tag", tag)
+                        .field("value", &format_args!("****"))
+                        .finish(),
+                    Self::Custom { tag, value } => f
+                        .debug_struct("Custom")
+                        .field("tag", tag)
+                        .field("value", value)
+                        .finish(),
+                    Self::Raw(raw) => f.debug_tuple("Raw").field(raw).finish(),
+                }
             }
         }
 
@@ -50,12 +126,15 @@ macro_rules! fields_macro {
             /// # Errors
             ///
             /// This function might return error if invalid values are passed for the given tag.
-            pub fn try_new(tag: u16, bytes: &[u8]) -> Result<Self, Box<dyn std::error::Error>> {
+            pub fn try_new(tag: u32, bytes: &[u8]) -> Result<Self, Box<dyn std::error::Error>> {
                 use value::FromFixBytes;
 
                 match tag {
                     $(
-                    $tag => Ok(Self::$variant(<$type as FromFixBytes>::from_fix_bytes(bytes)?)),
+                    $tag => Ok(Self::$variant(
+                        <$type as FromFixBytes>::from_fix_bytes(bytes)
+                            .map_err(|error| error.to_string())?
+                    )),
                     )*
                     other => Ok(Field::Custom {
                         tag: other,
@@ -64,6 +143,58 @@ macro_rules! fields_macro {
                 }
             }
 
+            /// Returns `true` if the values of fields with the given tag are limited to a fixed
+            /// set of known values, e.g. [`Field::OrdType`].
+            pub(crate) fn is_enumerated(tag: u32) -> bool {
+                use value::FromFixBytes;
+
+                match tag {
+                    $(
+                    $tag => <$type as FromFixBytes>::ENUMERATED,
+                    )*
+                    _ => false,
+                }
+            }
+
+            /// Returns `true` if the values of fields with the given tag are integers, e.g.
+            /// [`Field::MsgSeqNum`].
+            pub(crate) fn is_numeric(tag: u32) -> bool {
+                use value::FromFixBytes;
+
+                match tag {
+                    $(
+                    $tag => <$type as FromFixBytes>::NUMERIC,
+                    )*
+                    _ => false,
+                }
+            }
+
+            /// Returns the FIX name of the field with the given tag, e.g. `SenderCompID` for `49`,
+            /// or [`None`] if the tag has no predefined variant. The framing fields `BeginString`
+            /// (`8`), `BodyLength` (`9`), `CheckSum` (`10`) and `MsgType` (`35`) are named as
+            /// well.
+            ///
+            /// Example usage:
+            /// ```
+            /// use trafix_codec::message::field::Field;
+            /// assert_eq!(Field::tag_name(49), Some("SenderCompID"));
+            /// assert_eq!(Field::tag_name(35), Some("MsgType"));
+            /// assert_eq!(Field::tag_name(9999), None);
+            /// ```
+            #[must_use]
+            pub fn tag_name(tag: u32) -> Option<&'static str> {
+                match tag {
+                    8 => Some("BeginString"),
+                    9 => Some("BodyLength"),
+                    10 => Some("CheckSum"),
+                    35 => Some("MsgType"),
+                    $(
+                    $tag => Some(stringify!($variant)),
+                    )*
+                    _ => None,
+                }
+            }
+
             /// Returns the numeric FIX tag associated with this field.
             ///
             /// Example usage:
@@ -73,13 +204,14 @@ macro_rules! fields_macro {
             /// assert_eq!(f.tag(), 34);
             /// ```
             #[must_use]
-            pub fn tag(&self) -> u16 {
+            pub fn tag(&self) -> u32 {
                 match self {
                     $(
                     Field::$variant(_) => $tag
                     ),+,
 
                     Field::Custom { tag, .. } => { *tag }
+                    Field::Raw(raw) => raw.tag(),
                 }
             }
 
@@ -96,6 +228,40 @@ macro_rules! fields_macro {
                     ),+,
 
                     Field::Custom { value, .. } => { value.clone() }
+                    Field::Raw(raw) => raw.value().to_vec(),
+                }
+            }
+
+            /// Returns the serialized value of the field as raw bytes, like [`Field::value`], but
+            /// borrows the value where it is stored in its serialized form, e.g. for custom fields
+            /// and text values. Only values that must be formatted, e.g. numbers, are allocated.
+            ///
+            /// Example usage:
+            /// ```
+            /// use std::borrow::Cow;
+            ///
+            /// use trafix_codec::message::field::Field;
+            ///
+            /// let text = Field::Text(b"Hello".to_vec());
+            /// assert!(matches!(text.value_ref(), Cow::Borrowed(b"Hello")));
+            ///
+            /// let seq_num = Field::MsgSeqNum(7);
+            /// assert_eq!(seq_num.value_ref().as_ref(), b"7");
+            /// ```
+            #[must_use]
+            pub fn value_ref(&self) -> Cow<'_, [u8]> {
+                use value::AsFixBytes;
+
+                match self {
+                    $(
+                    Field::$variant($match) => match AsFixBytes::as_fix_bytes($match) {
+                        Some(bytes) => Cow::Borrowed(bytes),
+                        None => Cow::Owned($expr),
+                    }
+                    ),+,
+
+                    Field::Custom { value, .. } => Cow::Borrowed(value),
+                    Field::Raw(raw) => Cow::Borrowed(raw.value()),
                 }
             }
 
@@ -131,6 +297,8 @@ macro_rules! fields_macro {
 
                         field
                     }
+
+                    Field::Raw(raw) => raw.as_bytes().to_vec(),
                 }
             }
         }
@@ -138,32 +306,443 @@ macro_rules! fields_macro {
 }
 
 fields_macro! {
+    /// Average price (`6`).
+    ///
+    /// Average price of all fills of an order.
+    AvgPx(AvgPx) = 6 => avg_px Vec::from(avg_px),
+
+    /// Client order identifier (`11`).
+    ///
+    /// Identifies an order, assigned by the institution sending it.
+    ClOrdID(ClOrdID) = 11 => cl_ord_id cl_ord_id.clone(),
+
+    /// Cumulative quantity (`14`).
+    ///
+    /// Total quantity filled so far for an order.
+    CumQty(CumQty) = 14 => cum_qty Vec::from(cum_qty),
+
+    /// Currency (`15`).
+    ///
+    /// ISO 4217 code of the currency a price or amount is expressed in.
+    Currency(Currency) = 15 => currency Vec::from(currency),
+
+    /// Execution identifier (`17`).
+    ///
+    /// Identifies an execution report, assigned by its sender.
+    ExecID(ExecID) = 17 => exec_id exec_id.clone(),
+
+    /// Execution instructions (`18`).
+    ///
+    /// Instructions for the handling of the order, as a space-separated list.
+    ExecInst(ExecInst) = 18 => exec_inst Vec::from(exec_inst),
+
+    /// Handling instruction (`21`).
+    ///
+    /// Instructions for the handling of an order by the broker, e.g. automated or manual.
+    HandlInst(HandlInst) = 21 => handl_inst Vec::from(*handl_inst),
+
+    /// Security identifier source (`22`).
+    ///
+    /// Identification scheme of the `SecurityID`, e.g. ISIN or CUSIP.
+    SecurityIDSource(SecurityIDSource) = 22 => source Vec::from(*source),
+
+    /// Last price (`31`).
+    ///
+    /// Price of the last fill of an order.
+    LastPx(LastPx) = 31 => last_px Vec::from(last_px),
+
+    /// Last quantity (`32`).
+    ///
+    /// Quantity of the last fill of an order.
+    LastQty(LastQty) = 32 => last_qty Vec::from(last_qty),
+
     /// Message sequence number (`34`).
     ///
     /// Used to identify message ordering within a FIX session.
     MsgSeqNum(MsgSeqNum) = 34 => msg_seq_num format!("{msg_seq_num}").into_bytes(),
 
+    /// New sequence number (`36`).
+    ///
+    /// The `MsgSeqNum` expected of the next message after a `SequenceReset`.
+    NewSeqNo(NewSeqNo) = 36 => new_seq_no format!("{new_seq_no}").into_bytes(),
+
+    /// Order quantity (`38`).
+    ///
+    /// Quantity ordered.
+    OrderQty(OrderQty) = 38 => order_qty Vec::from(order_qty),
+
+    /// Order type (`40`).
+    ///
+    /// Type of an order, e.g. market or limit.
+    OrdType(OrdType) = 40 => ord_type Vec::from(*ord_type),
+
+    /// Original client order identifier (`41`).
+    ///
+    /// The `ClOrdID` of the order to cancel or replace.
+    OrigClOrdID(OrigClOrdID) = 41 => orig_cl_ord_id orig_cl_ord_id.clone(),
+
+    /// Price (`44`).
+    ///
+    /// Price per unit of quantity of an order.
+    Price(Price) = 44 => price Vec::from(price),
+
+    /// Referenced message sequence number (`45`).
+    ///
+    /// The `MsgSeqNum` of the message a reject refers to.
+    RefSeqNum(RefSeqNum) = 45 => ref_seq_num format!("{ref_seq_num}").into_bytes(),
+
+    /// Security identifier (`48`).
+    ///
+    /// Identifies the instrument in the scheme given by the `SecurityIDSource`.
+    SecurityID(SecurityID) = 48 => security_id security_id.clone(),
+
     /// Sender company or system identifier (`49`).
     ///
     /// Identifies the sender of the message in a FIX session.
-    SenderCompID(SenderCompID) = 49 => sender_comp_id sender_comp_id.clone(),
+    SenderCompID(SenderCompID) = 49 => sender_comp_id Vec::from(sender_comp_id),
 
     /// Message sending time (`52`).
     ///
     /// Timestamp representing when the message was sent.
-    SendingTime(SendingTime) = 52 => sending_time sending_time.clone(),
+    SendingTime(SendingTime) = 52 => sending_time Vec::from(sending_time),
+
+    /// Side (`54`).
+    ///
+    /// Side of an order, e.g. buy or sell.
+    Side(Side) = 54 => side Vec::from(*side),
+
+    /// Ticker symbol (`55`).
+    ///
+    /// Identifies the instrument the message refers to.
+    Symbol(Symbol) = 55 => symbol symbol.clone(),
 
     /// Target company or system identifier (`56`).
     ///
     /// Identifies the intended recipient of the message in a FIX session.
-    TargetCompID(TargetCompID) = 56 => target_comp_id target_comp_id.clone()
+    TargetCompID(TargetCompID) = 56 => target_comp_id Vec::from(target_comp_id),
+
+    /// Free format text (`58`).
+    ///
+    /// Commonly used to explain rejections or session terminations.
+    Text(Text) = 58 => text text.clone(),
+
+    /// Time in force (`59`).
+    ///
+    /// How long an order remains in effect.
+    TimeInForce(TimeInForce) = 59 => time_in_force Vec::from(*time_in_force),
+
+    /// Transaction time (`60`).
+    ///
+    /// Timestamp of the business transaction, required on order messages.
+    TransactTime(TransactTime) = 60 => transact_time Vec::from(transact_time),
+
+    /// Trade date (`75`).
+    ///
+    /// Date of the trade, local to the exchange or market.
+    TradeDate(TradeDate) = 75 => trade_date Vec::from(trade_date),
+
+    /// Encryption method (`98`).
+    ///
+    /// Method of encryption used by the session, sent in the `Logon` message.
+    EncryptMethod(EncryptMethod) = 98 => encrypt_method Vec::from(*encrypt_method),
+
+    /// Cancel reject reason (`102`).
+    ///
+    /// Reason an order cancel or cancel/replace request was rejected.
+    CxlRejReason(CxlRejReason) = 102 => cxl_rej_reason Vec::from(*cxl_rej_reason),
+
+    /// Order reject reason (`103`).
+    ///
+    /// Reason an order was rejected.
+    OrdRejReason(OrdRejReason) = 103 => ord_rej_reason Vec::from(*ord_rej_reason),
+
+    /// Heartbeat interval (`108`).
+    ///
+    /// Interval in seconds between heartbeats, sent in the `Logon` message.
+    HeartBtInt(HeartBtInt) = 108 => heart_bt_int format!("{heart_bt_int}").into_bytes(),
+
+    /// Minimum quantity (`110`).
+    ///
+    /// Minimum quantity of an order to be executed.
+    MinQty(MinQty) = 110 => min_qty Vec::from(min_qty),
+
+    /// Maximum floor (`111`).
+    ///
+    /// Maximum quantity of an order to be shown at any given time, e.g. of an iceberg order.
+    MaxFloor(MaxFloor) = 111 => max_floor Vec::from(max_floor),
+
+    /// Test request identifier (`112`).
+    ///
+    /// Sent in a `TestRequest` and echoed back in the responding `Heartbeat`.
+    TestReqID(TestReqID) = 112 => test_req_id test_req_id.clone(),
+
+    /// Quote identifier (`117`).
+    ///
+    /// Identifies a quote, assigned by the sender of it.
+    QuoteID(QuoteID) = 117 => quote_id quote_id.clone(),
+
+    /// Gap fill flag (`123`).
+    ///
+    /// Distinguishes a gap filling `SequenceReset` (`Y`) from a hard reset (`N`).
+    GapFillFlag(GapFillFlag) = 123 => gap_fill_flag if *gap_fill_flag { b"Y".to_vec() } else { b"N".to_vec() },
+
+    /// Expire time (`126`).
+    ///
+    /// Time a Good-Till-Date order expires.
+    ExpireTime(ExpireTime) = 126 => expire_time Vec::from(expire_time),
+
+    /// Bid price (`132`).
+    ///
+    /// Bid price of a quote.
+    BidPx(BidPx) = 132 => bid_px Vec::from(bid_px),
+
+    /// Offer price (`133`).
+    ///
+    /// Offer price of a quote.
+    OfferPx(OfferPx) = 133 => offer_px Vec::from(offer_px),
+
+    /// Number of related symbols (`146`).
+    ///
+    /// Number of entries in the related symbols repeating group, each starting with `Symbol`.
+    NoRelatedSym(NoRelatedSym) = 146 => no_related_sym format!("{no_related_sym}").into_bytes(),
+
+    /// Leaves quantity (`151`).
+    ///
+    /// Quantity of an order still open for further execution.
+    LeavesQty(LeavesQty) = 151 => leaves_qty Vec::from(leaves_qty),
+
+    /// Security type (`167`).
+    ///
+    /// Type of the instrument, e.g. common stock or future.
+    SecurityType(SecurityType) = 167 => security_type Vec::from(*security_type),
+
+    /// XML data length (`212`).
+    ///
+    /// Length in bytes of the `XmlData` field, which must immediately follow this field.
+    XmlDataLen(XmlDataLen) = 212 => xml_data_len format!("{xml_data_len}").into_bytes(),
+
+    /// XML data (`213`).
+    ///
+    /// Raw XML payload, which may contain SOH bytes.
+    XmlData(XmlData) = 213 => xml_data xml_data.clone(),
+
+    /// Market data request identifier (`262`).
+    ///
+    /// Unique identifier of a market data request.
+    MDReqID(MDReqID) = 262 => md_req_id md_req_id.clone(),
+
+    /// Subscription request type (`263`).
+    ///
+    /// Whether a snapshot, a subscription or an unsubscription is requested.
+    SubscriptionRequestType(SubscriptionRequestType) = 263 => subscription_request_type Vec::from(*subscription_request_type),
+
+    /// Market depth (`264`).
+    ///
+    /// Depth of the requested market data, `0` means full book depth.
+    MarketDepth(MarketDepth) = 264 => market_depth format!("{market_depth}").into_bytes(),
+
+    /// Market data update type (`265`).
+    ///
+    /// Whether subscription updates carry the full book or only the changes to it.
+    MDUpdateType(MDUpdateType) = 265 => md_update_type Vec::from(*md_update_type),
+
+    /// Number of market data entries (`268`).
+    ///
+    /// Number of entries in the `NoMDEntries` repeating group.
+    NoMDEntries(NoMDEntries) = 268 => no_md_entries format!("{no_md_entries}").into_bytes(),
+
+    /// Market data update action (`279`).
+    ///
+    /// Action an entry of an incremental refresh applies to the book.
+    MDUpdateAction(MDUpdateAction) = 279 => md_update_action Vec::from(*md_update_action),
+
+    /// Market data request reject reason (`281`).
+    ///
+    /// Reason a market data request was rejected.
+    MDReqRejReason(MDReqRejReason) = 281 => md_req_rej_reason Vec::from(*md_req_rej_reason),
+
+    /// Last message sequence number processed (`369`).
+    ///
+    /// The last `MsgSeqNum` processed by the sender, carried in the header.
+    LastMsgSeqNumProcessed(LastMsgSeqNumProcessed) = 369 => last_msg_seq_num_processed format!("{last_msg_seq_num_processed}").into_bytes(),
+
+    /// Referenced tag (`371`).
+    ///
+    /// The tag number of the field a reject refers to.
+    RefTagID(RefTagID) = 371 => ref_tag_id format!("{ref_tag_id}").into_bytes(),
+
+    /// Referenced message type (`372`).
+    ///
+    /// The `MsgType` of the message a reject refers to.
+    RefMsgType(MsgType) = 372 => ref_msg_type Vec::from(ref_msg_type),
+
+    /// Session reject reason (`373`).
+    ///
+    /// Code identifying the reason of a session-level `Reject`.
+    SessionRejectReason(SessionRejectReason) = 373 => reason Vec::from(*reason),
+
+    /// Business reject reason (`380`).
+    ///
+    /// Code identifying the reason of a `BusinessMessageReject`.
+    BusinessRejectReason(BusinessRejectReason) = 380 => reason Vec::from(*reason),
+
+    /// Maximum message size (`383`).
+    ///
+    /// Maximum number of bytes of a message the sender of a `Logon` is able to receive.
+    MaxMessageSize(MaxMessageSize) = 383 => max_message_size format!("{max_message_size}").into_bytes(),
+
+    /// Cancel reject response to (`434`).
+    ///
+    /// Type of the request an `OrderCancelReject` responds to.
+    CxlRejResponseTo(CxlRejResponseTo) = 434 => cxl_rej_response_to Vec::from(*cxl_rej_response_to),
+
+    /// Party identifier source (`447`).
+    ///
+    /// Identification scheme of the `PartyID`, e.g. BIC or a proprietary code.
+    PartyIDSource(PartyIDSource) = 447 => source Vec::from(*source),
+
+    /// Party identifier (`448`).
+    ///
+    /// Identifies a party of the parties repeating group, e.g. a firm or a trader.
+    PartyID(PartyID) = 448 => party_id party_id.clone(),
+
+    /// Party role (`452`).
+    ///
+    /// Role of a party of the parties repeating group, e.g. executing firm or client.
+    PartyRole(PartyRole) = 452 => role Vec::from(*role),
+
+    /// Number of parties (`453`).
+    ///
+    /// Number of entries in the parties repeating group, each starting with `PartyID`.
+    NoPartyIDs(NoPartyIDs) = 453 => no_party_ids format!("{no_party_ids}").into_bytes(),
+
+    /// CFI code (`461`).
+    ///
+    /// ISO 10962 classification of the instrument.
+    CFICode(CFICode) = 461 => cfi_code Vec::from(cfi_code),
+
+    /// Test message indicator (`464`).
+    ///
+    /// Sent in a `Logon` to indicate whether the session carries test (`Y`) or production (`N`)
+    /// messages.
+    TestMessageIndicator(TestMessageIndicator) = 464 => test_message_indicator if *test_message_indicator { b"Y".to_vec() } else { b"N".to_vec() },
+
+    /// Party sub-identifier (`523`).
+    ///
+    /// Sub-identifier of a party of the parties repeating group, e.g. a desk or a contact.
+    PartySubID(PartySubID) = 523 => party_sub_id party_sub_id.clone(),
+
+    /// Order capacity (`528`).
+    ///
+    /// Capacity in which the firm placing the order acts, e.g. as agent or principal.
+    OrderCapacity(OrderCapacity) = 528 => order_capacity Vec::from(*order_capacity),
+
+    /// Order restrictions (`529`).
+    ///
+    /// Restrictions associated with the order, as a space-separated list.
+    OrderRestrictions(OrderRestrictions) = 529 => order_restrictions Vec::from(order_restrictions),
+
+    /// Username (`553`).
+    ///
+    /// User name sent in the `Logon` of an authenticated session.
+    Username(Username) = 553 => username username.clone(),
+
+    /// Password (`554`).
+    ///
+    /// Password sent in the `Logon` of an authenticated session, redacted in debug output.
+    Password(Password) = 554 => password Vec::from(password),
+
+    /// Number of party sub-identifiers (`802`).
+    ///
+    /// Number of entries in the party sub-identifiers repeating group, each starting with
+    /// `PartySubID`.
+    NoPartySubIDs(NoPartySubIDs) = 802 => no_party_sub_ids format!("{no_party_sub_ids}").into_bytes(),
+
+    /// Party sub-identifier type (`803`).
+    ///
+    /// Type of a `PartySubID`, e.g. `1` for a firm or `4000` and above for user-defined types.
+    PartySubIDType(PartySubIDType) = 803 => party_sub_id_type format!("{party_sub_id_type}").into_bytes(),
+
+    /// User-defined profit and loss (`20000`).
+    ///
+    /// Signed value, negative amounts are encoded with a leading `-` sign.
+    PnL(PnL) = 20000 => pnl format!("{pnl}").into_bytes()
+}
+
+impl Field {
+    /// Serializes the field into its `"tag=value"` representation, terminated by the SOH
+    /// delimiter (`0x01`).
+    ///
+    /// ```
+    /// use trafix_codec::message::field::Field;
+    /// let f = Field::MsgSeqNum(4);
+    /// assert_eq!(f.encode_with_soh(), b"34=4\x01".to_vec());
+    /// ```
+    #[must_use]
+    pub fn encode_with_soh(&self) -> Vec<u8> {
+        let mut field = self.encode();
+        field.push(constants::SOH);
+
+        field
+    }
+
+    /// Serializes the field into its `"tag=value"` representation, terminated by the SOH
+    /// delimiter (`0x01`), and appends it to the given buffer.
+    ///
+    /// ```
+    /// use bytes::BytesMut;
+    /// use trafix_codec::message::field::Field;
+    ///
+    /// let mut buf = BytesMut::new();
+    /// Field::MsgSeqNum(4).encode_into(&mut buf);
+    /// Field::MsgSeqNum(5).encode_into(&mut buf);
+    /// assert_eq!(&buf[..], b"34=4\x0134=5\x01");
+    /// ```
+    pub fn encode_into(&self, buf: &mut BytesMut) {
+        buf.extend_from_slice(&self.encode());
+        buf.put_u8(constants::SOH);
+    }
+}
+
+impl TryFrom<(u32, Vec<u8>)> for Field {
+    type Error = Box<dyn std::error::Error>;
+
+    /// Converts a raw `(tag, value)` pair into a [`Field`], the same way as [`Field::try_new`].
+    ///
+    /// ```
+    /// use trafix_codec::message::field::Field;
+    /// let field = Field::try_from((34, b"4".to_vec())).unwrap();
+    /// assert_eq!(field, Field::MsgSeqNum(4));
+    /// ```
+    fn try_from((tag, value): (u32, Vec<u8>)) -> Result<Self, Self::Error> {
+        Field::try_new(tag, &value)
+    }
+}
+
+impl From<&Field> for (u32, Vec<u8>) {
+    /// Converts a [`Field`] into its raw `(tag, value)` pair.
+    fn from(field: &Field) -> Self {
+        (field.tag(), field.value())
+    }
 }
 
 #[cfg(test)]
 mod test {
+    use std::borrow::Cow;
+
+    use bytes::BytesMut;
+
     use crate::message::field::{
         Field,
-        value::aliases::{MsgSeqNum, SenderCompID, SendingTime, TargetCompID},
+        raw::RawField,
+        value::{
+            FromFixBytes,
+            aliases::{MsgSeqNum, SenderCompID, SendingTime, TargetCompID, TradeDate},
+            handl_inst::HandlInst,
+            password::Password,
+            security_id_source::SecurityIDSource,
+            side::Side,
+        },
     };
 
     #[test]
@@ -174,7 +753,8 @@ mod test {
         let sender_comp_id_field = Field::SenderCompID(SenderCompID::new());
         assert_eq!(sender_comp_id_field.tag(), 49);
 
-        let sending_time_field = Field::SendingTime(SendingTime::new());
+        let sending_time = SendingTime::from_fix_bytes(b"20180920-18:14:19.508").unwrap();
+        let sending_time_field = Field::SendingTime(sending_time);
         assert_eq!(sending_time_field.tag(), 52);
 
         let target_comp_id_field = Field::TargetCompID(TargetCompID::new());
@@ -208,6 +788,144 @@ mod test {
         );
     }
 
+    #[test]
+    fn currency_field() {
+        let currency = Field::try_new(15, b"USD").unwrap();
+        assert!(matches!(currency, Field::Currency(code) if code.as_str() == "USD"));
+        assert_eq!(currency.encode(), b"15=USD".to_vec());
+
+        assert!(Field::try_new(15, b"usd").is_err());
+    }
+
+    #[test]
+    fn exec_inst_field() {
+        let exec_inst = Field::try_new(18, b"G 6").unwrap();
+        assert!(matches!(&exec_inst, Field::ExecInst(value) if value.iter().eq([b'G', b'6'])));
+        assert_eq!(exec_inst.encode(), b"18=G 6".to_vec());
+        assert!(matches!(exec_inst.value_ref(), Cow::Borrowed(b"G 6")));
+
+        assert!(Field::try_new(18, b"G6").is_err());
+    }
+
+    #[test]
+    fn ord_type_field() {
+        // all FIX 4.4 order types decode strictly and round-trip
+        for value in b"123456789ABCDEFGHIJKLMP" {
+            let field = Field::try_new(40, &[*value]).unwrap();
+            assert!(matches!(field, Field::OrdType(_)));
+            assert_eq!(field.value(), [*value]);
+        }
+
+        assert!(Field::try_new(40, b"Z").is_err());
+    }
+
+    #[test]
+    fn side_field() {
+        // all FIX 4.4 sides decode strictly and round-trip
+        for value in b"123456789ABCDEFG" {
+            let field = Field::try_new(54, &[*value]).unwrap();
+            assert!(matches!(field, Field::Side(_)));
+            assert_eq!(field.value(), [*value]);
+        }
+
+        assert!(Field::try_new(54, b"H").is_err());
+    }
+
+    #[test]
+    fn security_type_field() {
+        // a sample of the FIX 4.4 security types across the asset classes
+        for value in [
+            &b"FXSPOT"[..],
+            b"CP",
+            b"REPO",
+            b"TIPS",
+            b"MBS",
+            b"GO",
+            b"NONE",
+            b"?",
+        ] {
+            let field = Field::try_new(167, value).unwrap();
+            assert!(matches!(field, Field::SecurityType(_)));
+            assert_eq!(field.value(), value);
+        }
+
+        assert!(Field::try_new(167, b"XYZ").is_err());
+    }
+
+    #[test]
+    fn security_id_source_field() {
+        let field = Field::try_new(22, b"4321").unwrap();
+        assert_eq!(
+            field,
+            Field::SecurityIDSource(SecurityIDSource::UserDefined(4321))
+        );
+        assert_eq!(field.value(), b"4321");
+
+        // the user-defined range starts at 100, without leading zeros
+        for value in [&b"99"[..], b"0100", b"K", b"99999999999"] {
+            assert!(Field::try_new(22, value).is_err());
+        }
+    }
+
+    #[test]
+    fn handl_inst_field() {
+        let handl_inst = Field::try_new(21, b"2").unwrap();
+        assert_eq!(
+            handl_inst,
+            Field::HandlInst(HandlInst::AutomatedExecutionInterventionOk)
+        );
+        assert_eq!(handl_inst.encode(), b"21=2".to_vec());
+
+        assert!(Field::try_new(21, b"4").is_err());
+    }
+
+    #[test]
+    fn timestamp_fields() {
+        let transact_time = Field::try_new(60, b"20180920-18:14:19.492").unwrap();
+        assert!(matches!(transact_time, Field::TransactTime(_)));
+        assert_eq!(transact_time.encode(), b"60=20180920-18:14:19.492".to_vec());
+
+        let trade_date = Field::try_new(75, b"20180920").unwrap();
+        assert_eq!(
+            trade_date,
+            Field::TradeDate(TradeDate::new(2018, 9, 20).unwrap())
+        );
+        assert_eq!(trade_date.encode(), b"75=20180920".to_vec());
+
+        assert!(Field::try_new(60, b"20180920-25:14:19.492").is_err());
+        assert!(Field::try_new(75, b"20180230").is_err());
+    }
+
+    #[test]
+    fn signed_field() {
+        let pnl_field = Field::PnL(-150);
+
+        assert_eq!(pnl_field.tag(), 20000);
+        assert_eq!(pnl_field.value(), b"-150".to_vec());
+        assert_eq!(pnl_field.encode(), b"20000=-150".to_vec());
+
+        // round-trip through the typed decoding path
+        let decoded = Field::try_new(pnl_field.tag(), &pnl_field.value()).unwrap();
+        assert_eq!(decoded, pnl_field);
+
+        let positive = Field::try_new(20000, b"42").unwrap();
+        assert_eq!(positive, Field::PnL(42));
+
+        assert!(Field::try_new(20000, b"-").is_err());
+    }
+
+    #[test]
+    fn encode_with_soh() {
+        let field = Field::Text(b"hello".to_vec());
+
+        assert_eq!(field.encode_with_soh(), b"58=hello\x01".to_vec());
+
+        let mut buf = BytesMut::from(&b"35=0\x01"[..]);
+        field.encode_into(&mut buf);
+
+        assert_eq!(&buf[..], b"35=0\x0158=hello\x01");
+    }
+
     #[test]
     fn custom_field() {
         let tag = 62000;
@@ -228,4 +946,80 @@ mod test {
         // b"62000=trafix-codec"
         assert_eq!(custom_field.encode(), encoded);
     }
+
+    #[test]
+    fn tuple_round_trip() {
+        for field in [
+            Field::MsgSeqNum(4),
+            Field::Custom {
+                tag: 62000,
+                value: b"trafix-codec".to_vec(),
+            },
+        ] {
+            let pair = <(u32, Vec<u8>)>::from(&field);
+            assert_eq!(Field::try_from(pair).unwrap(), field);
+        }
+
+        assert_eq!(
+            <(u32, Vec<u8>)>::from(&Field::MsgSeqNum(4)),
+            (34, b"4".to_vec())
+        );
+        assert!(Field::try_from((34, b"four".to_vec())).is_err());
+    }
+
+    #[test]
+    fn value_ref() {
+        let custom = Field::Custom {
+            tag: 9999,
+            value: b"abc".to_vec(),
+        };
+        assert!(matches!(custom.value_ref(), Cow::Borrowed(b"abc")));
+
+        let side = Field::Side(Side::Sell);
+        assert!(matches!(side.value_ref(), Cow::Borrowed(b"2")));
+
+        let msg_seq_num = Field::MsgSeqNum(42);
+        assert!(matches!(msg_seq_num.value_ref(), Cow::Owned(_)));
+
+        for field in [custom, side, msg_seq_num] {
+            assert_eq!(field.value_ref().as_ref(), field.value());
+        }
+    }
+
+    #[test]
+    fn debug_redacts_password() {
+        assert_eq!(format!("{:?}", Field::MsgSeqNum(4)), "MsgSeqNum(4)");
+        assert_eq!(
+            format!("{:?}", Field::Password(Password::from(b"secret"))),
+            "Password(554=****)"
+        );
+        assert_eq!(
+            format!(
+                "{:?}",
+                Field::Custom {
+                    tag: 554,
+                    value: b"secret".to_vec(),
+                }
+            ),
+            "Custom { tag: 554, value: **** }"
+        );
+        assert_eq!(
+            format!(
+                "{:?}",
+                Field::Custom {
+                    tag: 9999,
+                    value: b"x".to_vec(),
+                }
+            ),
+            "Custom { tag: 9999, value: [120] }"
+        );
+        assert_eq!(
+            format!("{:?}", Field::Raw(RawField::new(b"554=secret").unwrap())),
+            "Raw(554=****)"
+        );
+        assert_eq!(
+            format!("{:?}", Field::Raw(RawField::new(b"58=Bye").unwrap())),
+            "Raw(58=Bye)"
+        );
+    }
 }
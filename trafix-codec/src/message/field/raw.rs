@@ -0,0 +1,97 @@
+//! Defines the [`RawField`] type, a pre-encoded `tag=value` field emitted as given.
+
+use std::fmt;
+
+use crate::constants;
+use crate::decoder::num::ParseFixInt as _;
+use crate::message::{RawFieldError, field::value::password::Password};
+
+/// Represents a pre-encoded `tag=value` field (without the SOH delimiter), held by
+/// [`Field::Raw`](crate::message::field::Field::Raw).
+///
+/// The bytes are validated on construction and copied into the encoded message as they are,
+/// without being parsed into a typed field, e.g. for splicing in cached fields that never
+/// change. Only the tag is parsed, so the field can be looked up by it.
+///
+/// The [`Debug`](fmt::Debug) representation redacts the password (`554`).
+#[derive(Clone, PartialEq, Eq)]
+pub struct RawField {
+    /// Tag of the field.
+    tag: u32,
+
+    /// The `tag=value` bytes, as given.
+    bytes: Vec<u8>,
+}
+
+impl RawField {
+    /// Creates a new [`RawField`] from the given `tag=value` bytes.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::field::raw::RawField;
+    ///
+    /// let raw = RawField::new(b"58=Bye").unwrap();
+    ///
+    /// assert_eq!(raw.tag(), 58);
+    /// assert_eq!(raw.value(), b"Bye");
+    /// assert_eq!(raw.as_bytes(), b"58=Bye");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RawFieldError`] if the bytes do not form exactly one `tag=value` field.
+    pub fn new(bytes: &[u8]) -> Result<Self, RawFieldError> {
+        if bytes.contains(&constants::SOH) {
+            return Err(RawFieldError::ContainsSoh);
+        }
+
+        let mut parts = bytes.splitn(3, |byte| *byte == constants::EQUALS);
+
+        let (Some(tag), Some(_), None) = (parts.next(), parts.next(), parts.next()) else {
+            return Err(RawFieldError::Equals);
+        };
+
+        if tag.is_empty() || !tag.iter().all(u8::is_ascii_digit) {
+            return Err(RawFieldError::BadTag);
+        }
+
+        let tag = u32::parse_fix_int(tag).map_err(|_| RawFieldError::BadTag)?;
+
+        Ok(Self {
+            tag,
+            bytes: bytes.to_vec(),
+        })
+    }
+
+    /// Returns the tag of the field.
+    #[must_use]
+    pub fn tag(&self) -> u32 {
+        self.tag
+    }
+
+    /// Returns the value of the field, i.e. the bytes after the `=`.
+    #[must_use]
+    pub fn value(&self) -> &[u8] {
+        // the bytes were validated to contain exactly one `=`
+        self.bytes
+            .iter()
+            .position(|byte| *byte == constants::EQUALS)
+            .map_or(&[], |equals| &self.bytes[equals + 1..])
+    }
+
+    /// Returns the `tag=value` bytes of the field, as given.
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl fmt::Debug for RawField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.tag == Password::tag() {
+            return write!(f, "{}=****", Password::tag());
+        }
+
+        write!(f, "{}", String::from_utf8_lossy(&self.bytes))
+    }
+}
@@ -6,6 +6,47 @@
 use std::convert::Infallible;
 
 use crate::message::field::value::FromFixBytes;
+use crate::message::field::value::comp_id::CompID;
+use crate::message::field::value::fix_decimal::FixDecimal;
+use crate::message::field::value::local_mkt_date::LocalMktDate;
+use crate::message::field::value::multiple_value::MultipleCharValue;
+use crate::message::field::value::utc_timestamp::UtcTimestamp;
+
+/// Represents the `AvgPx` (`6`).
+///
+/// Average price of all fills of an order.
+pub type AvgPx = FixDecimal;
+
+/// Represents the `CumQty` (`14`).
+///
+/// Total quantity filled so far for an order.
+pub type CumQty = FixDecimal;
+
+/// Represents the `ClOrdID` (`11`).
+///
+/// Unique identifier of an order, assigned by the institution sending it. Stored as raw bytes.
+pub type ClOrdID = Vec<u8>;
+
+/// Represents the `ExecID` (`17`).
+///
+/// Unique identifier of an execution report, assigned by the sender. Stored as raw bytes.
+pub type ExecID = Vec<u8>;
+
+/// Represents the `ExecInst` (`18`).
+///
+/// Instructions for the handling of an order, e.g. `G` for all or none. Multiple instructions are
+/// separated by spaces.
+pub type ExecInst = MultipleCharValue;
+
+/// Represents the `LastPx` (`31`).
+///
+/// Price of the last fill of an order.
+pub type LastPx = FixDecimal;
+
+/// Represents the `LastQty` (`32`).
+///
+/// Quantity of the last fill of an order.
+pub type LastQty = FixDecimal;
 
 /// Represents the `MsgSeqNum` (`34`).
 ///
@@ -13,25 +54,219 @@ use crate::message::field::value::FromFixBytes;
 /// ensuring ordering and detection of missing or duplicated messages.
 pub type MsgSeqNum = u64;
 
+/// Represents the `OrderQty` (`38`).
+///
+/// Quantity ordered.
+pub type OrderQty = FixDecimal;
+
+/// Represents the `OrigClOrdID` (`41`).
+///
+/// `ClOrdID` of the previous order in a cancel or cancel/replace chain. Stored as raw bytes.
+pub type OrigClOrdID = Vec<u8>;
+
+/// Represents the `NewSeqNo` (`36`).
+///
+/// The `MsgSeqNum` expected of the next message after a `SequenceReset`.
+pub type NewSeqNo = u64;
+
+/// Represents the `Price` (`44`).
+///
+/// Price per unit of quantity of an order.
+pub type Price = FixDecimal;
+
+/// Represents the `RefSeqNum` (`45`).
+///
+/// The `MsgSeqNum` of the message a reject refers to.
+pub type RefSeqNum = u64;
+
+/// Represents the `SecurityID` (`48`).
+///
+/// Identifier of a security in the scheme given by the `SecurityIDSource` (`22`), e.g. an ISIN.
+/// Stored as raw bytes.
+pub type SecurityID = Vec<u8>;
+
 /// Represents the `SenderCompID` (`49`).
 ///
 /// Identifies the sender of the FIX message (typically the firm,
 /// system, or gateway). Stored as raw bytes to preserve any
 /// non-UTF-8 or fixed-width encodings.
-pub type SenderCompID = Vec<u8>;
+pub type SenderCompID = CompID;
 
 /// Represents the `SendingTime` (`52`).
 ///
-/// Timestamp indicating when the message was sent.
-// TODO(kfejzic): Replace with a more specific time type, adhering to the
-// FIXs SendingTime ruling: YYYYMMDD-HH:MM:SS[.sss]
-pub type SendingTime = Vec<u8>;
+/// Timestamp indicating when the message was sent, in UTC.
+pub type SendingTime = UtcTimestamp;
+
+/// Represents the `Symbol` (`55`).
+///
+/// Ticker symbol of the instrument. Stored as raw bytes.
+pub type Symbol = Vec<u8>;
 
 /// Represents the `TargetCompID` (`56`).
 ///
 /// Identifies the intended recipient of the FIX message.
 /// Stored as raw bytes for full fidelity with on-wire data.
-pub type TargetCompID = Vec<u8>;
+pub type TargetCompID = CompID;
+
+/// Represents the `Text` (`58`).
+///
+/// Free format text string. Stored as raw bytes, as the encoding of the
+/// text is agreed upon between counterparties.
+pub type Text = Vec<u8>;
+
+/// Represents the `TransactTime` (`60`).
+///
+/// Timestamp indicating when the business transaction represented by the
+/// message occurred, in UTC. Required on order messages.
+pub type TransactTime = UtcTimestamp;
+
+/// Represents the `TradeDate` (`75`).
+///
+/// Date of the trade, local to the exchange or market.
+pub type TradeDate = LocalMktDate;
+
+/// Represents the `HeartBtInt` (`108`).
+///
+/// Heartbeat interval in seconds, agreed upon in the `Logon` exchange.
+pub type HeartBtInt = u32;
+
+/// Represents the `MinQty` (`110`).
+///
+/// Minimum quantity of an order to be executed.
+pub type MinQty = FixDecimal;
+
+/// Represents the `MaxFloor` (`111`).
+///
+/// Maximum quantity of an order to be shown on the exchange floor at any given time, e.g. the
+/// visible part of an iceberg order.
+pub type MaxFloor = FixDecimal;
+
+/// Represents the `TestReqID` (`112`).
+///
+/// Identifier of a `TestRequest`, echoed back by the `Heartbeat` sent in
+/// response to it. Stored as raw bytes.
+pub type TestReqID = Vec<u8>;
+
+/// Represents the `QuoteID` (`117`).
+///
+/// Identifies a quote, assigned by the sender of it.
+pub type QuoteID = Vec<u8>;
+
+/// Represents the `GapFillFlag` (`123`).
+///
+/// Whether a `SequenceReset` fills a gap of skipped messages (`Y`), or hard resets the
+/// sequence numbers (`N`, or absent).
+pub type GapFillFlag = bool;
+
+/// Represents the `ExpireTime` (`126`).
+///
+/// Time a Good-Till-Date order expires.
+pub type ExpireTime = UtcTimestamp;
+
+/// Represents the `BidPx` (`132`).
+///
+/// Bid price of a quote.
+pub type BidPx = FixDecimal;
+
+/// Represents the `OfferPx` (`133`).
+///
+/// Offer price of a quote.
+pub type OfferPx = FixDecimal;
+
+/// Represents the `NoRelatedSym` (`146`).
+///
+/// Number of entries in the related symbols repeating group.
+pub type NoRelatedSym = u32;
+
+/// Represents the `LeavesQty` (`151`).
+///
+/// Quantity of an order still open for further execution.
+pub type LeavesQty = FixDecimal;
+
+/// Represents the `XmlDataLen` (`212`).
+///
+/// Length in bytes of the `XmlData` field that follows it.
+pub type XmlDataLen = u32;
+
+/// Represents the `XmlData` (`213`).
+///
+/// Raw XML payload, such as a FIXML message. The payload may contain SOH bytes, as its extent is
+/// given by the preceding `XmlDataLen`.
+pub type XmlData = Vec<u8>;
+
+/// Represents the `MDReqID` (`262`).
+///
+/// Unique identifier of a market data request, echoed back in responses
+/// and used to unsubscribe. Stored as raw bytes.
+pub type MDReqID = Vec<u8>;
+
+/// Represents the `MarketDepth` (`264`).
+///
+/// Depth of the requested market data, where `0` means full book depth
+/// and `1` means top of book.
+pub type MarketDepth = u32;
+
+/// Represents the `NoMDEntries` (`268`).
+///
+/// Number of market data entries in the repeating group following it.
+pub type NoMDEntries = u32;
+
+/// Represents the `LastMsgSeqNumProcessed` (`369`).
+///
+/// The last `MsgSeqNum` (`34`) processed by the sender of the message.
+pub type LastMsgSeqNumProcessed = u64;
+
+/// Represents the `RefTagID` (`371`).
+///
+/// The tag number of the field a reject refers to.
+pub type RefTagID = u32;
+
+/// Represents the `MaxMessageSize` (`383`).
+///
+/// Maximum number of bytes of a message the sender of a `Logon` is able to receive.
+pub type MaxMessageSize = u32;
+
+/// Represents the `PartyID` (`448`).
+///
+/// Identifier of a party in the scheme given by the `PartyIDSource` (`447`). Stored as raw bytes.
+pub type PartyID = Vec<u8>;
+
+/// Represents the `NoPartyIDs` (`453`).
+///
+/// Number of entries in the parties repeating group, each starting with `PartyID`.
+pub type NoPartyIDs = u32;
+
+/// Represents the `TestMessageIndicator` (`464`).
+///
+/// Indicates whether the session carries test (`Y`) or production (`N`) messages.
+pub type TestMessageIndicator = bool;
+
+/// Represents a user-defined profit and loss (`20000`) value.
+///
+/// Signed integer value that may be negative, transmitted with a leading `-`
+/// sign on the wire (e.g. `20000=-150`). Tag `20000` belongs to the FIX
+/// user-defined range reserved for internal use between counterparties.
+pub type PnL = i64;
+
+/// The error type for failed parsing of FIX `Boolean` values.
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+#[error("invalid boolean, expected 'Y' or 'N': {}", String::from_utf8_lossy(.0))]
+pub struct ParseBoolError<'input>(&'input [u8]);
+
+impl FromFixBytes for bool {
+    type Error<'input> = ParseBoolError<'input>;
+
+    fn from_fix_bytes(bytes: &[u8]) -> Result<Self, Self::Error<'_>>
+    where
+        Self: Sized,
+    {
+        match bytes {
+            b"Y" => Ok(true),
+            b"N" => Ok(false),
+            other => Err(ParseBoolError(other)),
+        }
+    }
+}
 
 impl FromFixBytes for Vec<u8> {
     type Error<'unused> = Infallible;
@@ -43,3 +278,26 @@ impl FromFixBytes for Vec<u8> {
         Ok(bytes.into())
     }
 }
+
+/// Represents the `PartySubID` (`523`).
+///
+/// Sub-identifier of a party, e.g. a desk or a contact, whose meaning is given by the
+/// `PartySubIDType` (`803`). Stored as raw bytes.
+pub type PartySubID = Vec<u8>;
+
+/// Represents the `Username` (`553`).
+///
+/// User name sent in the `Logon` of an authenticated session.
+pub type Username = Vec<u8>;
+
+/// Represents the `NoPartySubIDs` (`802`).
+///
+/// Number of entries in the party sub-identifiers repeating group nested in each party, each
+/// starting with `PartySubID`.
+pub type NoPartySubIDs = u32;
+
+/// Represents the `PartySubIDType` (`803`).
+///
+/// Type of a `PartySubID` (`523`), e.g. `1` for a firm or `4000` and above for user-defined
+/// types.
+pub type PartySubIDType = u32;
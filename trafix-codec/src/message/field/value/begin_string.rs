@@ -11,14 +11,20 @@ use crate::message::field::value::FromFixBytes;
 /// that apply to subsequent tags in the message.
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum BeginString {
+    /// FIX.4.2 protocol version (`8=FIX.4.2`).
+    FIX42,
+
     /// FIX.4.4 protocol version (`8=FIX.4.4`).
     FIX44,
+
+    /// FIXT.1.1 transport version (`8=FIXT.1.1`), used by FIX 5.0 and later sessions.
+    FIXT11,
 }
 
 impl BeginString {
     /// Returns the tag used for [`BeginString`].
     #[must_use]
-    pub const fn tag() -> u16 {
+    pub const fn tag() -> u32 {
         8
     }
 }
@@ -38,7 +44,9 @@ impl From<BeginString> for &'static [u8] {
     /// ```
     fn from(val: BeginString) -> Self {
         match val {
+            BeginString::FIX42 => b"FIX.4.2",
             BeginString::FIX44 => b"FIX.4.4",
+            BeginString::FIXT11 => b"FIXT.1.1",
         }
     }
 }
@@ -73,10 +81,11 @@ impl FromFixBytes for BeginString {
     where
         Self: Sized,
     {
-        if bytes == <&[u8]>::from(BeginString::FIX44) {
-            Ok(BeginString::FIX44)
-        } else {
-            Err(ParseError::Unsupported(bytes))
+        match bytes {
+            b"FIX.4.2" => Ok(BeginString::FIX42),
+            b"FIX.4.4" => Ok(BeginString::FIX44),
+            b"FIXT.1.1" => Ok(BeginString::FIXT11),
+            other => Err(ParseError::Unsupported(other)),
         }
     }
 }
@@ -0,0 +1,108 @@
+//! Defines the [`BusinessRejectReason`] enumeration representing the FIX
+//! **380 `BusinessRejectReason`** field value.
+
+use crate::message::field::value::FromFixBytes;
+
+/// Represents the reason (`380`) a `BusinessMessageReject` was sent.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BusinessRejectReason {
+    /// Other reason (`380=0`).
+    Other,
+
+    /// Unknown ID (`380=1`).
+    UnknownId,
+
+    /// Unknown security (`380=2`).
+    UnknownSecurity,
+
+    /// Unsupported message type (`380=3`).
+    UnsupportedMessageType,
+
+    /// Application not available (`380=4`).
+    ApplicationNotAvailable,
+
+    /// Conditionally required field missing (`380=5`).
+    ConditionallyRequiredFieldMissing,
+
+    /// Not authorized (`380=6`).
+    NotAuthorized,
+
+    /// `DeliverTo` firm not available at this time (`380=7`).
+    DeliverToFirmNotAvailable,
+
+    /// Invalid price increment (`380=18`).
+    InvalidPriceIncrement,
+}
+
+impl BusinessRejectReason {
+    /// Returns the tag used for [`BusinessRejectReason`].
+    #[must_use]
+    pub const fn tag() -> u32 {
+        380
+    }
+}
+
+impl From<BusinessRejectReason> for &'static [u8] {
+    /// Converts a [`BusinessRejectReason`] variant into its **static byte slice**
+    /// representation, corresponding to the FIX wire value of tag **380**.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::field::value::business_reject_reason::BusinessRejectReason;
+    /// let bytes: &'static [u8] = BusinessRejectReason::NotAuthorized.into();
+    /// assert_eq!(bytes, b"6");
+    /// ```
+    fn from(val: BusinessRejectReason) -> Self {
+        match val {
+            BusinessRejectReason::Other => b"0",
+            BusinessRejectReason::UnknownId => b"1",
+            BusinessRejectReason::UnknownSecurity => b"2",
+            BusinessRejectReason::UnsupportedMessageType => b"3",
+            BusinessRejectReason::ApplicationNotAvailable => b"4",
+            BusinessRejectReason::ConditionallyRequiredFieldMissing => b"5",
+            BusinessRejectReason::NotAuthorized => b"6",
+            BusinessRejectReason::DeliverToFirmNotAvailable => b"7",
+            BusinessRejectReason::InvalidPriceIncrement => b"18",
+        }
+    }
+}
+
+impl From<BusinessRejectReason> for Vec<u8> {
+    /// Converts a [`BusinessRejectReason`] variant into an **owned `Vec<u8>`**
+    /// containing its FIX wire representation (tag **380** value).
+    fn from(val: BusinessRejectReason) -> Self {
+        <&[u8]>::from(val).to_vec()
+    }
+}
+
+/// The error type for failed parsing of [`BusinessRejectReason`]
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ParseError<'input> {
+    /// Provided byte slice contains data that is not a valid or supported reject reason.
+    #[error("unsupported business reject reason: {}", String::from_utf8_lossy(.0))]
+    Unsupported(&'input [u8]),
+}
+
+impl FromFixBytes for BusinessRejectReason {
+    type Error<'input> = ParseError<'input>;
+
+    const ENUMERATED: bool = true;
+
+    fn from_fix_bytes(bytes: &[u8]) -> Result<Self, Self::Error<'_>>
+    where
+        Self: Sized,
+    {
+        match bytes {
+            b"0" => Ok(BusinessRejectReason::Other),
+            b"1" => Ok(BusinessRejectReason::UnknownId),
+            b"2" => Ok(BusinessRejectReason::UnknownSecurity),
+            b"3" => Ok(BusinessRejectReason::UnsupportedMessageType),
+            b"4" => Ok(BusinessRejectReason::ApplicationNotAvailable),
+            b"5" => Ok(BusinessRejectReason::ConditionallyRequiredFieldMissing),
+            b"6" => Ok(BusinessRejectReason::NotAuthorized),
+            b"7" => Ok(BusinessRejectReason::DeliverToFirmNotAvailable),
+            b"18" => Ok(BusinessRejectReason::InvalidPriceIncrement),
+            other => Err(ParseError::Unsupported(other)),
+        }
+    }
+}
@@ -0,0 +1,120 @@
+//! Defines the [`CFICode`] type, representing the FIX **461 `CFICode`** field value.
+
+use crate::message::field::value::FromFixBytes;
+
+/// Represents an ISO 10962 classification of financial instruments (CFI) code, e.g. the
+/// `CFICode` (`461`).
+///
+/// The code is validated on construction, so a [`CFICode`] always consists of exactly six ASCII
+/// alphanumeric characters: the category, the group and four attributes of the instrument.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CFICode([u8; 6]);
+
+impl CFICode {
+    /// Creates a new [`CFICode`] from its six-character code.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::field::value::cfi_code::CFICode;
+    ///
+    /// let code = CFICode::new(b"ESVUFR").unwrap();
+    ///
+    /// assert_eq!(code.category(), 'E');
+    /// assert_eq!(code.group(), 'S');
+    /// assert_eq!(code.attributes(), "VUFR");
+    /// assert!(CFICode::new(b"ESVUF").is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseError::Invalid`] if the code does not consist of exactly six ASCII
+    /// alphanumeric characters.
+    pub fn new(code: &[u8]) -> Result<Self, ParseError<'_>> {
+        match <[u8; 6]>::try_from(code) {
+            Ok(bytes) if bytes.iter().all(u8::is_ascii_alphanumeric) => Ok(Self(bytes)),
+            _ => Err(ParseError::Invalid(code)),
+        }
+    }
+
+    /// Returns the tag used for [`CFICode`].
+    #[must_use]
+    pub const fn tag() -> u32 {
+        461
+    }
+
+    /// Returns the six-character code.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        // INVARIANT: code consists of ASCII alphanumeric characters, so it is always valid UTF-8
+        std::str::from_utf8(&self.0).unwrap_or_default()
+    }
+
+    /// Returns the category of the instrument, the first character of the code, e.g. `E` for
+    /// equities.
+    #[must_use]
+    pub fn category(&self) -> char {
+        char::from(self.0[0])
+    }
+
+    /// Returns the group of the instrument within its category, the second character of the
+    /// code, e.g. `S` for shares.
+    #[must_use]
+    pub fn group(&self) -> char {
+        char::from(self.0[1])
+    }
+
+    /// Returns the four attributes of the instrument, the remaining characters of the code.
+    #[must_use]
+    pub fn attributes(&self) -> &str {
+        &self.as_str()[2..]
+    }
+}
+
+impl From<&CFICode> for Vec<u8> {
+    /// Converts a [`CFICode`] into an **owned `Vec<u8>`** containing its six-character code.
+    fn from(val: &CFICode) -> Self {
+        val.0.to_vec()
+    }
+}
+
+/// The error type for failed parsing of [`CFICode`]
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ParseError<'input> {
+    /// Provided byte slice is not a six-character alphanumeric CFI code.
+    #[error("invalid CFI code: {}", String::from_utf8_lossy(.0))]
+    Invalid(&'input [u8]),
+}
+
+impl FromFixBytes for CFICode {
+    type Error<'input> = ParseError<'input>;
+
+    fn from_fix_bytes(bytes: &[u8]) -> Result<Self, Self::Error<'_>>
+    where
+        Self: Sized,
+    {
+        CFICode::new(bytes)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{CFICode, ParseError};
+    use crate::message::field::value::FromFixBytes;
+
+    #[test]
+    fn parse() {
+        let code = CFICode::from_fix_bytes(b"OCASPS").unwrap();
+
+        assert_eq!(code.as_str(), "OCASPS");
+        assert_eq!(code.category(), 'O');
+        assert_eq!(code.group(), 'C');
+        assert_eq!(Vec::from(&code), b"OCASPS");
+
+        for input in [&b"OCASP"[..], b"OCASPSX", b"OC-SPS", b"OC SPS", b""] {
+            assert_eq!(
+                CFICode::from_fix_bytes(input),
+                Err(ParseError::Invalid(input))
+            );
+        }
+    }
+}
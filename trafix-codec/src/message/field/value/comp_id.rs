@@ -0,0 +1,213 @@
+//! Defines the [`CompID`] type, representing the FIX **49 `SenderCompID`** and
+//! **56 `TargetCompID`** field values, and the [`Interner`] used to share their storage.
+
+use std::borrow::Borrow;
+use std::collections::HashSet;
+use std::convert::Infallible;
+use std::ops::Deref;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use crate::message::field::value::FromFixBytes;
+
+/// Represents a company or system identifier, such as the `SenderCompID` (`49`) or the
+/// `TargetCompID` (`56`).
+///
+/// The identifier is stored as raw bytes behind an [`Arc`], so identifiers decoded with an
+/// [`Interner`] share their storage, and cloning is cheap.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CompID(Arc<[u8]>);
+
+impl CompID {
+    /// Creates a new, empty [`CompID`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` if both identifiers share the same storage.
+    #[must_use]
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        Arc::ptr_eq(&this.0, &other.0)
+    }
+}
+
+impl Deref for CompID {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl AsRef<[u8]> for CompID {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl Borrow<[u8]> for CompID {
+    fn borrow(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<&[u8]> for CompID {
+    fn from(value: &[u8]) -> Self {
+        Self(value.into())
+    }
+}
+
+impl<const N: usize> From<&[u8; N]> for CompID {
+    fn from(value: &[u8; N]) -> Self {
+        Self(value.as_slice().into())
+    }
+}
+
+impl From<Vec<u8>> for CompID {
+    fn from(value: Vec<u8>) -> Self {
+        Self(value.into())
+    }
+}
+
+impl From<&CompID> for Vec<u8> {
+    /// Converts a [`CompID`] into an **owned `Vec<u8>`** containing its raw bytes.
+    fn from(val: &CompID) -> Self {
+        val.0.to_vec()
+    }
+}
+
+impl PartialEq<CompID> for Vec<u8> {
+    fn eq(&self, other: &CompID) -> bool {
+        self.as_slice() == &*other.0
+    }
+}
+
+impl PartialEq<[u8]> for CompID {
+    fn eq(&self, other: &[u8]) -> bool {
+        &*self.0 == other
+    }
+}
+
+impl FromFixBytes for CompID {
+    type Error<'unused> = Infallible;
+
+    fn from_fix_bytes(bytes: &[u8]) -> Result<Self, Self::Error<'_>>
+    where
+        Self: Sized,
+    {
+        Ok(bytes.into())
+    }
+}
+
+/// Pool of [`CompID`]s, used to share the storage of identical identifiers across decoded
+/// messages.
+///
+/// Within a FIX session, the `SenderCompID` and `TargetCompID` are identical across all
+/// messages, so decoding with an [`Interner`] avoids allocating them for every message. An
+/// interner is meant to be scoped to a single session: its pool is guarded by a lock taken for
+/// every interned identifier, and holds at most [`Interner::capacity`] identifiers, so a peer
+/// sending many distinct identifiers cannot grow it without limit.
+///
+/// Example usage:
+/// ```
+/// use trafix_codec::message::field::value::comp_id::{CompID, Interner};
+///
+/// let interner = Interner::new();
+///
+/// let first = interner.intern(b"SENDER");
+/// let second = interner.intern(b"SENDER");
+///
+/// assert!(CompID::ptr_eq(&first, &second));
+/// assert_eq!(interner.len(), 1);
+/// ```
+#[derive(Debug)]
+pub struct Interner {
+    /// Identifiers interned so far.
+    pool: Mutex<HashSet<CompID>>,
+
+    /// Maximum number of identifiers in the pool.
+    capacity: usize,
+}
+
+impl Interner {
+    /// Maximum number of identifiers pooled by an [`Interner`] created with [`Interner::new`],
+    /// plenty for the identifiers of a single session.
+    pub const DEFAULT_CAPACITY: usize = 16;
+
+    /// Creates a new, empty [`Interner`] holding at most [`Interner::DEFAULT_CAPACITY`]
+    /// identifiers.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_capacity(Self::DEFAULT_CAPACITY)
+    }
+
+    /// Creates a new, empty [`Interner`] holding at most `capacity` identifiers. Once the pool
+    /// is full, new identifiers are returned without being pooled.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::field::value::comp_id::{CompID, Interner};
+    ///
+    /// let interner = Interner::with_capacity(1);
+    ///
+    /// let sender = interner.intern(b"SENDER");
+    /// let target = interner.intern(b"TARGET");
+    ///
+    /// // the pool is full, so the target is not pooled
+    /// assert_eq!(interner.len(), 1);
+    /// assert!(!CompID::ptr_eq(&target, &interner.intern(b"TARGET")));
+    /// assert!(CompID::ptr_eq(&sender, &interner.intern(b"SENDER")));
+    /// ```
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            pool: Mutex::default(),
+            capacity,
+        }
+    }
+
+    /// Returns the maximum number of identifiers in the pool.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the pooled [`CompID`] equal to the given bytes, adding it to the pool first if
+    /// not present yet. If the pool is full, a new [`CompID`] is returned without pooling it.
+    pub fn intern(&self, bytes: &[u8]) -> CompID {
+        let mut pool = self.pool.lock().unwrap_or_else(PoisonError::into_inner);
+
+        if let Some(comp_id) = pool.get(bytes) {
+            return comp_id.clone();
+        }
+
+        let comp_id = CompID::from(bytes);
+
+        if pool.len() < self.capacity {
+            pool.insert(comp_id.clone());
+        }
+
+        comp_id
+    }
+
+    /// Returns the number of distinct identifiers in the pool.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.pool
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .len()
+    }
+
+    /// Returns `true` if the pool contains no identifiers.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Default for Interner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,93 @@
+//! Defines the [`Currency`] type, representing the FIX **15 `Currency`** field value.
+
+use crate::message::field::value::FromFixBytes;
+
+/// Represents an ISO 4217 currency code, e.g. the `Currency` (`15`).
+///
+/// The code is validated on construction, so a [`Currency`] always consists of exactly three
+/// ASCII uppercase letters.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Currency([u8; 3]);
+
+impl Currency {
+    /// Creates a new [`Currency`] from its three-letter code.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::field::value::currency::Currency;
+    ///
+    /// assert_eq!(Currency::new(b"USD").unwrap().as_str(), "USD");
+    /// assert!(Currency::new(b"usd").is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseError::Invalid`] if the code does not consist of exactly three ASCII
+    /// uppercase letters.
+    pub fn new(code: &[u8]) -> Result<Self, ParseError<'_>> {
+        match code {
+            [a, b, c] if code.iter().all(u8::is_ascii_uppercase) => Ok(Self([*a, *b, *c])),
+            _ => Err(ParseError::Invalid(code)),
+        }
+    }
+
+    /// Returns the tag used for [`Currency`].
+    #[must_use]
+    pub const fn tag() -> u32 {
+        15
+    }
+
+    /// Returns the three-letter code of this currency.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        // INVARIANT: code consists of ASCII uppercase letters, so it is always valid UTF-8
+        std::str::from_utf8(&self.0).unwrap_or_default()
+    }
+}
+
+impl From<&Currency> for Vec<u8> {
+    /// Converts a [`Currency`] into an **owned `Vec<u8>`** containing its three-letter code.
+    fn from(val: &Currency) -> Self {
+        val.0.to_vec()
+    }
+}
+
+/// The error type for failed parsing of [`Currency`]
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ParseError<'input> {
+    /// Provided byte slice is not a three-letter uppercase currency code.
+    #[error("invalid currency code: {}", String::from_utf8_lossy(.0))]
+    Invalid(&'input [u8]),
+}
+
+impl FromFixBytes for Currency {
+    type Error<'input> = ParseError<'input>;
+
+    fn from_fix_bytes(bytes: &[u8]) -> Result<Self, Self::Error<'_>>
+    where
+        Self: Sized,
+    {
+        Currency::new(bytes)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Currency, ParseError};
+    use crate::message::field::value::FromFixBytes;
+
+    #[test]
+    fn parse() {
+        let currency = Currency::from_fix_bytes(b"USD").unwrap();
+
+        assert_eq!(currency.as_str(), "USD");
+        assert_eq!(Vec::from(&currency), b"USD");
+
+        for input in [&b"US"[..], b"usd", b"USDX", b"U1D", b""] {
+            assert_eq!(
+                Currency::from_fix_bytes(input),
+                Err(ParseError::Invalid(input))
+            );
+        }
+    }
+}
@@ -0,0 +1,104 @@
+//! Defines the [`CxlRejReason`] enumeration representing the FIX
+//! **102 `CxlRejReason`** field value.
+
+use crate::message::field::value::FromFixBytes;
+
+/// Represents the reason (`102`) of an `OrderCancelReject`, rejecting a cancel or cancel/replace
+/// request.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CxlRejReason {
+    /// Too late to cancel (`102=0`).
+    TooLateToCancel,
+
+    /// Unknown order (`102=1`).
+    UnknownOrder,
+
+    /// Broker / exchange option (`102=2`).
+    BrokerOption,
+
+    /// Order already in pending cancel or pending replace status (`102=3`).
+    PendingCancelOrReplace,
+
+    /// Unable to process order mass cancel request (`102=4`).
+    UnableToProcessMassCancel,
+
+    /// `OrigOrdModTime` did not match the last `TransactTime` of the order (`102=5`).
+    OrigOrdModTimeMismatch,
+
+    /// Duplicate `ClOrdID` received (`102=6`).
+    DuplicateClOrdID,
+
+    /// Other (`102=99`).
+    Other,
+}
+
+impl CxlRejReason {
+    /// Returns the tag used for [`CxlRejReason`].
+    #[must_use]
+    pub const fn tag() -> u32 {
+        102
+    }
+}
+
+impl From<CxlRejReason> for &'static [u8] {
+    /// Converts a [`CxlRejReason`] variant into its **static byte slice**
+    /// representation, corresponding to the FIX wire value of tag **102**.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::field::value::cxl_rej_reason::CxlRejReason;
+    /// let bytes: &'static [u8] = CxlRejReason::UnknownOrder.into();
+    /// assert_eq!(bytes, b"1");
+    /// ```
+    fn from(val: CxlRejReason) -> Self {
+        match val {
+            CxlRejReason::TooLateToCancel => b"0",
+            CxlRejReason::UnknownOrder => b"1",
+            CxlRejReason::BrokerOption => b"2",
+            CxlRejReason::PendingCancelOrReplace => b"3",
+            CxlRejReason::UnableToProcessMassCancel => b"4",
+            CxlRejReason::OrigOrdModTimeMismatch => b"5",
+            CxlRejReason::DuplicateClOrdID => b"6",
+            CxlRejReason::Other => b"99",
+        }
+    }
+}
+
+impl From<CxlRejReason> for Vec<u8> {
+    /// Converts a [`CxlRejReason`] variant into an **owned `Vec<u8>`**
+    /// containing its FIX wire representation (tag **102** value).
+    fn from(val: CxlRejReason) -> Self {
+        <&[u8]>::from(val).to_vec()
+    }
+}
+
+/// The error type for failed parsing of [`CxlRejReason`]
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ParseError<'input> {
+    /// Provided byte slice contains data that is not a valid or supported cancel reject reason.
+    #[error("unsupported cancel reject reason: {}", String::from_utf8_lossy(.0))]
+    Unsupported(&'input [u8]),
+}
+
+impl FromFixBytes for CxlRejReason {
+    type Error<'input> = ParseError<'input>;
+
+    const ENUMERATED: bool = true;
+
+    fn from_fix_bytes(bytes: &[u8]) -> Result<Self, Self::Error<'_>>
+    where
+        Self: Sized,
+    {
+        match bytes {
+            b"0" => Ok(CxlRejReason::TooLateToCancel),
+            b"1" => Ok(CxlRejReason::UnknownOrder),
+            b"2" => Ok(CxlRejReason::BrokerOption),
+            b"3" => Ok(CxlRejReason::PendingCancelOrReplace),
+            b"4" => Ok(CxlRejReason::UnableToProcessMassCancel),
+            b"5" => Ok(CxlRejReason::OrigOrdModTimeMismatch),
+            b"6" => Ok(CxlRejReason::DuplicateClOrdID),
+            b"99" => Ok(CxlRejReason::Other),
+            other => Err(ParseError::Unsupported(other)),
+        }
+    }
+}
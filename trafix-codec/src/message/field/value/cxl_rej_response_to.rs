@@ -0,0 +1,74 @@
+//! Defines the [`CxlRejResponseTo`] enumeration representing the FIX
+//! **434 `CxlRejResponseTo`** field value.
+
+use crate::message::field::value::FromFixBytes;
+
+/// Represents the type of request (`434`) an `OrderCancelReject` responds to.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CxlRejResponseTo {
+    /// Order cancel request (`434=1`).
+    OrderCancelRequest,
+
+    /// Order cancel/replace request (`434=2`).
+    OrderCancelReplaceRequest,
+}
+
+impl CxlRejResponseTo {
+    /// Returns the tag used for [`CxlRejResponseTo`].
+    #[must_use]
+    pub const fn tag() -> u32 {
+        434
+    }
+}
+
+impl From<CxlRejResponseTo> for &'static [u8] {
+    /// Converts a [`CxlRejResponseTo`] variant into its **static byte slice**
+    /// representation, corresponding to the FIX wire value of tag **434**.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::field::value::cxl_rej_response_to::CxlRejResponseTo;
+    /// let bytes: &'static [u8] = CxlRejResponseTo::OrderCancelReplaceRequest.into();
+    /// assert_eq!(bytes, b"2");
+    /// ```
+    fn from(val: CxlRejResponseTo) -> Self {
+        match val {
+            CxlRejResponseTo::OrderCancelRequest => b"1",
+            CxlRejResponseTo::OrderCancelReplaceRequest => b"2",
+        }
+    }
+}
+
+impl From<CxlRejResponseTo> for Vec<u8> {
+    /// Converts a [`CxlRejResponseTo`] variant into an **owned `Vec<u8>`**
+    /// containing its FIX wire representation (tag **434** value).
+    fn from(val: CxlRejResponseTo) -> Self {
+        <&[u8]>::from(val).to_vec()
+    }
+}
+
+/// The error type for failed parsing of [`CxlRejResponseTo`]
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ParseError<'input> {
+    /// Provided byte slice contains data that is not a valid or supported cancel reject response
+    /// type.
+    #[error("unsupported cancel reject response type: {}", String::from_utf8_lossy(.0))]
+    Unsupported(&'input [u8]),
+}
+
+impl FromFixBytes for CxlRejResponseTo {
+    type Error<'input> = ParseError<'input>;
+
+    const ENUMERATED: bool = true;
+
+    fn from_fix_bytes(bytes: &[u8]) -> Result<Self, Self::Error<'_>>
+    where
+        Self: Sized,
+    {
+        match bytes {
+            b"1" => Ok(CxlRejResponseTo::OrderCancelRequest),
+            b"2" => Ok(CxlRejResponseTo::OrderCancelReplaceRequest),
+            other => Err(ParseError::Unsupported(other)),
+        }
+    }
+}
@@ -0,0 +1,98 @@
+//! Defines the [`EncryptMethod`] enumeration representing the FIX
+//! **98 `EncryptMethod`** field value.
+
+use crate::message::field::value::FromFixBytes;
+
+/// Represents the method of encryption (`98`) used by a session, sent in the `Logon` message.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum EncryptMethod {
+    /// None / other (`98=0`), the message is not encrypted.
+    None,
+
+    /// PKCS, proprietary (`98=1`).
+    Pkcs,
+
+    /// DES, ECB mode (`98=2`).
+    Des,
+
+    /// PKCS/DES, proprietary (`98=3`).
+    PkcsDes,
+
+    /// PGP/DES, defunct (`98=4`).
+    PgpDes,
+
+    /// PGP/DES-MD5 (`98=5`).
+    PgpDesMd5,
+
+    /// PEM/DES-MD5 (`98=6`).
+    PemDesMd5,
+}
+
+impl EncryptMethod {
+    /// Returns the tag used for [`EncryptMethod`].
+    #[must_use]
+    pub const fn tag() -> u32 {
+        98
+    }
+}
+
+impl From<EncryptMethod> for &'static [u8] {
+    /// Converts a [`EncryptMethod`] variant into its **static byte slice**
+    /// representation, corresponding to the FIX wire value of tag **98**.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::field::value::encrypt_method::EncryptMethod;
+    /// let bytes: &'static [u8] = EncryptMethod::None.into();
+    /// assert_eq!(bytes, b"0");
+    /// ```
+    fn from(val: EncryptMethod) -> Self {
+        match val {
+            EncryptMethod::None => b"0",
+            EncryptMethod::Pkcs => b"1",
+            EncryptMethod::Des => b"2",
+            EncryptMethod::PkcsDes => b"3",
+            EncryptMethod::PgpDes => b"4",
+            EncryptMethod::PgpDesMd5 => b"5",
+            EncryptMethod::PemDesMd5 => b"6",
+        }
+    }
+}
+
+impl From<EncryptMethod> for Vec<u8> {
+    /// Converts a [`EncryptMethod`] variant into an **owned `Vec<u8>`**
+    /// containing its FIX wire representation (tag **98** value).
+    fn from(val: EncryptMethod) -> Self {
+        <&[u8]>::from(val).to_vec()
+    }
+}
+
+/// The error type for failed parsing of [`EncryptMethod`]
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ParseError<'input> {
+    /// Provided byte slice contains data that is not a valid or supported encryption method.
+    #[error("unsupported encryption method: {}", String::from_utf8_lossy(.0))]
+    Unsupported(&'input [u8]),
+}
+
+impl FromFixBytes for EncryptMethod {
+    type Error<'input> = ParseError<'input>;
+
+    const ENUMERATED: bool = true;
+
+    fn from_fix_bytes(bytes: &[u8]) -> Result<Self, Self::Error<'_>>
+    where
+        Self: Sized,
+    {
+        match bytes {
+            b"0" => Ok(EncryptMethod::None),
+            b"1" => Ok(EncryptMethod::Pkcs),
+            b"2" => Ok(EncryptMethod::Des),
+            b"3" => Ok(EncryptMethod::PkcsDes),
+            b"4" => Ok(EncryptMethod::PgpDes),
+            b"5" => Ok(EncryptMethod::PgpDesMd5),
+            b"6" => Ok(EncryptMethod::PemDesMd5),
+            other => Err(ParseError::Unsupported(other)),
+        }
+    }
+}
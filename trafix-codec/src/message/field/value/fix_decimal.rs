@@ -0,0 +1,300 @@
+//! Defines the [`FixDecimal`] type, representing the FIX `float`-based field value formats, such
+//! as `Qty`, `Price` and `Amt`.
+
+use std::{cmp::Ordering, fmt};
+
+use crate::message::field::value::FromFixBytes;
+
+/// Represents a decimal number, e.g. the `AvgPx` (`6`) or the `CumQty` (`14`).
+///
+/// The number is stored exactly, as an integer `mantissa` scaled down by `10^scale`, so no
+/// precision is lost to floating point conversion. The scale, i.e. the number of digits after
+/// the decimal point, is retained, so that encoding a decoded value produces the same digits.
+/// Consequently, `1.5` and `1.50` are not considered equal.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FixDecimal {
+    /// Digits of the number, without the decimal point.
+    mantissa: i64,
+
+    /// Number of digits after the decimal point.
+    scale: u8,
+}
+
+impl FixDecimal {
+    /// Creates a new [`FixDecimal`] equal to `mantissa * 10^-scale`.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::field::value::fix_decimal::FixDecimal;
+    ///
+    /// let price = FixDecimal::new(10_125, 2);
+    /// assert_eq!(price.to_string(), "101.25");
+    /// ```
+    #[must_use]
+    pub const fn new(mantissa: i64, scale: u8) -> Self {
+        Self { mantissa, scale }
+    }
+
+    /// Returns the digits of the number, without the decimal point.
+    #[must_use]
+    pub const fn mantissa(&self) -> i64 {
+        self.mantissa
+    }
+
+    /// Returns the number of digits after the decimal point.
+    #[must_use]
+    pub const fn scale(&self) -> u8 {
+        self.scale
+    }
+
+    /// Compares the numeric values of two decimals, regardless of their scales. Unlike with
+    /// [`PartialEq`], `1.5` and `1.50` compare as equal.
+    ///
+    /// Example usage:
+    /// ```
+    /// use std::cmp::Ordering;
+    ///
+    /// use trafix_codec::message::field::value::fix_decimal::FixDecimal;
+    ///
+    /// let a = FixDecimal::new(15, 1);
+    /// assert_eq!(a.cmp_value(&FixDecimal::new(150, 2)), Ordering::Equal);
+    /// assert_eq!(a.cmp_value(&FixDecimal::new(2, 0)), Ordering::Less);
+    /// ```
+    #[must_use]
+    pub fn cmp_value(&self, other: &Self) -> Ordering {
+        // decimals of different signs, or zero, compare by sign, whatever their scales
+        let signs = self.mantissa.signum().cmp(&other.mantissa.signum());
+        if signs != Ordering::Equal || self.mantissa == 0 {
+            return signs;
+        }
+
+        let scale = self.scale.max(other.scale);
+        let widen = |decimal: &Self| {
+            10_i128
+                .checked_pow(u32::from(scale - decimal.scale))
+                .and_then(|factor| i128::from(decimal.mantissa).checked_mul(factor))
+        };
+
+        // at most one side is scaled up, and it only overflows if its magnitude exceeds that of
+        // the other side, so with both signs equal, the sign of the overflowing side decides
+        match (widen(self), widen(other)) {
+            (Some(this), Some(other)) => this.cmp(&other),
+            (None, _) => self.mantissa.cmp(&0),
+            (_, None) => 0.cmp(&other.mantissa),
+        }
+    }
+}
+
+impl fmt::Display for FixDecimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.scale == 0 {
+            return write!(f, "{}", self.mantissa);
+        }
+
+        let sign = if self.mantissa < 0 { "-" } else { "" };
+        let digits = format!(
+            "{:0width$}",
+            self.mantissa.unsigned_abs(),
+            width = usize::from(self.scale) + 1
+        );
+        let (integer, fraction) = digits.split_at(digits.len() - usize::from(self.scale));
+
+        write!(f, "{sign}{integer}.{fraction}")
+    }
+}
+
+impl From<&FixDecimal> for Vec<u8> {
+    /// Converts a [`FixDecimal`] into its decimal wire representation.
+    fn from(val: &FixDecimal) -> Self {
+        val.to_string().into_bytes()
+    }
+}
+
+/// The error type for failed parsing of [`FixDecimal`]
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ParseError<'input> {
+    /// Provided byte slice is not a decimal number, or does not fit into the representation.
+    #[error("invalid decimal: {}", String::from_utf8_lossy(.0))]
+    Invalid(&'input [u8]),
+}
+
+impl FromFixBytes for FixDecimal {
+    type Error<'input> = ParseError<'input>;
+
+    fn from_fix_bytes(bytes: &[u8]) -> Result<Self, Self::Error<'_>>
+    where
+        Self: Sized,
+    {
+        let invalid = || ParseError::Invalid(bytes);
+
+        let (negative, unsigned) = match bytes {
+            [b'-', rest @ ..] => (true, rest),
+            _ => (false, bytes),
+        };
+
+        let (integer, fraction) = match unsigned.iter().position(|byte| *byte == b'.') {
+            Some(point) => (&unsigned[..point], &unsigned[point + 1..]),
+            None => (unsigned, &[][..]),
+        };
+
+        if integer.is_empty() && fraction.is_empty() {
+            return Err(invalid());
+        }
+
+        let mut mantissa: i64 = 0;
+
+        for byte in integer.iter().chain(fraction) {
+            if !byte.is_ascii_digit() {
+                return Err(invalid());
+            }
+
+            let digit = i64::from(byte - b'0');
+
+            // accumulate negative numbers downwards, so that i64::MIN can be represented
+            mantissa = mantissa
+                .checked_mul(10)
+                .and_then(|mantissa| {
+                    if negative {
+                        mantissa.checked_sub(digit)
+                    } else {
+                        mantissa.checked_add(digit)
+                    }
+                })
+                .ok_or_else(invalid)?;
+        }
+
+        let scale = u8::try_from(fraction.len()).map_err(|_| invalid())?;
+
+        Ok(Self { mantissa, scale })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{FixDecimal, ParseError};
+    use crate::message::field::value::FromFixBytes;
+
+    #[test]
+    fn parse_valid() {
+        for (input, mantissa, scale) in [
+            (&b"0"[..], 0, 0),
+            (b"150", 150, 0),
+            (b"1.50", 150, 2),
+            (b"-0.005", -5, 3),
+            (b"101.25", 10_125, 2),
+            (b".5", 5, 1),
+            (b"-9223372036854775808", i64::MIN, 0),
+        ] {
+            assert_eq!(
+                FixDecimal::from_fix_bytes(input),
+                Ok(FixDecimal::new(mantissa, scale))
+            );
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        for input in [&b"0"[..], b"7000", b"1.50", b"-0.005", b"0.000001"] {
+            let decimal = FixDecimal::from_fix_bytes(input).unwrap();
+            assert_eq!(Vec::from(&decimal), input);
+        }
+    }
+
+    #[test]
+    fn parse_invalid() {
+        for input in [
+            &b""[..],
+            b"-",
+            b".",
+            b"+1",
+            b"1.2.3",
+            b"1,5",
+            b"1e5",
+            b"9223372036854775808",
+        ] {
+            assert_eq!(
+                FixDecimal::from_fix_bytes(input),
+                Err(ParseError::Invalid(input))
+            );
+        }
+    }
+
+    #[test]
+    fn cmp_value() {
+        use std::cmp::Ordering;
+
+        let cases = [
+            (
+                FixDecimal::new(15, 1),
+                FixDecimal::new(150, 2),
+                Ordering::Equal,
+            ),
+            (
+                FixDecimal::new(15, 1),
+                FixDecimal::new(151, 2),
+                Ordering::Less,
+            ),
+            (
+                FixDecimal::new(-1, 0),
+                FixDecimal::new(-15, 1),
+                Ordering::Greater,
+            ),
+            (
+                FixDecimal::new(0, 0),
+                FixDecimal::new(0, 30),
+                Ordering::Equal,
+            ),
+            // zero is compared by sign, even if scaling it up overflows
+            (
+                FixDecimal::new(0, 0),
+                FixDecimal::new(5, 100),
+                Ordering::Less,
+            ),
+            (
+                FixDecimal::new(0, 0),
+                FixDecimal::new(-5, 100),
+                Ordering::Greater,
+            ),
+            (
+                FixDecimal::new(-1, 0),
+                FixDecimal::new(5, 100),
+                Ordering::Less,
+            ),
+            (
+                FixDecimal::new(0, 100),
+                FixDecimal::new(0, 0),
+                Ordering::Equal,
+            ),
+            // scaling up overflows, the sign decides
+            (
+                FixDecimal::new(1, 0),
+                FixDecimal::new(i64::MAX, 100),
+                Ordering::Greater,
+            ),
+            (
+                FixDecimal::new(-1, 0),
+                FixDecimal::new(i64::MAX, 100),
+                Ordering::Less,
+            ),
+            (
+                FixDecimal::new(i64::MIN, 100),
+                FixDecimal::new(1, 0),
+                Ordering::Less,
+            ),
+            (
+                FixDecimal::new(-1, 0),
+                FixDecimal::new(-5, 100),
+                Ordering::Less,
+            ),
+        ];
+
+        for (left, right, ordering) in cases {
+            assert_eq!(left.cmp_value(&right), ordering, "{left} vs {right}");
+            assert_eq!(
+                right.cmp_value(&left),
+                ordering.reverse(),
+                "{right} vs {left}"
+            );
+        }
+    }
+}
@@ -0,0 +1,78 @@
+//! Defines the [`HandlInst`] enumeration representing the FIX
+//! **21 `HandlInst`** field value.
+
+use crate::message::field::value::FromFixBytes;
+
+/// Represents the handling instruction (`21`) of an order, e.g. sent in a `NewOrderSingle`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum HandlInst {
+    /// Automated execution order, private, no broker intervention (`21=1`).
+    AutomatedExecutionNoIntervention,
+
+    /// Automated execution order, public, broker intervention OK (`21=2`).
+    AutomatedExecutionInterventionOk,
+
+    /// Manual order, best execution (`21=3`).
+    ManualOrder,
+}
+
+impl HandlInst {
+    /// Returns the tag used for [`HandlInst`].
+    #[must_use]
+    pub const fn tag() -> u32 {
+        21
+    }
+}
+
+impl From<HandlInst> for &'static [u8] {
+    /// Converts a [`HandlInst`] variant into its **static byte slice**
+    /// representation, corresponding to the FIX wire value of tag **21**.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::field::value::handl_inst::HandlInst;
+    /// let bytes: &'static [u8] = HandlInst::ManualOrder.into();
+    /// assert_eq!(bytes, b"3");
+    /// ```
+    fn from(val: HandlInst) -> Self {
+        match val {
+            HandlInst::AutomatedExecutionNoIntervention => b"1",
+            HandlInst::AutomatedExecutionInterventionOk => b"2",
+            HandlInst::ManualOrder => b"3",
+        }
+    }
+}
+
+impl From<HandlInst> for Vec<u8> {
+    /// Converts a [`HandlInst`] variant into an **owned `Vec<u8>`**
+    /// containing its FIX wire representation (tag **21** value).
+    fn from(val: HandlInst) -> Self {
+        <&[u8]>::from(val).to_vec()
+    }
+}
+
+/// The error type for failed parsing of [`HandlInst`]
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ParseError<'input> {
+    /// Provided byte slice contains data that is not a valid or supported handling instruction.
+    #[error("unsupported handling instruction: {}", String::from_utf8_lossy(.0))]
+    Unsupported(&'input [u8]),
+}
+
+impl FromFixBytes for HandlInst {
+    type Error<'input> = ParseError<'input>;
+
+    const ENUMERATED: bool = true;
+
+    fn from_fix_bytes(bytes: &[u8]) -> Result<Self, Self::Error<'_>>
+    where
+        Self: Sized,
+    {
+        match bytes {
+            b"1" => Ok(HandlInst::AutomatedExecutionNoIntervention),
+            b"2" => Ok(HandlInst::AutomatedExecutionInterventionOk),
+            b"3" => Ok(HandlInst::ManualOrder),
+            other => Err(ParseError::Unsupported(other)),
+        }
+    }
+}
@@ -0,0 +1,193 @@
+//! Defines the [`LocalMktDate`] type, representing the FIX `LocalMktDate` field value format
+//! (`YYYYMMDD`).
+
+use std::fmt;
+
+use crate::decoder::num::ParseFixInt;
+use crate::message::field::value::FromFixBytes;
+
+/// Represents a date local to the exchange or market (`YYYYMMDD`), e.g. the `TradeDate` (`75`).
+///
+/// The date components are validated on construction, so a [`LocalMktDate`] always represents
+/// an existing calendar date.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LocalMktDate {
+    /// Year component, in range `0..=9999`.
+    year: u16,
+
+    /// Month component, in range `1..=12`.
+    month: u8,
+
+    /// Day component, in range `1..=31` depending on the month.
+    day: u8,
+}
+
+impl LocalMktDate {
+    /// Creates a new [`LocalMktDate`] from its components.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::field::value::local_mkt_date::LocalMktDate;
+    ///
+    /// assert!(LocalMktDate::new(2024, 2, 29).is_ok());
+    /// assert!(LocalMktDate::new(2023, 2, 29).is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseError::OutOfRange`] if any of the components is out of its valid range.
+    pub fn new(year: u16, month: u8, day: u8) -> Result<Self, ParseError> {
+        if year > 9999 {
+            return Err(ParseError::OutOfRange("year"));
+        }
+
+        if !(1..=12).contains(&month) {
+            return Err(ParseError::OutOfRange("month"));
+        }
+
+        if day == 0 || day > days_in_month(year, month) {
+            return Err(ParseError::OutOfRange("day"));
+        }
+
+        Ok(Self { year, month, day })
+    }
+
+    /// Returns the year component.
+    #[must_use]
+    pub const fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// Returns the month component (`1..=12`).
+    #[must_use]
+    pub const fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// Returns the day of month component (`1..=31`).
+    #[must_use]
+    pub const fn day(&self) -> u8 {
+        self.day
+    }
+}
+
+/// Returns the number of days in the given month of the given year, taking leap years into
+/// account.
+pub(crate) const fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400)) => {
+            29
+        }
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Parses a fixed-width, unsigned decimal component out of the given bytes. Components are
+/// parsed into unsigned integer types, which reject empty input and any byte that is not a
+/// digit, including signs.
+pub(crate) fn parse_component<T: ParseFixInt>(bytes: &[u8]) -> Option<T> {
+    T::parse_fix_int(bytes).ok()
+}
+
+impl fmt::Display for LocalMktDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}{:02}{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl From<&LocalMktDate> for Vec<u8> {
+    /// Converts a [`LocalMktDate`] into its `YYYYMMDD` wire representation.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::field::value::local_mkt_date::LocalMktDate;
+    /// let date = LocalMktDate::new(2018, 9, 20).unwrap();
+    /// assert_eq!(Vec::from(&date), b"20180920");
+    /// ```
+    fn from(val: &LocalMktDate) -> Self {
+        val.to_string().into_bytes()
+    }
+}
+
+/// The error type for failed parsing of dates and timestamps.
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ParseError {
+    /// Provided byte slice does not follow the expected layout.
+    #[error("malformed date or time: {}", String::from_utf8_lossy(.0))]
+    Malformed(Vec<u8>),
+
+    /// One of the components is out of its valid range.
+    #[error("date or time component '{}' out of range", .0)]
+    OutOfRange(&'static str),
+}
+
+impl FromFixBytes for LocalMktDate {
+    type Error<'unused> = ParseError;
+
+    fn from_fix_bytes(bytes: &[u8]) -> Result<Self, Self::Error<'_>>
+    where
+        Self: Sized,
+    {
+        let (Some(year), Some(month), Some(day), 8) = (
+            bytes.get(0..4).and_then(parse_component),
+            bytes.get(4..6).and_then(parse_component),
+            bytes.get(6..8).and_then(parse_component),
+            bytes.len(),
+        ) else {
+            return Err(ParseError::Malformed(bytes.to_vec()));
+        };
+
+        LocalMktDate::new(year, month, day)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{LocalMktDate, ParseError};
+    use crate::message::field::value::FromFixBytes;
+
+    #[test]
+    fn parse_valid() {
+        let date = LocalMktDate::from_fix_bytes(b"20180920").unwrap();
+
+        assert_eq!(date, LocalMktDate::new(2018, 9, 20).unwrap());
+        assert_eq!(Vec::from(&date), b"20180920");
+
+        // leap years
+        assert!(LocalMktDate::from_fix_bytes(b"20240229").is_ok());
+        assert!(LocalMktDate::from_fix_bytes(b"20000229").is_ok());
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert_eq!(
+            LocalMktDate::from_fix_bytes(b"2018092"),
+            Err(ParseError::Malformed(b"2018092".to_vec()))
+        );
+        assert_eq!(
+            LocalMktDate::from_fix_bytes(b"2018-9-2"),
+            Err(ParseError::Malformed(b"2018-9-2".to_vec()))
+        );
+        // signs are not digits of a component
+        for input in [&b"+0180920"[..], b"2018+920", b"201809-2"] {
+            assert_eq!(
+                LocalMktDate::from_fix_bytes(input),
+                Err(ParseError::Malformed(input.to_vec()))
+            );
+        }
+        assert_eq!(
+            LocalMktDate::from_fix_bytes(b"20181320"),
+            Err(ParseError::OutOfRange("month"))
+        );
+        assert_eq!(
+            LocalMktDate::from_fix_bytes(b"20180931"),
+            Err(ParseError::OutOfRange("day"))
+        );
+        assert_eq!(
+            LocalMktDate::from_fix_bytes(b"19000229"),
+            Err(ParseError::OutOfRange("day"))
+        );
+    }
+}
@@ -0,0 +1,129 @@
+//! Defines the [`MDReqRejReason`] enumeration representing the FIX
+//! **281 `MDReqRejReason`** field value.
+
+use crate::message::field::value::FromFixBytes;
+
+/// Represents the reason (`281`) a market data request was rejected.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MDReqRejReason {
+    /// Unknown symbol (`281=0`).
+    UnknownSymbol,
+
+    /// Duplicate `MDReqID` (`281=1`).
+    DuplicateMDReqID,
+
+    /// Insufficient bandwidth (`281=2`).
+    InsufficientBandwidth,
+
+    /// Insufficient permissions (`281=3`).
+    InsufficientPermissions,
+
+    /// Unsupported `SubscriptionRequestType` (`281=4`).
+    UnsupportedSubscriptionRequestType,
+
+    /// Unsupported `MarketDepth` (`281=5`).
+    UnsupportedMarketDepth,
+
+    /// Unsupported `MDUpdateType` (`281=6`).
+    UnsupportedMDUpdateType,
+
+    /// Unsupported `AggregatedBook` (`281=7`).
+    UnsupportedAggregatedBook,
+
+    /// Unsupported `MDEntryType` (`281=8`).
+    UnsupportedMDEntryType,
+
+    /// Unsupported `TradingSessionID` (`281=9`).
+    UnsupportedTradingSessionID,
+
+    /// Unsupported `Scope` (`281=A`).
+    UnsupportedScope,
+
+    /// Unsupported `OpenCloseSettlFlag` (`281=B`).
+    UnsupportedOpenCloseSettlFlag,
+
+    /// Unsupported `MDImplicitDelete` (`281=C`).
+    UnsupportedMDImplicitDelete,
+}
+
+impl MDReqRejReason {
+    /// Returns the tag used for [`MDReqRejReason`].
+    #[must_use]
+    pub const fn tag() -> u32 {
+        281
+    }
+}
+
+impl From<MDReqRejReason> for &'static [u8] {
+    /// Converts a [`MDReqRejReason`] variant into its **static byte slice**
+    /// representation, corresponding to the FIX wire value of tag **281**.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::field::value::md_req_rej_reason::MDReqRejReason;
+    /// let bytes: &'static [u8] = MDReqRejReason::InsufficientPermissions.into();
+    /// assert_eq!(bytes, b"3");
+    /// ```
+    fn from(val: MDReqRejReason) -> Self {
+        match val {
+            MDReqRejReason::UnknownSymbol => b"0",
+            MDReqRejReason::DuplicateMDReqID => b"1",
+            MDReqRejReason::InsufficientBandwidth => b"2",
+            MDReqRejReason::InsufficientPermissions => b"3",
+            MDReqRejReason::UnsupportedSubscriptionRequestType => b"4",
+            MDReqRejReason::UnsupportedMarketDepth => b"5",
+            MDReqRejReason::UnsupportedMDUpdateType => b"6",
+            MDReqRejReason::UnsupportedAggregatedBook => b"7",
+            MDReqRejReason::UnsupportedMDEntryType => b"8",
+            MDReqRejReason::UnsupportedTradingSessionID => b"9",
+            MDReqRejReason::UnsupportedScope => b"A",
+            MDReqRejReason::UnsupportedOpenCloseSettlFlag => b"B",
+            MDReqRejReason::UnsupportedMDImplicitDelete => b"C",
+        }
+    }
+}
+
+impl From<MDReqRejReason> for Vec<u8> {
+    /// Converts a [`MDReqRejReason`] variant into an **owned `Vec<u8>`**
+    /// containing its FIX wire representation (tag **281** value).
+    fn from(val: MDReqRejReason) -> Self {
+        <&[u8]>::from(val).to_vec()
+    }
+}
+
+/// The error type for failed parsing of [`MDReqRejReason`]
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ParseError<'input> {
+    /// Provided byte slice contains data that is not a valid or supported market data request
+    /// reject reason.
+    #[error("unsupported market data request reject reason: {}", String::from_utf8_lossy(.0))]
+    Unsupported(&'input [u8]),
+}
+
+impl FromFixBytes for MDReqRejReason {
+    type Error<'input> = ParseError<'input>;
+
+    const ENUMERATED: bool = true;
+
+    fn from_fix_bytes(bytes: &[u8]) -> Result<Self, Self::Error<'_>>
+    where
+        Self: Sized,
+    {
+        match bytes {
+            b"0" => Ok(MDReqRejReason::UnknownSymbol),
+            b"1" => Ok(MDReqRejReason::DuplicateMDReqID),
+            b"2" => Ok(MDReqRejReason::InsufficientBandwidth),
+            b"3" => Ok(MDReqRejReason::InsufficientPermissions),
+            b"4" => Ok(MDReqRejReason::UnsupportedSubscriptionRequestType),
+            b"5" => Ok(MDReqRejReason::UnsupportedMarketDepth),
+            b"6" => Ok(MDReqRejReason::UnsupportedMDUpdateType),
+            b"7" => Ok(MDReqRejReason::UnsupportedAggregatedBook),
+            b"8" => Ok(MDReqRejReason::UnsupportedMDEntryType),
+            b"9" => Ok(MDReqRejReason::UnsupportedTradingSessionID),
+            b"A" => Ok(MDReqRejReason::UnsupportedScope),
+            b"B" => Ok(MDReqRejReason::UnsupportedOpenCloseSettlFlag),
+            b"C" => Ok(MDReqRejReason::UnsupportedMDImplicitDelete),
+            other => Err(ParseError::Unsupported(other)),
+        }
+    }
+}
@@ -0,0 +1,90 @@
+//! Defines the [`MDUpdateAction`] enumeration representing the FIX
+//! **279 `MDUpdateAction`** field value.
+
+use crate::message::field::value::FromFixBytes;
+
+/// Represents the action (`279`) an entry of an incremental market data refresh applies to the
+/// book.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MDUpdateAction {
+    /// New entry (`279=0`), added to the book.
+    New,
+
+    /// Changed entry (`279=1`), replacing an entry of the book.
+    Change,
+
+    /// Deleted entry (`279=2`), removed from the book.
+    Delete,
+
+    /// Delete thru (`279=3`), removing all entries from the top of the book up to the entry.
+    DeleteThru,
+
+    /// Delete from (`279=4`), removing all entries from the entry to the bottom of the book.
+    DeleteFrom,
+}
+
+impl MDUpdateAction {
+    /// Returns the tag used for [`MDUpdateAction`].
+    #[must_use]
+    pub const fn tag() -> u32 {
+        279
+    }
+}
+
+impl From<MDUpdateAction> for &'static [u8] {
+    /// Converts a [`MDUpdateAction`] variant into its **static byte slice**
+    /// representation, corresponding to the FIX wire value of tag **279**.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::field::value::md_update_action::MDUpdateAction;
+    /// let bytes: &'static [u8] = MDUpdateAction::Delete.into();
+    /// assert_eq!(bytes, b"2");
+    /// ```
+    fn from(val: MDUpdateAction) -> Self {
+        match val {
+            MDUpdateAction::New => b"0",
+            MDUpdateAction::Change => b"1",
+            MDUpdateAction::Delete => b"2",
+            MDUpdateAction::DeleteThru => b"3",
+            MDUpdateAction::DeleteFrom => b"4",
+        }
+    }
+}
+
+impl From<MDUpdateAction> for Vec<u8> {
+    /// Converts a [`MDUpdateAction`] variant into an **owned `Vec<u8>`**
+    /// containing its FIX wire representation (tag **279** value).
+    fn from(val: MDUpdateAction) -> Self {
+        <&[u8]>::from(val).to_vec()
+    }
+}
+
+/// The error type for failed parsing of [`MDUpdateAction`]
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ParseError<'input> {
+    /// Provided byte slice contains data that is not a valid or supported market data update
+    /// action.
+    #[error("unsupported market data update action: {}", String::from_utf8_lossy(.0))]
+    Unsupported(&'input [u8]),
+}
+
+impl FromFixBytes for MDUpdateAction {
+    type Error<'input> = ParseError<'input>;
+
+    const ENUMERATED: bool = true;
+
+    fn from_fix_bytes(bytes: &[u8]) -> Result<Self, Self::Error<'_>>
+    where
+        Self: Sized,
+    {
+        match bytes {
+            b"0" => Ok(MDUpdateAction::New),
+            b"1" => Ok(MDUpdateAction::Change),
+            b"2" => Ok(MDUpdateAction::Delete),
+            b"3" => Ok(MDUpdateAction::DeleteThru),
+            b"4" => Ok(MDUpdateAction::DeleteFrom),
+            other => Err(ParseError::Unsupported(other)),
+        }
+    }
+}
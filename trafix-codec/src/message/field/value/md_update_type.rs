@@ -0,0 +1,75 @@
+//! Defines the [`MDUpdateType`] enumeration representing the FIX
+//! **265 `MDUpdateType`** field value.
+
+use crate::message::field::value::FromFixBytes;
+
+/// Represents the type of market data updates (`265`) requested by a `MarketDataRequest`
+/// subscription.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MDUpdateType {
+    /// Full refresh (`265=0`), every update carries the full state of the book.
+    FullRefresh,
+
+    /// Incremental refresh (`265=1`), updates only carry the changes to the book.
+    IncrementalRefresh,
+}
+
+impl MDUpdateType {
+    /// Returns the tag used for [`MDUpdateType`].
+    #[must_use]
+    pub const fn tag() -> u32 {
+        265
+    }
+}
+
+impl From<MDUpdateType> for &'static [u8] {
+    /// Converts a [`MDUpdateType`] variant into its **static byte slice**
+    /// representation, corresponding to the FIX wire value of tag **265**.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::field::value::md_update_type::MDUpdateType;
+    /// let bytes: &'static [u8] = MDUpdateType::IncrementalRefresh.into();
+    /// assert_eq!(bytes, b"1");
+    /// ```
+    fn from(val: MDUpdateType) -> Self {
+        match val {
+            MDUpdateType::FullRefresh => b"0",
+            MDUpdateType::IncrementalRefresh => b"1",
+        }
+    }
+}
+
+impl From<MDUpdateType> for Vec<u8> {
+    /// Converts a [`MDUpdateType`] variant into an **owned `Vec<u8>`**
+    /// containing its FIX wire representation (tag **265** value).
+    fn from(val: MDUpdateType) -> Self {
+        <&[u8]>::from(val).to_vec()
+    }
+}
+
+/// The error type for failed parsing of [`MDUpdateType`]
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ParseError<'input> {
+    /// Provided byte slice contains data that is not a valid or supported market data update
+    /// type.
+    #[error("unsupported market data update type: {}", String::from_utf8_lossy(.0))]
+    Unsupported(&'input [u8]),
+}
+
+impl FromFixBytes for MDUpdateType {
+    type Error<'input> = ParseError<'input>;
+
+    const ENUMERATED: bool = true;
+
+    fn from_fix_bytes(bytes: &[u8]) -> Result<Self, Self::Error<'_>>
+    where
+        Self: Sized,
+    {
+        match bytes {
+            b"0" => Ok(MDUpdateType::FullRefresh),
+            b"1" => Ok(MDUpdateType::IncrementalRefresh),
+            other => Err(ParseError::Unsupported(other)),
+        }
+    }
+}
@@ -1,10 +1,70 @@
 //! Implementation of the value module.
 
 use crate::decoder::num::ParseFixInt;
+use crate::message::field::value::{
+    business_reject_reason::BusinessRejectReason,
+    cfi_code::CFICode,
+    comp_id::CompID,
+    currency::Currency,
+    cxl_rej_reason::CxlRejReason,
+    cxl_rej_response_to::CxlRejResponseTo,
+    encrypt_method::EncryptMethod,
+    fix_decimal::FixDecimal,
+    handl_inst::HandlInst,
+    local_mkt_date::LocalMktDate,
+    md_req_rej_reason::MDReqRejReason,
+    md_update_action::MDUpdateAction,
+    md_update_type::MDUpdateType,
+    msg_type::MsgType,
+    multiple_value::{MultipleCharValue, MultipleStringValue},
+    ord_rej_reason::OrdRejReason,
+    ord_type::OrdType,
+    order_capacity::OrderCapacity,
+    order_restrictions::OrderRestrictions,
+    party_id_source::PartyIDSource,
+    party_role::PartyRole,
+    password::Password,
+    security_id_source::SecurityIDSource,
+    security_type::SecurityType,
+    session_reject_reason::SessionRejectReason,
+    side::Side,
+    subscription_request_type::SubscriptionRequestType,
+    time_in_force::TimeInForce,
+    utc_timestamp::UtcTimestamp,
+};
 
 pub mod aliases;
 pub mod begin_string;
+pub mod business_reject_reason;
+pub mod cfi_code;
+pub mod comp_id;
+pub mod currency;
+pub mod cxl_rej_reason;
+pub mod cxl_rej_response_to;
+pub mod encrypt_method;
+pub mod fix_decimal;
+pub mod handl_inst;
+pub mod local_mkt_date;
+pub mod md_req_rej_reason;
+pub mod md_update_action;
+pub mod md_update_type;
 pub mod msg_type;
+pub mod multiple_value;
+pub mod ord_rej_reason;
+pub mod ord_type;
+pub mod order_capacity;
+pub mod order_restrictions;
+pub mod party_id_source;
+pub mod party_role;
+pub mod password;
+pub mod security_id_source;
+pub mod security_type;
+pub mod session_reject_reason;
+pub mod side;
+pub mod subscription_request_type;
+pub mod text;
+pub mod time_in_force;
+pub mod utc_timestamp;
 
 /// Trait that abstracts conversion from bytes to values of FIX message fields.
 // TODO(nfejzic): this trait might be obsolete if we decide to wrap used types (i.e. newtype
@@ -13,6 +73,14 @@ pub(crate) trait FromFixBytes {
     /// Error returned on failed conversion.
     type Error<'lifetime>;
 
+    /// Whether the type only accepts a fixed set of known values, so that parsing fails on
+    /// values that are well-formed, but not known (yet).
+    const ENUMERATED: bool = false;
+
+    /// Whether the type is an integer, whose values must consist of ASCII digits only, with an
+    /// optional leading `-` for signed types.
+    const NUMERIC: bool = false;
+
     /// Parses the input and returns an instance of self.
     fn from_fix_bytes(bytes: &[u8]) -> Result<Self, Self::Error<'_>>
     where
@@ -25,6 +93,8 @@ where
 {
     type Error<'unused> = crate::decoder::num::ParseIntError;
 
+    const NUMERIC: bool = true;
+
     fn from_fix_bytes(bytes: &[u8]) -> Result<Self, Self::Error<'_>>
     where
         Self: Sized,
@@ -32,3 +102,122 @@ where
         Self::parse_fix_int(bytes)
     }
 }
+
+/// Trait that gives access to the wire representation of values of FIX message fields without
+/// allocating, for values stored in that representation, see [`Field::value_ref`].
+///
+/// [`Field::value_ref`]: crate::message::field::Field::value_ref
+pub(crate) trait AsFixBytes {
+    /// Returns the wire representation of the value, or `None` if it has to be formatted.
+    fn as_fix_bytes(&self) -> Option<&[u8]> {
+        None
+    }
+}
+
+// numeric values are formatted on demand
+impl AsFixBytes for bool {}
+impl AsFixBytes for i64 {}
+impl AsFixBytes for u32 {}
+impl AsFixBytes for u64 {}
+impl AsFixBytes for FixDecimal {}
+impl AsFixBytes for LocalMktDate {}
+impl AsFixBytes for UtcTimestamp {}
+
+impl AsFixBytes for Vec<u8> {
+    fn as_fix_bytes(&self) -> Option<&[u8]> {
+        Some(self)
+    }
+}
+
+impl AsFixBytes for MultipleCharValue {
+    fn as_fix_bytes(&self) -> Option<&[u8]> {
+        Some(self.as_bytes())
+    }
+}
+
+impl AsFixBytes for OrderRestrictions {
+    fn as_fix_bytes(&self) -> Option<&[u8]> {
+        Some(self.as_bytes())
+    }
+}
+
+impl AsFixBytes for MultipleStringValue {
+    fn as_fix_bytes(&self) -> Option<&[u8]> {
+        Some(self.as_bytes())
+    }
+}
+
+impl AsFixBytes for CompID {
+    fn as_fix_bytes(&self) -> Option<&[u8]> {
+        Some(self.as_ref())
+    }
+}
+
+impl AsFixBytes for Password {
+    fn as_fix_bytes(&self) -> Option<&[u8]> {
+        Some(self.expose())
+    }
+}
+
+impl AsFixBytes for CFICode {
+    fn as_fix_bytes(&self) -> Option<&[u8]> {
+        Some(self.as_str().as_bytes())
+    }
+}
+
+impl AsFixBytes for Currency {
+    fn as_fix_bytes(&self) -> Option<&[u8]> {
+        Some(self.as_str().as_bytes())
+    }
+}
+
+impl AsFixBytes for PartyRole {
+    fn as_fix_bytes(&self) -> Option<&[u8]> {
+        self.as_static_bytes()
+    }
+}
+
+impl AsFixBytes for SecurityIDSource {
+    fn as_fix_bytes(&self) -> Option<&[u8]> {
+        self.as_static_bytes()
+    }
+}
+
+impl AsFixBytes for MsgType {
+    fn as_fix_bytes(&self) -> Option<&[u8]> {
+        Some(self.as_bytes())
+    }
+}
+
+/// Implements [`AsFixBytes`] for enumerations convertible into static byte slices.
+macro_rules! static_fix_bytes {
+    ($($type:ty),+ $(,)?) => {
+        $(
+        impl AsFixBytes for $type {
+            fn as_fix_bytes(&self) -> Option<&[u8]> {
+                Some(<&'static [u8]>::from(*self))
+            }
+        }
+        )+
+    };
+}
+
+static_fix_bytes!(
+    BusinessRejectReason,
+    CxlRejReason,
+    CxlRejResponseTo,
+    EncryptMethod,
+    HandlInst,
+    MDReqRejReason,
+    MDUpdateAction,
+    MDUpdateType,
+    OrdRejReason,
+    OrdType,
+    OrderCapacity,
+    PartyIDSource,
+    SecurityType,
+    SessionRejectReason,
+    Side,
+    SubscriptionRequestType,
+    TimeInForce,
+);
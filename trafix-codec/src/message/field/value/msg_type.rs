@@ -1,12 +1,18 @@
 //! Defines the [`MsgType`] enumeration representing the FIX **35 `MsgType`** field value.
 
+use std::hash::{Hash, Hasher};
+
 use crate::message::field::value::FromFixBytes;
 
 /// Represents the FIX message type (`35`) field value.
 ///
 /// Each variant corresponds to a well-known administrative message
-/// used in FIX session-level communication.
-#[derive(Clone, Copy, Debug, PartialEq)]
+/// used in FIX session-level communication. Message types without a
+/// dedicated variant are carried verbatim by [`MsgType::Custom`].
+///
+/// Message types are compared and hashed by their wire value, see [`MsgType::as_bytes`], so a
+/// [`MsgType::Custom`] holding the wire value of a dedicated variant equals that variant.
+#[derive(Clone, Debug)]
 pub enum MsgType {
     /// `Logon` message (`35=A`), representing a session initiation request.
     Logon,
@@ -28,31 +34,93 @@ pub enum MsgType {
 
     /// `Logout` message (`35=5`), representing a session termination (grafecul) request.
     Logout,
+
+    /// `ExecutionReport` message (`35=8`), representing a change of an order's state, e.g. a
+    /// fill.
+    ExecutionReport,
+
+    /// `OrderCancelReject` message (`35=9`), representing a rejection of an order cancel or
+    /// cancel/replace request.
+    OrderCancelReject,
+
+    /// `NewOrderSingle` message (`35=D`), representing a new order for a single instrument.
+    NewOrderSingle,
+
+    /// `OrderCancelRequest` message (`35=F`), representing a request to cancel the remaining
+    /// quantity of an order.
+    OrderCancelRequest,
+
+    /// `OrderCancelReplaceRequest` message (`35=G`), representing a request to modify an
+    /// existing order.
+    OrderCancelReplaceRequest,
+
+    /// `DontKnowTrade` message (`35=Q`), representing a rejection of an execution report the
+    /// receiver does not recognize.
+    DontKnowTrade,
+
+    /// `QuoteRequest` message (`35=R`), representing a request for quotes on an instrument.
+    QuoteRequest,
+
+    /// `Quote` message (`35=S`), representing bid and offer prices of an instrument.
+    Quote,
+
+    /// `MarketDataRequest` message (`35=V`), representing a subscription request for market
+    /// data.
+    MarketDataRequest,
+
+    /// `MarketDataRequestReject` message (`35=Y`), representing the rejection of a market data
+    /// request.
+    MarketDataRequestReject,
+
+    /// `TradeCaptureReport` message (`35=AE`), representing a trade reported to or by a trade
+    /// capture system.
+    TradeCaptureReport,
+
+    /// `BusinessMessageReject` message (`35=j`), representing an application-level rejection of
+    /// a message that passed session-level validation.
+    BusinessMessageReject,
+
+    /// Any other message type (e.g. `35=BO`), holding its raw wire value. Equals the dedicated
+    /// variant with the same wire value, e.g. `Custom(b"AE".to_vec())` equals
+    /// [`MsgType::TradeCaptureReport`], which is what decoding it yields.
+    Custom(Vec<u8>),
+}
+
+impl PartialEq for MsgType {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_bytes() == other.as_bytes()
+    }
+}
+
+impl Eq for MsgType {}
+
+impl Hash for MsgType {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_bytes().hash(state);
+    }
 }
 
 impl MsgType {
     /// Returns the tag used for [`MsgType`].
     #[must_use]
-    pub const fn tag() -> u16 {
+    pub const fn tag() -> u32 {
         35
     }
 }
 
-impl From<MsgType> for &'static [u8] {
-    /// Converts a [`MsgType`] variant into its **static byte slice**
-    /// representation, corresponding to the FIX wire value of tag **35**.
-    ///
-    /// This conversion is zero-allocation and suitable for direct use when
-    /// encoding FIX messages.
+impl MsgType {
+    /// Returns the FIX wire representation of this message type (tag **35** value).
     ///
     /// Example usage:
     /// ```
     /// use trafix_codec::message::field::value::msg_type::MsgType;
-    /// let bytes: &'static [u8] = MsgType::Heartbeat.into();
-    /// assert_eq!(bytes, b"0");
+    /// assert_eq!(MsgType::Heartbeat.as_bytes(), b"0");
+    /// assert_eq!(MsgType::TradeCaptureReport.as_bytes(), b"AE");
+    /// assert_eq!(MsgType::Custom(b"BO".to_vec()).as_bytes(), b"BO");
     /// ```
-    fn from(val: MsgType) -> Self {
-        match val {
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        match self {
             MsgType::Logon => b"A",
             MsgType::Heartbeat => b"0",
             MsgType::TestRequest => b"1",
@@ -60,8 +128,98 @@ impl From<MsgType> for &'static [u8] {
             MsgType::Reject => b"3",
             MsgType::SequenceReset => b"4",
             MsgType::Logout => b"5",
+            MsgType::ExecutionReport => b"8",
+            MsgType::OrderCancelReject => b"9",
+            MsgType::NewOrderSingle => b"D",
+            MsgType::OrderCancelRequest => b"F",
+            MsgType::OrderCancelReplaceRequest => b"G",
+            MsgType::DontKnowTrade => b"Q",
+            MsgType::QuoteRequest => b"R",
+            MsgType::Quote => b"S",
+            MsgType::MarketDataRequest => b"V",
+            MsgType::MarketDataRequestReject => b"Y",
+            MsgType::TradeCaptureReport => b"AE",
+            MsgType::BusinessMessageReject => b"j",
+            MsgType::Custom(bytes) => bytes,
+        }
+    }
+
+    /// Returns the human-readable name of this message type, e.g. `"NewOrderSingle"`, as used
+    /// in logs, metrics labels and configuration. [`MsgType::Custom`] is named `"Custom"`.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::field::value::msg_type::MsgType;
+    /// assert_eq!(MsgType::Logon.name(), "Logon");
+    /// assert_eq!(MsgType::Custom(b"BO".to_vec()).name(), "Custom");
+    /// ```
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        match self {
+            MsgType::Logon => "Logon",
+            MsgType::Heartbeat => "Heartbeat",
+            MsgType::TestRequest => "TestRequest",
+            MsgType::ResendRequest => "ResendRequest",
+            MsgType::Reject => "Reject",
+            MsgType::SequenceReset => "SequenceReset",
+            MsgType::Logout => "Logout",
+            MsgType::ExecutionReport => "ExecutionReport",
+            MsgType::OrderCancelReject => "OrderCancelReject",
+            MsgType::NewOrderSingle => "NewOrderSingle",
+            MsgType::OrderCancelRequest => "OrderCancelRequest",
+            MsgType::OrderCancelReplaceRequest => "OrderCancelReplaceRequest",
+            MsgType::DontKnowTrade => "DontKnowTrade",
+            MsgType::QuoteRequest => "QuoteRequest",
+            MsgType::Quote => "Quote",
+            MsgType::MarketDataRequest => "MarketDataRequest",
+            MsgType::MarketDataRequestReject => "MarketDataRequestReject",
+            MsgType::TradeCaptureReport => "TradeCaptureReport",
+            MsgType::BusinessMessageReject => "BusinessMessageReject",
+            MsgType::Custom(_) => "Custom",
         }
     }
+
+    /// Returns the message type with the given human-readable name, the inverse of
+    /// [`MsgType::name`]. Returns `None` for unknown names, including `"Custom"`, which does
+    /// not identify a single message type.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::field::value::msg_type::MsgType;
+    /// assert_eq!(MsgType::from_name("NewOrderSingle"), Some(MsgType::NewOrderSingle));
+    /// assert_eq!(MsgType::from_name("Custom"), None);
+    /// ```
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<MsgType> {
+        match name {
+            "Logon" => Some(MsgType::Logon),
+            "Heartbeat" => Some(MsgType::Heartbeat),
+            "TestRequest" => Some(MsgType::TestRequest),
+            "ResendRequest" => Some(MsgType::ResendRequest),
+            "Reject" => Some(MsgType::Reject),
+            "SequenceReset" => Some(MsgType::SequenceReset),
+            "Logout" => Some(MsgType::Logout),
+            "ExecutionReport" => Some(MsgType::ExecutionReport),
+            "OrderCancelReject" => Some(MsgType::OrderCancelReject),
+            "NewOrderSingle" => Some(MsgType::NewOrderSingle),
+            "OrderCancelRequest" => Some(MsgType::OrderCancelRequest),
+            "OrderCancelReplaceRequest" => Some(MsgType::OrderCancelReplaceRequest),
+            "DontKnowTrade" => Some(MsgType::DontKnowTrade),
+            "QuoteRequest" => Some(MsgType::QuoteRequest),
+            "Quote" => Some(MsgType::Quote),
+            "MarketDataRequest" => Some(MsgType::MarketDataRequest),
+            "MarketDataRequestReject" => Some(MsgType::MarketDataRequestReject),
+            "TradeCaptureReport" => Some(MsgType::TradeCaptureReport),
+            "BusinessMessageReject" => Some(MsgType::BusinessMessageReject),
+            _ => None,
+        }
+    }
+}
+
+impl AsRef<[u8]> for MsgType {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
 }
 
 impl From<MsgType> for Vec<u8> {
@@ -75,14 +233,25 @@ impl From<MsgType> for Vec<u8> {
     /// assert_eq!(bytes, b"5");
     /// ```
     fn from(val: MsgType) -> Self {
-        <&[u8]>::from(val).to_vec()
+        match val {
+            MsgType::Custom(bytes) => bytes,
+            other => other.as_bytes().to_vec(),
+        }
+    }
+}
+
+impl From<&MsgType> for Vec<u8> {
+    /// Converts a [`MsgType`] reference into an **owned `Vec<u8>`** containing its FIX wire
+    /// representation (tag **35** value).
+    fn from(val: &MsgType) -> Self {
+        val.as_bytes().to_vec()
     }
 }
 
 /// The error type for failed parsing of [`MsgType`]
 #[derive(Debug, Clone, PartialEq, thiserror::Error)]
 pub enum ParseError<'input> {
-    /// Provided byte slice contains data that is not a valid or supported message type.
+    /// Provided byte slice is empty or contains bytes that cannot form a message type.
     #[error("unsupported message type: {}", String::from_utf8_lossy(.0))]
     Unsupported(&'input [u8]),
 }
@@ -102,7 +271,88 @@ impl FromFixBytes for MsgType {
             b"3" => Ok(MsgType::Reject),
             b"4" => Ok(MsgType::SequenceReset),
             b"5" => Ok(MsgType::Logout),
+            b"8" => Ok(MsgType::ExecutionReport),
+            b"9" => Ok(MsgType::OrderCancelReject),
+            b"D" => Ok(MsgType::NewOrderSingle),
+            b"F" => Ok(MsgType::OrderCancelRequest),
+            b"G" => Ok(MsgType::OrderCancelReplaceRequest),
+            b"Q" => Ok(MsgType::DontKnowTrade),
+            b"R" => Ok(MsgType::QuoteRequest),
+            b"S" => Ok(MsgType::Quote),
+            b"V" => Ok(MsgType::MarketDataRequest),
+            b"Y" => Ok(MsgType::MarketDataRequestReject),
+            b"AE" => Ok(MsgType::TradeCaptureReport),
+            b"j" => Ok(MsgType::BusinessMessageReject),
+            other if !other.is_empty() && other.iter().all(u8::is_ascii_alphanumeric) => {
+                Ok(MsgType::Custom(other.to_vec()))
+            }
             other => Err(ParseError::Unsupported(other)),
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use super::MsgType;
+    use crate::message::field::value::FromFixBytes;
+
+    #[test]
+    fn multi_byte() {
+        assert_eq!(
+            MsgType::from_fix_bytes(b"AE"),
+            Ok(MsgType::TradeCaptureReport)
+        );
+        assert_eq!(MsgType::TradeCaptureReport.as_bytes(), b"AE");
+
+        // prefixes of multi-byte types are distinct types
+        assert_eq!(MsgType::from_fix_bytes(b"A"), Ok(MsgType::Logon));
+        assert_eq!(
+            MsgType::from_fix_bytes(b"AEX"),
+            Ok(MsgType::Custom(b"AEX".to_vec()))
+        );
+    }
+
+    #[test]
+    fn custom_equals_dedicated_variant() {
+        let custom = MsgType::Custom(b"AE".to_vec());
+        assert_eq!(custom, MsgType::TradeCaptureReport);
+        assert_ne!(custom, MsgType::Custom(b"AEX".to_vec()));
+        assert_eq!(MsgType::Custom(b"0".to_vec()), MsgType::Heartbeat);
+
+        // map lookups find either representation
+        let types = HashSet::from([MsgType::TradeCaptureReport]);
+        assert!(types.contains(&custom));
+    }
+
+    #[test]
+    fn name_round_trip() {
+        for wire in [
+            &b"A"[..],
+            b"0",
+            b"1",
+            b"2",
+            b"3",
+            b"4",
+            b"5",
+            b"8",
+            b"9",
+            b"D",
+            b"F",
+            b"G",
+            b"Q",
+            b"R",
+            b"S",
+            b"V",
+            b"Y",
+            b"AE",
+            b"j",
+        ] {
+            let msg_type = MsgType::from_fix_bytes(wire).unwrap();
+            assert_eq!(MsgType::from_name(msg_type.name()), Some(msg_type));
+        }
+
+        assert_eq!(MsgType::from_name("logon"), None);
+    }
+}
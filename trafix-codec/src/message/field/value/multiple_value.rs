@@ -0,0 +1,277 @@
+//! Defines the [`MultipleCharValue`] and [`MultipleStringValue`] types, representing FIX field
+//! values made up of a space-separated list of values, e.g. the **18 `ExecInst`**.
+
+use crate::message::field::value::FromFixBytes;
+
+/// Represents a space-separated list of single characters, the FIX `MultipleCharValue` type,
+/// e.g. the `ExecInst` (`18`).
+///
+/// The list is validated on construction, so it always holds at least one character, and all
+/// characters are printable ASCII other than space.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MultipleCharValue(Vec<u8>);
+
+impl MultipleCharValue {
+    /// Creates a new [`MultipleCharValue`] from the given characters.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::field::value::multiple_value::MultipleCharValue;
+    ///
+    /// let value = MultipleCharValue::new(b"1G").unwrap();
+    ///
+    /// assert_eq!(value.as_bytes(), b"1 G");
+    /// assert!(value.contains(b'G'));
+    /// assert!(MultipleCharValue::new(b"").is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseError::Empty`] if no characters are given, or [`ParseError::Invalid`] if a
+    /// character is not printable ASCII or is a space.
+    pub fn new(chars: &[u8]) -> Result<Self, ParseError<'_>> {
+        if chars.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        let mut bytes = Vec::with_capacity(chars.len() * 2);
+
+        for (index, char) in chars.iter().enumerate() {
+            if !char.is_ascii_graphic() {
+                return Err(ParseError::Invalid(&chars[index..=index]));
+            }
+
+            if index > 0 {
+                bytes.push(b' ');
+            }
+            bytes.push(*char);
+        }
+
+        Ok(Self(bytes))
+    }
+
+    /// Returns the wire representation of this list, with the characters separated by single
+    /// spaces.
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Returns `true` if the given character is in this list.
+    #[must_use]
+    pub fn contains(&self, char: u8) -> bool {
+        self.iter().any(|value| value == char)
+    }
+
+    /// Returns an iterator over the characters of this list, in order.
+    pub fn iter(&self) -> impl Iterator<Item = u8> {
+        self.0.iter().step_by(2).copied()
+    }
+}
+
+impl From<&MultipleCharValue> for Vec<u8> {
+    /// Converts a [`MultipleCharValue`] into an **owned `Vec<u8>`** containing its wire
+    /// representation.
+    fn from(val: &MultipleCharValue) -> Self {
+        val.0.clone()
+    }
+}
+
+impl FromFixBytes for MultipleCharValue {
+    type Error<'input> = ParseError<'input>;
+
+    fn from_fix_bytes(bytes: &[u8]) -> Result<Self, Self::Error<'_>>
+    where
+        Self: Sized,
+    {
+        for value in split_values(bytes)? {
+            if !matches!(value, [char] if char.is_ascii_graphic()) {
+                return Err(ParseError::Invalid(value));
+            }
+        }
+
+        Ok(Self(bytes.to_vec()))
+    }
+}
+
+/// Represents a space-separated list of strings, the FIX `MultipleStringValue` type.
+///
+/// The list is validated on construction, so it always holds at least one string, and all
+/// strings are non-empty and consist of printable ASCII other than space.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MultipleStringValue(Vec<u8>);
+
+impl MultipleStringValue {
+    /// Creates a new [`MultipleStringValue`] from the given strings.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::field::value::multiple_value::MultipleStringValue;
+    ///
+    /// let value = MultipleStringValue::new([b"XNYS".as_slice(), b"XNAS"]).unwrap();
+    ///
+    /// assert_eq!(value.as_bytes(), b"XNYS XNAS");
+    /// assert_eq!(value.iter().collect::<Vec<_>>(), [b"XNYS", b"XNAS"]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseError::Empty`] if no strings are given or a string is empty, or
+    /// [`ParseError::Invalid`] if a string contains a byte that is not printable ASCII or is a
+    /// space.
+    pub fn new<'input>(
+        values: impl IntoIterator<Item = &'input [u8]>,
+    ) -> Result<Self, ParseError<'input>> {
+        let mut bytes = Vec::new();
+
+        for value in values {
+            if value.is_empty() {
+                return Err(ParseError::Empty);
+            }
+
+            if !value.iter().all(u8::is_ascii_graphic) {
+                return Err(ParseError::Invalid(value));
+            }
+
+            if !bytes.is_empty() {
+                bytes.push(b' ');
+            }
+            bytes.extend_from_slice(value);
+        }
+
+        if bytes.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        Ok(Self(bytes))
+    }
+
+    /// Returns the wire representation of this list, with the strings separated by single
+    /// spaces.
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Returns `true` if the given string is in this list.
+    #[must_use]
+    pub fn contains(&self, value: &[u8]) -> bool {
+        self.iter().any(|item| item == value)
+    }
+
+    /// Returns an iterator over the strings of this list, in order.
+    pub fn iter(&self) -> impl Iterator<Item = &[u8]> {
+        self.0.split(|byte| *byte == b' ')
+    }
+}
+
+impl From<&MultipleStringValue> for Vec<u8> {
+    /// Converts a [`MultipleStringValue`] into an **owned `Vec<u8>`** containing its wire
+    /// representation.
+    fn from(val: &MultipleStringValue) -> Self {
+        val.0.clone()
+    }
+}
+
+impl FromFixBytes for MultipleStringValue {
+    type Error<'input> = ParseError<'input>;
+
+    fn from_fix_bytes(bytes: &[u8]) -> Result<Self, Self::Error<'_>>
+    where
+        Self: Sized,
+    {
+        for value in split_values(bytes)? {
+            if !value.iter().all(u8::is_ascii_graphic) {
+                return Err(ParseError::Invalid(value));
+            }
+        }
+
+        Ok(Self(bytes.to_vec()))
+    }
+}
+
+/// Splits a space-separated list into its values, failing if the list or any of its values is
+/// empty, e.g. due to consecutive spaces.
+fn split_values(bytes: &[u8]) -> Result<Vec<&[u8]>, ParseError<'_>> {
+    let values: Vec<_> = bytes.split(|byte| *byte == b' ').collect();
+
+    if values.iter().any(|value| value.is_empty()) {
+        return Err(ParseError::Empty);
+    }
+
+    Ok(values)
+}
+
+/// The error type for failed parsing of [`MultipleCharValue`] and [`MultipleStringValue`].
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ParseError<'input> {
+    /// The list or one of its values is empty, e.g. due to consecutive spaces.
+    #[error("empty value in space-separated list")]
+    Empty,
+
+    /// Provided byte slice is not a valid value of the list.
+    #[error("invalid value in space-separated list: {}", String::from_utf8_lossy(.0))]
+    Invalid(&'input [u8]),
+}
+
+#[cfg(test)]
+mod test {
+    use super::{MultipleCharValue, MultipleStringValue, ParseError};
+    use crate::message::field::value::FromFixBytes;
+
+    #[test]
+    fn multiple_char_value() {
+        let single = MultipleCharValue::from_fix_bytes(b"G").unwrap();
+        assert_eq!(single.iter().collect::<Vec<_>>(), b"G");
+        assert_eq!(Vec::from(&single), b"G");
+
+        let multiple = MultipleCharValue::from_fix_bytes(b"1 G A").unwrap();
+        assert_eq!(multiple.iter().collect::<Vec<_>>(), b"1GA");
+        assert_eq!(Vec::from(&multiple), b"1 G A");
+        assert_eq!(MultipleCharValue::new(b"1GA"), Ok(multiple));
+
+        for input in [&b""[..], b" ", b"1  G", b"1 ", b" 1"] {
+            assert_eq!(
+                MultipleCharValue::from_fix_bytes(input),
+                Err(ParseError::Empty)
+            );
+        }
+
+        assert_eq!(
+            MultipleCharValue::from_fix_bytes(b"1 GA"),
+            Err(ParseError::Invalid(b"GA"))
+        );
+        assert_eq!(
+            MultipleCharValue::new(b"1 "),
+            Err(ParseError::Invalid(b" "))
+        );
+    }
+
+    #[test]
+    fn multiple_string_value() {
+        let single = MultipleStringValue::from_fix_bytes(b"XNYS").unwrap();
+        assert_eq!(single.iter().collect::<Vec<_>>(), [b"XNYS"]);
+        assert_eq!(Vec::from(&single), b"XNYS");
+
+        let multiple = MultipleStringValue::from_fix_bytes(b"XNYS XNAS BATS").unwrap();
+        assert_eq!(
+            multiple.iter().collect::<Vec<_>>(),
+            [&b"XNYS"[..], b"XNAS", b"BATS"]
+        );
+        assert!(multiple.contains(b"XNAS"));
+        assert_eq!(Vec::from(&multiple), b"XNYS XNAS BATS");
+
+        for input in [&b""[..], b" ", b"XNYS  XNAS", b"XNYS "] {
+            assert_eq!(
+                MultipleStringValue::from_fix_bytes(input),
+                Err(ParseError::Empty)
+            );
+        }
+
+        assert_eq!(MultipleStringValue::new([]), Err(ParseError::Empty));
+        assert_eq!(
+            MultipleStringValue::new([b"X\x01".as_slice()]),
+            Err(ParseError::Invalid(b"X\x01"))
+        );
+    }
+}
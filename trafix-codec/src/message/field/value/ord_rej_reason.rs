@@ -0,0 +1,148 @@
+//! Defines the [`OrdRejReason`] enumeration representing the FIX
+//! **103 `OrdRejReason`** field value.
+
+use crate::message::field::value::FromFixBytes;
+
+/// Represents the reason (`103`) of an order being rejected, e.g. carried by an `ExecutionReport`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OrdRejReason {
+    /// Broker / exchange option (`103=0`).
+    BrokerOption,
+
+    /// Unknown symbol (`103=1`).
+    UnknownSymbol,
+
+    /// Exchange closed (`103=2`).
+    ExchangeClosed,
+
+    /// Order exceeds limit (`103=3`).
+    OrderExceedsLimit,
+
+    /// Too late to enter (`103=4`).
+    TooLateToEnter,
+
+    /// Unknown order (`103=5`).
+    UnknownOrder,
+
+    /// Duplicate order, i.e. a duplicate `ClOrdID` (`103=6`).
+    DuplicateOrder,
+
+    /// Duplicate of a verbally communicated order (`103=7`).
+    DuplicateVerballyCommunicatedOrder,
+
+    /// Stale order (`103=8`).
+    StaleOrder,
+
+    /// Trade along required (`103=9`).
+    TradeAlongRequired,
+
+    /// Invalid investor ID (`103=10`).
+    InvalidInvestorID,
+
+    /// Unsupported order characteristic (`103=11`).
+    UnsupportedOrderCharacteristic,
+
+    /// Surveillance option (`103=12`).
+    SurveillanceOption,
+
+    /// Incorrect quantity (`103=13`).
+    IncorrectQuantity,
+
+    /// Incorrect allocated quantity (`103=14`).
+    IncorrectAllocatedQuantity,
+
+    /// Unknown account(s) (`103=15`).
+    UnknownAccount,
+
+    /// Other (`103=99`).
+    Other,
+}
+
+impl OrdRejReason {
+    /// Returns the tag used for [`OrdRejReason`].
+    #[must_use]
+    pub const fn tag() -> u32 {
+        103
+    }
+}
+
+impl From<OrdRejReason> for &'static [u8] {
+    /// Converts a [`OrdRejReason`] variant into its **static byte slice**
+    /// representation, corresponding to the FIX wire value of tag **103**.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::field::value::ord_rej_reason::OrdRejReason;
+    /// let bytes: &'static [u8] = OrdRejReason::UnknownSymbol.into();
+    /// assert_eq!(bytes, b"1");
+    /// ```
+    fn from(val: OrdRejReason) -> Self {
+        match val {
+            OrdRejReason::BrokerOption => b"0",
+            OrdRejReason::UnknownSymbol => b"1",
+            OrdRejReason::ExchangeClosed => b"2",
+            OrdRejReason::OrderExceedsLimit => b"3",
+            OrdRejReason::TooLateToEnter => b"4",
+            OrdRejReason::UnknownOrder => b"5",
+            OrdRejReason::DuplicateOrder => b"6",
+            OrdRejReason::DuplicateVerballyCommunicatedOrder => b"7",
+            OrdRejReason::StaleOrder => b"8",
+            OrdRejReason::TradeAlongRequired => b"9",
+            OrdRejReason::InvalidInvestorID => b"10",
+            OrdRejReason::UnsupportedOrderCharacteristic => b"11",
+            OrdRejReason::SurveillanceOption => b"12",
+            OrdRejReason::IncorrectQuantity => b"13",
+            OrdRejReason::IncorrectAllocatedQuantity => b"14",
+            OrdRejReason::UnknownAccount => b"15",
+            OrdRejReason::Other => b"99",
+        }
+    }
+}
+
+impl From<OrdRejReason> for Vec<u8> {
+    /// Converts a [`OrdRejReason`] variant into an **owned `Vec<u8>`**
+    /// containing its FIX wire representation (tag **103** value).
+    fn from(val: OrdRejReason) -> Self {
+        <&[u8]>::from(val).to_vec()
+    }
+}
+
+/// The error type for failed parsing of [`OrdRejReason`]
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ParseError<'input> {
+    /// Provided byte slice contains data that is not a valid or supported order reject reason.
+    #[error("unsupported order reject reason: {}", String::from_utf8_lossy(.0))]
+    Unsupported(&'input [u8]),
+}
+
+impl FromFixBytes for OrdRejReason {
+    type Error<'input> = ParseError<'input>;
+
+    const ENUMERATED: bool = true;
+
+    fn from_fix_bytes(bytes: &[u8]) -> Result<Self, Self::Error<'_>>
+    where
+        Self: Sized,
+    {
+        match bytes {
+            b"0" => Ok(OrdRejReason::BrokerOption),
+            b"1" => Ok(OrdRejReason::UnknownSymbol),
+            b"2" => Ok(OrdRejReason::ExchangeClosed),
+            b"3" => Ok(OrdRejReason::OrderExceedsLimit),
+            b"4" => Ok(OrdRejReason::TooLateToEnter),
+            b"5" => Ok(OrdRejReason::UnknownOrder),
+            b"6" => Ok(OrdRejReason::DuplicateOrder),
+            b"7" => Ok(OrdRejReason::DuplicateVerballyCommunicatedOrder),
+            b"8" => Ok(OrdRejReason::StaleOrder),
+            b"9" => Ok(OrdRejReason::TradeAlongRequired),
+            b"10" => Ok(OrdRejReason::InvalidInvestorID),
+            b"11" => Ok(OrdRejReason::UnsupportedOrderCharacteristic),
+            b"12" => Ok(OrdRejReason::SurveillanceOption),
+            b"13" => Ok(OrdRejReason::IncorrectQuantity),
+            b"14" => Ok(OrdRejReason::IncorrectAllocatedQuantity),
+            b"15" => Ok(OrdRejReason::UnknownAccount),
+            b"99" => Ok(OrdRejReason::Other),
+            other => Err(ParseError::Unsupported(other)),
+        }
+    }
+}
@@ -0,0 +1,179 @@
+//! Defines the [`OrdType`] enumeration representing the FIX
+//! **40 `OrdType`** field value.
+
+use crate::message::field::value::FromFixBytes;
+
+/// Represents the type (`40`) of an order, e.g. sent in a `NewOrderSingle`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OrdType {
+    /// Market order (`40=1`), executed at the best available price.
+    Market,
+
+    /// Limit order (`40=2`), executed at the given price or better.
+    Limit,
+
+    /// Stop order (`40=3`), becoming a market order once the stop price is reached.
+    Stop,
+
+    /// Stop limit order (`40=4`), becoming a limit order once the stop price is reached.
+    StopLimit,
+
+    /// Market on close order (`40=5`), deprecated in favor of `TimeInForce` at the close.
+    MarketOnClose,
+
+    /// With or without order (`40=6`).
+    WithOrWithout,
+
+    /// Limit or better order (`40=7`), deprecated.
+    LimitOrBetter,
+
+    /// Limit with or without order (`40=8`).
+    LimitWithOrWithout,
+
+    /// On basis order (`40=9`).
+    OnBasis,
+
+    /// On close order (`40=A`), deprecated.
+    OnClose,
+
+    /// Limit on close order (`40=B`), deprecated.
+    LimitOnClose,
+
+    /// Forex market order (`40=C`), deprecated.
+    ForexMarket,
+
+    /// Previously quoted order (`40=D`), accepting a quote received before.
+    PreviouslyQuoted,
+
+    /// Previously indicated order (`40=E`), responding to an indication of interest.
+    PreviouslyIndicated,
+
+    /// Forex limit order (`40=F`), deprecated.
+    ForexLimit,
+
+    /// Forex swap order (`40=G`).
+    ForexSwap,
+
+    /// Forex previously quoted order (`40=H`), deprecated.
+    ForexPreviouslyQuoted,
+
+    /// Funari order (`40=I`), a limit order executed at the close as a market order if unfilled.
+    Funari,
+
+    /// Market if touched order (`40=J`), becoming a market order once the given price is touched.
+    MarketIfTouched,
+
+    /// Market with leftover as limit order (`40=K`), with any unfilled quantity resting as a limit
+    /// order at the execution price.
+    MarketWithLeftoverAsLimit,
+
+    /// Previous fund valuation point order (`40=L`), for historic pricing of funds.
+    PreviousFundValuationPoint,
+
+    /// Next fund valuation point order (`40=M`), for forward pricing of funds.
+    NextFundValuationPoint,
+
+    /// Pegged order (`40=P`), with a price pegged to a reference price.
+    Pegged,
+}
+
+impl OrdType {
+    /// Returns the tag used for [`OrdType`].
+    #[must_use]
+    pub const fn tag() -> u32 {
+        40
+    }
+}
+
+impl From<OrdType> for &'static [u8] {
+    /// Converts a [`OrdType`] variant into its **static byte slice**
+    /// representation, corresponding to the FIX wire value of tag **40**.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::field::value::ord_type::OrdType;
+    /// let bytes: &'static [u8] = OrdType::Limit.into();
+    /// assert_eq!(bytes, b"2");
+    /// ```
+    fn from(val: OrdType) -> Self {
+        match val {
+            OrdType::Market => b"1",
+            OrdType::Limit => b"2",
+            OrdType::Stop => b"3",
+            OrdType::StopLimit => b"4",
+            OrdType::MarketOnClose => b"5",
+            OrdType::WithOrWithout => b"6",
+            OrdType::LimitOrBetter => b"7",
+            OrdType::LimitWithOrWithout => b"8",
+            OrdType::OnBasis => b"9",
+            OrdType::OnClose => b"A",
+            OrdType::LimitOnClose => b"B",
+            OrdType::ForexMarket => b"C",
+            OrdType::PreviouslyQuoted => b"D",
+            OrdType::PreviouslyIndicated => b"E",
+            OrdType::ForexLimit => b"F",
+            OrdType::ForexSwap => b"G",
+            OrdType::ForexPreviouslyQuoted => b"H",
+            OrdType::Funari => b"I",
+            OrdType::MarketIfTouched => b"J",
+            OrdType::MarketWithLeftoverAsLimit => b"K",
+            OrdType::PreviousFundValuationPoint => b"L",
+            OrdType::NextFundValuationPoint => b"M",
+            OrdType::Pegged => b"P",
+        }
+    }
+}
+
+impl From<OrdType> for Vec<u8> {
+    /// Converts a [`OrdType`] variant into an **owned `Vec<u8>`**
+    /// containing its FIX wire representation (tag **40** value).
+    fn from(val: OrdType) -> Self {
+        <&[u8]>::from(val).to_vec()
+    }
+}
+
+/// The error type for failed parsing of [`OrdType`]
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ParseError<'input> {
+    /// Provided byte slice contains data that is not a valid or supported order type.
+    #[error("unsupported order type: {}", String::from_utf8_lossy(.0))]
+    Unsupported(&'input [u8]),
+}
+
+impl FromFixBytes for OrdType {
+    type Error<'input> = ParseError<'input>;
+
+    const ENUMERATED: bool = true;
+
+    fn from_fix_bytes(bytes: &[u8]) -> Result<Self, Self::Error<'_>>
+    where
+        Self: Sized,
+    {
+        match bytes {
+            b"1" => Ok(OrdType::Market),
+            b"2" => Ok(OrdType::Limit),
+            b"3" => Ok(OrdType::Stop),
+            b"4" => Ok(OrdType::StopLimit),
+            b"5" => Ok(OrdType::MarketOnClose),
+            b"6" => Ok(OrdType::WithOrWithout),
+            b"7" => Ok(OrdType::LimitOrBetter),
+            b"8" => Ok(OrdType::LimitWithOrWithout),
+            b"9" => Ok(OrdType::OnBasis),
+            b"A" => Ok(OrdType::OnClose),
+            b"B" => Ok(OrdType::LimitOnClose),
+            b"C" => Ok(OrdType::ForexMarket),
+            b"D" => Ok(OrdType::PreviouslyQuoted),
+            b"E" => Ok(OrdType::PreviouslyIndicated),
+            b"F" => Ok(OrdType::ForexLimit),
+            b"G" => Ok(OrdType::ForexSwap),
+            b"H" => Ok(OrdType::ForexPreviouslyQuoted),
+            b"I" => Ok(OrdType::Funari),
+            b"J" => Ok(OrdType::MarketIfTouched),
+            b"K" => Ok(OrdType::MarketWithLeftoverAsLimit),
+            b"L" => Ok(OrdType::PreviousFundValuationPoint),
+            b"M" => Ok(OrdType::NextFundValuationPoint),
+            b"P" => Ok(OrdType::Pegged),
+            other => Err(ParseError::Unsupported(other)),
+        }
+    }
+}
@@ -0,0 +1,93 @@
+//! Defines the [`OrderCapacity`] enumeration representing the FIX
+//! **528 `OrderCapacity`** field value.
+
+use crate::message::field::value::FromFixBytes;
+
+/// Represents the capacity (`528`) in which the firm placing an order acts.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OrderCapacity {
+    /// Agency (`528=A`), acting on behalf of a client.
+    Agency,
+
+    /// Proprietary (`528=G`), trading on the firm's own account.
+    Proprietary,
+
+    /// Individual (`528=I`).
+    Individual,
+
+    /// Principal (`528=P`), trading against the firm's own inventory.
+    Principal,
+
+    /// Riskless principal (`528=R`), offsetting a client order with a principal trade.
+    RisklessPrincipal,
+
+    /// Agent for other member (`528=W`).
+    AgentForOtherMember,
+}
+
+impl OrderCapacity {
+    /// Returns the tag used for [`OrderCapacity`].
+    #[must_use]
+    pub const fn tag() -> u32 {
+        528
+    }
+}
+
+impl From<OrderCapacity> for &'static [u8] {
+    /// Converts a [`OrderCapacity`] variant into its **static byte slice**
+    /// representation, corresponding to the FIX wire value of tag **528**.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::field::value::order_capacity::OrderCapacity;
+    /// let bytes: &'static [u8] = OrderCapacity::Principal.into();
+    /// assert_eq!(bytes, b"P");
+    /// ```
+    fn from(val: OrderCapacity) -> Self {
+        match val {
+            OrderCapacity::Agency => b"A",
+            OrderCapacity::Proprietary => b"G",
+            OrderCapacity::Individual => b"I",
+            OrderCapacity::Principal => b"P",
+            OrderCapacity::RisklessPrincipal => b"R",
+            OrderCapacity::AgentForOtherMember => b"W",
+        }
+    }
+}
+
+impl From<OrderCapacity> for Vec<u8> {
+    /// Converts a [`OrderCapacity`] variant into an **owned `Vec<u8>`**
+    /// containing its FIX wire representation (tag **528** value).
+    fn from(val: OrderCapacity) -> Self {
+        <&[u8]>::from(val).to_vec()
+    }
+}
+
+/// The error type for failed parsing of [`OrderCapacity`]
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ParseError<'input> {
+    /// Provided byte slice contains data that is not a valid or supported order capacity.
+    #[error("unsupported order capacity: {}", String::from_utf8_lossy(.0))]
+    Unsupported(&'input [u8]),
+}
+
+impl FromFixBytes for OrderCapacity {
+    type Error<'input> = ParseError<'input>;
+
+    const ENUMERATED: bool = true;
+
+    fn from_fix_bytes(bytes: &[u8]) -> Result<Self, Self::Error<'_>>
+    where
+        Self: Sized,
+    {
+        match bytes {
+            b"A" => Ok(OrderCapacity::Agency),
+            b"G" => Ok(OrderCapacity::Proprietary),
+            b"I" => Ok(OrderCapacity::Individual),
+            b"P" => Ok(OrderCapacity::Principal),
+            b"R" => Ok(OrderCapacity::RisklessPrincipal),
+            b"W" => Ok(OrderCapacity::AgentForOtherMember),
+            other => Err(ParseError::Unsupported(other)),
+        }
+    }
+}
@@ -0,0 +1,267 @@
+//! Defines the [`OrderRestrictions`] type, representing the FIX **529 `OrderRestrictions`**
+//! field value, and the [`OrderRestriction`] enumeration of its individual values.
+
+use crate::message::field::value::{
+    FromFixBytes,
+    multiple_value::{self, MultipleCharValue},
+};
+
+/// Represents a single restriction of an order, one of the values of the `OrderRestrictions`
+/// (`529`).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OrderRestriction {
+    /// Program trade (`1`).
+    ProgramTrade,
+
+    /// Index arbitrage (`2`).
+    IndexArbitrage,
+
+    /// Non-index arbitrage (`3`).
+    NonIndexArbitrage,
+
+    /// Competing market maker (`4`).
+    CompetingMarketMaker,
+
+    /// Acting as market maker or specialist in the security (`5`).
+    MarketMakerInSecurity,
+
+    /// Acting as market maker or specialist in the underlying security of a derivative (`6`).
+    MarketMakerInUnderlying,
+
+    /// Foreign entity (`7`), of a foreign government or regulatory jurisdiction.
+    ForeignEntity,
+
+    /// External market participant (`8`).
+    ExternalMarketParticipant,
+
+    /// External inter-connected market linkage (`9`).
+    ExternalInterConnectedMarketLinkage,
+
+    /// Riskless arbitrage (`A`).
+    RisklessArbitrage,
+}
+
+impl OrderRestriction {
+    /// Returns the tag used for [`OrderRestriction`].
+    #[must_use]
+    pub const fn tag() -> u32 {
+        529
+    }
+}
+
+impl From<OrderRestriction> for &'static [u8] {
+    /// Converts a [`OrderRestriction`] variant into its **static byte slice**
+    /// representation, corresponding to the FIX wire value of tag **529**.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::field::value::order_restrictions::OrderRestriction;
+    /// let bytes: &'static [u8] = OrderRestriction::ForeignEntity.into();
+    /// assert_eq!(bytes, b"7");
+    /// ```
+    fn from(val: OrderRestriction) -> Self {
+        match val {
+            OrderRestriction::ProgramTrade => b"1",
+            OrderRestriction::IndexArbitrage => b"2",
+            OrderRestriction::NonIndexArbitrage => b"3",
+            OrderRestriction::CompetingMarketMaker => b"4",
+            OrderRestriction::MarketMakerInSecurity => b"5",
+            OrderRestriction::MarketMakerInUnderlying => b"6",
+            OrderRestriction::ForeignEntity => b"7",
+            OrderRestriction::ExternalMarketParticipant => b"8",
+            OrderRestriction::ExternalInterConnectedMarketLinkage => b"9",
+            OrderRestriction::RisklessArbitrage => b"A",
+        }
+    }
+}
+
+impl From<OrderRestriction> for Vec<u8> {
+    /// Converts a [`OrderRestriction`] variant into an **owned `Vec<u8>`**
+    /// containing its FIX wire representation (tag **529** value).
+    fn from(val: OrderRestriction) -> Self {
+        <&[u8]>::from(val).to_vec()
+    }
+}
+
+/// The error type for failed parsing of [`OrderRestriction`] and [`OrderRestrictions`].
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ParseError<'input> {
+    /// Provided byte slice contains data that is not a valid or supported order restriction.
+    #[error("unsupported order restriction: {}", String::from_utf8_lossy(.0))]
+    Unsupported(&'input [u8]),
+
+    /// Provided byte slice is not a valid space-separated list of single characters.
+    #[error("{0}")]
+    List(multiple_value::ParseError<'input>),
+}
+
+impl<'input> From<multiple_value::ParseError<'input>> for ParseError<'input> {
+    fn from(error: multiple_value::ParseError<'input>) -> Self {
+        Self::List(error)
+    }
+}
+
+impl FromFixBytes for OrderRestriction {
+    type Error<'input> = ParseError<'input>;
+
+    const ENUMERATED: bool = true;
+
+    fn from_fix_bytes(bytes: &[u8]) -> Result<Self, Self::Error<'_>>
+    where
+        Self: Sized,
+    {
+        match bytes {
+            b"1" => Ok(OrderRestriction::ProgramTrade),
+            b"2" => Ok(OrderRestriction::IndexArbitrage),
+            b"3" => Ok(OrderRestriction::NonIndexArbitrage),
+            b"4" => Ok(OrderRestriction::CompetingMarketMaker),
+            b"5" => Ok(OrderRestriction::MarketMakerInSecurity),
+            b"6" => Ok(OrderRestriction::MarketMakerInUnderlying),
+            b"7" => Ok(OrderRestriction::ForeignEntity),
+            b"8" => Ok(OrderRestriction::ExternalMarketParticipant),
+            b"9" => Ok(OrderRestriction::ExternalInterConnectedMarketLinkage),
+            b"A" => Ok(OrderRestriction::RisklessArbitrage),
+            other => Err(ParseError::Unsupported(other)),
+        }
+    }
+}
+
+/// Represents the `OrderRestrictions` (`529`), a list of [`OrderRestriction`]s, stored as a
+/// [`MultipleCharValue`] holding only known restrictions.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OrderRestrictions(MultipleCharValue);
+
+impl OrderRestrictions {
+    /// Creates new [`OrderRestrictions`] from the given restrictions, kept in order.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::field::value::order_restrictions::{
+    ///     OrderRestriction, OrderRestrictions,
+    /// };
+    ///
+    /// let restrictions = OrderRestrictions::new([
+    ///     OrderRestriction::ProgramTrade,
+    ///     OrderRestriction::ForeignEntity,
+    /// ])
+    /// .unwrap();
+    ///
+    /// assert_eq!(Vec::from(&restrictions), b"1 7");
+    /// assert!(restrictions.contains(OrderRestriction::ForeignEntity));
+    /// assert!(OrderRestrictions::new([]).is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseError::List`] if no restrictions are given.
+    pub fn new(
+        restrictions: impl IntoIterator<Item = OrderRestriction>,
+    ) -> Result<Self, ParseError<'static>> {
+        let chars: Vec<u8> = restrictions
+            .into_iter()
+            .map(|restriction| <&[u8]>::from(restriction)[0])
+            .collect();
+
+        // the wire values of restrictions are printable, so the list can only be empty
+        MultipleCharValue::new(&chars)
+            .map(Self)
+            .map_err(|_| multiple_value::ParseError::Empty.into())
+    }
+
+    /// Returns the tag used for [`OrderRestrictions`].
+    #[must_use]
+    pub const fn tag() -> u32 {
+        529
+    }
+
+    /// Returns the wire representation of the restrictions, separated by single spaces.
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        self.0.as_bytes()
+    }
+
+    /// Returns `true` if the given restriction is among these restrictions.
+    #[must_use]
+    pub fn contains(&self, restriction: OrderRestriction) -> bool {
+        self.0.contains(<&[u8]>::from(restriction)[0])
+    }
+
+    /// Returns an iterator over the restrictions, in order.
+    pub fn iter(&self) -> impl Iterator<Item = OrderRestriction> {
+        // all characters were validated on construction
+        self.0
+            .iter()
+            .filter_map(|char| OrderRestriction::from_fix_bytes(&[char]).ok())
+    }
+}
+
+impl From<&OrderRestrictions> for Vec<u8> {
+    /// Converts [`OrderRestrictions`] into an **owned `Vec<u8>`** containing the wire values
+    /// of the restrictions, separated by spaces.
+    fn from(val: &OrderRestrictions) -> Self {
+        Vec::from(&val.0)
+    }
+}
+
+impl FromFixBytes for OrderRestrictions {
+    type Error<'input> = ParseError<'input>;
+
+    const ENUMERATED: bool = true;
+
+    fn from_fix_bytes(bytes: &[u8]) -> Result<Self, Self::Error<'_>>
+    where
+        Self: Sized,
+    {
+        let list = MultipleCharValue::from_fix_bytes(bytes)?;
+
+        // a valid list holds single characters at even offsets
+        for index in (0..bytes.len()).step_by(2) {
+            OrderRestriction::from_fix_bytes(&bytes[index..=index])?;
+        }
+
+        Ok(Self(list))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::message::field::value::{
+        FromFixBytes, multiple_value,
+        order_restrictions::{OrderRestriction, OrderRestrictions, ParseError},
+    };
+
+    #[test]
+    fn multiple_values() {
+        let restrictions = OrderRestrictions::from_fix_bytes(b"1 A 7").unwrap();
+
+        assert_eq!(
+            restrictions.iter().collect::<Vec<_>>(),
+            vec![
+                OrderRestriction::ProgramTrade,
+                OrderRestriction::RisklessArbitrage,
+                OrderRestriction::ForeignEntity
+            ]
+        );
+        assert_eq!(Vec::from(&restrictions), b"1 A 7");
+
+        assert_eq!(
+            OrderRestrictions::from_fix_bytes(b"1 Z"),
+            Err(ParseError::Unsupported(b"Z"))
+        );
+        // values are single chars separated by single spaces
+        assert_eq!(
+            OrderRestrictions::from_fix_bytes(b"12"),
+            Err(ParseError::List(multiple_value::ParseError::Invalid(b"12")))
+        );
+        for input in [&b"1  2"[..], b"1 ", b""] {
+            assert_eq!(
+                OrderRestrictions::from_fix_bytes(input),
+                Err(ParseError::List(multiple_value::ParseError::Empty))
+            );
+        }
+        assert_eq!(
+            OrderRestrictions::new([]),
+            Err(ParseError::List(multiple_value::ParseError::Empty))
+        );
+    }
+}
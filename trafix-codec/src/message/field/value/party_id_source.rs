@@ -0,0 +1,153 @@
+//! Defines the [`PartyIDSource`] enumeration representing the FIX
+//! **447 `PartyIDSource`** field value.
+
+use crate::message::field::value::FromFixBytes;
+
+/// Represents the source (`447`) of a `PartyID`, i.e. the identification scheme it belongs to.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PartyIDSource {
+    /// Korean investor ID (`447=1`).
+    KoreanInvestorId,
+
+    /// Taiwanese qualified foreign investor ID, QFII or FID (`447=2`).
+    TaiwaneseQualifiedForeignInvestorId,
+
+    /// Taiwanese trading account (`447=3`).
+    TaiwaneseTradingAccount,
+
+    /// Malaysian central depository number (`447=4`).
+    MalaysianCentralDepositoryNumber,
+
+    /// Chinese B share, Shenzhen and Shanghai (`447=5`).
+    ChineseBShare,
+
+    /// UK national insurance or pension number (`447=6`).
+    UkNationalInsuranceOrPensionNumber,
+
+    /// US social security number (`447=7`).
+    UsSocialSecurityNumber,
+
+    /// US employer identification number (`447=8`).
+    UsEmployerIdentificationNumber,
+
+    /// Australian business number (`447=9`).
+    AustralianBusinessNumber,
+
+    /// Australian tax file number (`447=A`).
+    AustralianTaxFileNumber,
+
+    /// BIC, i.e. SWIFT bank identifier code (`447=B`).
+    Bic,
+
+    /// Generally accepted market participant identifier, e.g. an NASD mnemonic (`447=C`).
+    MarketParticipantIdentifier,
+
+    /// Proprietary or custom code (`447=D`).
+    Proprietary,
+
+    /// ISO country code (`447=E`).
+    IsoCountryCode,
+
+    /// Settlement entity location (`447=F`).
+    SettlementEntityLocation,
+
+    /// MIC, i.e. ISO 10383 market identifier code (`447=G`).
+    Mic,
+
+    /// CSD participant or member code (`447=H`).
+    CsdParticipantCode,
+
+    /// ISITC acronym (`447=I`).
+    IsitcAcronym,
+}
+
+impl PartyIDSource {
+    /// Returns the tag used for [`PartyIDSource`].
+    #[must_use]
+    pub const fn tag() -> u32 {
+        447
+    }
+}
+
+impl From<PartyIDSource> for &'static [u8] {
+    /// Converts a [`PartyIDSource`] variant into its **static byte slice**
+    /// representation, corresponding to the FIX wire value of tag **447**.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::field::value::party_id_source::PartyIDSource;
+    /// let bytes: &'static [u8] = PartyIDSource::Proprietary.into();
+    /// assert_eq!(bytes, b"D");
+    /// ```
+    fn from(val: PartyIDSource) -> Self {
+        match val {
+            PartyIDSource::KoreanInvestorId => b"1",
+            PartyIDSource::TaiwaneseQualifiedForeignInvestorId => b"2",
+            PartyIDSource::TaiwaneseTradingAccount => b"3",
+            PartyIDSource::MalaysianCentralDepositoryNumber => b"4",
+            PartyIDSource::ChineseBShare => b"5",
+            PartyIDSource::UkNationalInsuranceOrPensionNumber => b"6",
+            PartyIDSource::UsSocialSecurityNumber => b"7",
+            PartyIDSource::UsEmployerIdentificationNumber => b"8",
+            PartyIDSource::AustralianBusinessNumber => b"9",
+            PartyIDSource::AustralianTaxFileNumber => b"A",
+            PartyIDSource::Bic => b"B",
+            PartyIDSource::MarketParticipantIdentifier => b"C",
+            PartyIDSource::Proprietary => b"D",
+            PartyIDSource::IsoCountryCode => b"E",
+            PartyIDSource::SettlementEntityLocation => b"F",
+            PartyIDSource::Mic => b"G",
+            PartyIDSource::CsdParticipantCode => b"H",
+            PartyIDSource::IsitcAcronym => b"I",
+        }
+    }
+}
+
+impl From<PartyIDSource> for Vec<u8> {
+    /// Converts a [`PartyIDSource`] variant into an **owned `Vec<u8>`**
+    /// containing its FIX wire representation (tag **447** value).
+    fn from(val: PartyIDSource) -> Self {
+        <&[u8]>::from(val).to_vec()
+    }
+}
+
+/// The error type for failed parsing of [`PartyIDSource`]
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ParseError<'input> {
+    /// Provided byte slice contains data that is not a valid or supported party ID source.
+    #[error("unsupported party ID source: {}", String::from_utf8_lossy(.0))]
+    Unsupported(&'input [u8]),
+}
+
+impl FromFixBytes for PartyIDSource {
+    type Error<'input> = ParseError<'input>;
+
+    const ENUMERATED: bool = true;
+
+    fn from_fix_bytes(bytes: &[u8]) -> Result<Self, Self::Error<'_>>
+    where
+        Self: Sized,
+    {
+        match bytes {
+            b"1" => Ok(PartyIDSource::KoreanInvestorId),
+            b"2" => Ok(PartyIDSource::TaiwaneseQualifiedForeignInvestorId),
+            b"3" => Ok(PartyIDSource::TaiwaneseTradingAccount),
+            b"4" => Ok(PartyIDSource::MalaysianCentralDepositoryNumber),
+            b"5" => Ok(PartyIDSource::ChineseBShare),
+            b"6" => Ok(PartyIDSource::UkNationalInsuranceOrPensionNumber),
+            b"7" => Ok(PartyIDSource::UsSocialSecurityNumber),
+            b"8" => Ok(PartyIDSource::UsEmployerIdentificationNumber),
+            b"9" => Ok(PartyIDSource::AustralianBusinessNumber),
+            b"A" => Ok(PartyIDSource::AustralianTaxFileNumber),
+            b"B" => Ok(PartyIDSource::Bic),
+            b"C" => Ok(PartyIDSource::MarketParticipantIdentifier),
+            b"D" => Ok(PartyIDSource::Proprietary),
+            b"E" => Ok(PartyIDSource::IsoCountryCode),
+            b"F" => Ok(PartyIDSource::SettlementEntityLocation),
+            b"G" => Ok(PartyIDSource::Mic),
+            b"H" => Ok(PartyIDSource::CsdParticipantCode),
+            b"I" => Ok(PartyIDSource::IsitcAcronym),
+            other => Err(ParseError::Unsupported(other)),
+        }
+    }
+}
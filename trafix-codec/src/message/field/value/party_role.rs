@@ -0,0 +1,495 @@
+//! Defines the [`PartyRole`] enumeration representing the FIX
+//! **452 `PartyRole`** field value.
+
+use crate::message::field::value::FromFixBytes;
+
+/// Represents the role (`452`) of a party, e.g. the executing firm or the client.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PartyRole {
+    /// Executing firm (`452=1`).
+    ExecutingFirm,
+
+    /// Broker of credit (`452=2`).
+    BrokerOfCredit,
+
+    /// Client ID (`452=3`).
+    ClientId,
+
+    /// Clearing firm (`452=4`).
+    ClearingFirm,
+
+    /// Investor ID (`452=5`).
+    InvestorId,
+
+    /// Introducing firm (`452=6`).
+    IntroducingFirm,
+
+    /// Entering firm (`452=7`).
+    EnteringFirm,
+
+    /// Locate or lending firm (`452=8`).
+    LocateLendingFirm,
+
+    /// Fund manager client ID (`452=9`).
+    FundManagerClientId,
+
+    /// Settlement location (`452=10`).
+    SettlementLocation,
+
+    /// Order origination trader (`452=11`).
+    OrderOriginationTrader,
+
+    /// Executing trader (`452=12`).
+    ExecutingTrader,
+
+    /// Order origination firm (`452=13`).
+    OrderOriginationFirm,
+
+    /// Give-up clearing firm (`452=14`).
+    GiveUpClearingFirm,
+
+    /// Correspondent clearing firm (`452=15`).
+    CorrespondentClearingFirm,
+
+    /// Executing system (`452=16`).
+    ExecutingSystem,
+
+    /// Contra firm (`452=17`).
+    ContraFirm,
+
+    /// Contra clearing firm (`452=18`).
+    ContraClearingFirm,
+
+    /// Sponsoring firm (`452=19`).
+    SponsoringFirm,
+
+    /// Underlying contra firm (`452=20`).
+    UnderlyingContraFirm,
+
+    /// Clearing organization (`452=21`).
+    ClearingOrganization,
+
+    /// Exchange (`452=22`).
+    Exchange,
+
+    /// Customer account (`452=24`).
+    CustomerAccount,
+
+    /// Correspondent clearing organization (`452=25`).
+    CorrespondentClearingOrganization,
+
+    /// Correspondent broker (`452=26`).
+    CorrespondentBroker,
+
+    /// Buyer or seller, i.e. the receiver or deliverer (`452=27`).
+    BuyerSeller,
+
+    /// Custodian (`452=28`).
+    Custodian,
+
+    /// Intermediary (`452=29`).
+    Intermediary,
+
+    /// Agent (`452=30`).
+    Agent,
+
+    /// Sub-custodian (`452=31`).
+    SubCustodian,
+
+    /// Beneficiary (`452=32`).
+    Beneficiary,
+
+    /// Interested party (`452=33`).
+    InterestedParty,
+
+    /// Regulatory body (`452=34`).
+    RegulatoryBody,
+
+    /// Liquidity provider (`452=35`).
+    LiquidityProvider,
+
+    /// Entering trader (`452=36`).
+    EnteringTrader,
+
+    /// Contra trader (`452=37`).
+    ContraTrader,
+
+    /// Position account (`452=38`).
+    PositionAccount,
+
+    /// Contra position account (`452=39`).
+    ContraPositionAccount,
+
+    /// Contra exchange (`452=40`).
+    ContraExchange,
+
+    /// Internal carry account (`452=41`).
+    InternalCarryAccount,
+
+    /// Order entry operator ID (`452=42`).
+    OrderEntryOperatorId,
+
+    /// Secondary account number (`452=43`).
+    SecondaryAccountNumber,
+
+    /// Foreign firm (`452=44`).
+    ForeignFirm,
+
+    /// Third party allocation firm (`452=45`).
+    ThirdPartyAllocationFirm,
+
+    /// Claiming account (`452=46`).
+    ClaimingAccount,
+
+    /// Asset manager (`452=47`).
+    AssetManager,
+
+    /// Pledgor account (`452=48`).
+    PledgorAccount,
+
+    /// Pledgee account (`452=49`).
+    PledgeeAccount,
+
+    /// Large trader reportable account (`452=50`).
+    LargeTraderReportableAccount,
+
+    /// Trader mnemonic (`452=51`).
+    TraderMnemonic,
+
+    /// Sender location (`452=52`).
+    SenderLocation,
+
+    /// Session ID (`452=53`).
+    SessionId,
+
+    /// Acceptable counterparty (`452=54`).
+    AcceptableCounterparty,
+
+    /// Unacceptable counterparty (`452=55`).
+    UnacceptableCounterparty,
+
+    /// Entering unit (`452=56`).
+    EnteringUnit,
+
+    /// Executing unit (`452=57`).
+    ExecutingUnit,
+
+    /// Introducing broker (`452=58`).
+    IntroducingBroker,
+
+    /// Quote originator (`452=59`).
+    QuoteOriginator,
+
+    /// Report originator (`452=60`).
+    ReportOriginator,
+
+    /// Systematic internaliser (`452=61`).
+    SystematicInternaliser,
+
+    /// Multilateral trading facility (`452=62`).
+    MultilateralTradingFacility,
+
+    /// Regulated market (`452=63`).
+    RegulatedMarket,
+
+    /// Market maker (`452=64`).
+    MarketMaker,
+
+    /// Investment firm (`452=65`).
+    InvestmentFirm,
+
+    /// Host competent authority (`452=66`).
+    HostCompetentAuthority,
+
+    /// Home competent authority (`452=67`).
+    HomeCompetentAuthority,
+
+    /// Competent authority of the most relevant market in terms of liquidity (`452=68`).
+    CompetentAuthorityOfLiquidity,
+
+    /// Competent authority of the transaction (execution) venue (`452=69`).
+    CompetentAuthorityOfVenue,
+
+    /// Reporting intermediary, e.g. an approved publication arrangement (`452=70`).
+    ReportingIntermediary,
+
+    /// Execution venue (`452=71`).
+    ExecutionVenue,
+
+    /// Market data entry originator (`452=72`).
+    MarketDataEntryOriginator,
+
+    /// Location ID (`452=73`).
+    LocationId,
+
+    /// Desk ID (`452=74`).
+    DeskId,
+
+    /// Market data market (`452=75`).
+    MarketDataMarket,
+
+    /// Allocation entity (`452=76`).
+    AllocationEntity,
+
+    /// Prime broker providing general trade services (`452=77`).
+    PrimeBroker,
+
+    /// Step-out firm, i.e. the prime broker (`452=78`).
+    StepOutFirm,
+
+    /// Broker clearing ID (`452=79`).
+    BrokerClearingId,
+
+    /// Central Registration Depository (CRD) (`452=80`).
+    CentralRegistrationDepository,
+
+    /// Clearing account (`452=81`).
+    ClearingAccount,
+
+    /// Acceptable settling counterparty (`452=82`).
+    AcceptableSettlingCounterparty,
+
+    /// Unacceptable settling counterparty (`452=83`).
+    UnacceptableSettlingCounterparty,
+
+    /// Any other role, e.g. the roles added by later FIX versions for regulatory reporting, such as
+    /// investment decision maker (`452=122`), or bilaterally agreed roles from the user-defined
+    /// range `4000` and above.
+    Other(u32),
+}
+
+impl PartyRole {
+    /// Returns the tag used for [`PartyRole`].
+    #[must_use]
+    pub const fn tag() -> u32 {
+        452
+    }
+
+    /// Returns the **static byte slice** representation of this [`PartyRole`], or `None` for
+    /// [`PartyRole::Other`], whose value has to be formatted.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::field::value::party_role::PartyRole;
+    ///
+    /// assert_eq!(PartyRole::ClientId.as_static_bytes(), Some(b"3".as_slice()));
+    /// assert_eq!(PartyRole::Other(122).as_static_bytes(), None);
+    /// ```
+    #[must_use]
+    pub const fn as_static_bytes(&self) -> Option<&'static [u8]> {
+        Some(match self {
+            PartyRole::ExecutingFirm => b"1",
+            PartyRole::BrokerOfCredit => b"2",
+            PartyRole::ClientId => b"3",
+            PartyRole::ClearingFirm => b"4",
+            PartyRole::InvestorId => b"5",
+            PartyRole::IntroducingFirm => b"6",
+            PartyRole::EnteringFirm => b"7",
+            PartyRole::LocateLendingFirm => b"8",
+            PartyRole::FundManagerClientId => b"9",
+            PartyRole::SettlementLocation => b"10",
+            PartyRole::OrderOriginationTrader => b"11",
+            PartyRole::ExecutingTrader => b"12",
+            PartyRole::OrderOriginationFirm => b"13",
+            PartyRole::GiveUpClearingFirm => b"14",
+            PartyRole::CorrespondentClearingFirm => b"15",
+            PartyRole::ExecutingSystem => b"16",
+            PartyRole::ContraFirm => b"17",
+            PartyRole::ContraClearingFirm => b"18",
+            PartyRole::SponsoringFirm => b"19",
+            PartyRole::UnderlyingContraFirm => b"20",
+            PartyRole::ClearingOrganization => b"21",
+            PartyRole::Exchange => b"22",
+            PartyRole::CustomerAccount => b"24",
+            PartyRole::CorrespondentClearingOrganization => b"25",
+            PartyRole::CorrespondentBroker => b"26",
+            PartyRole::BuyerSeller => b"27",
+            PartyRole::Custodian => b"28",
+            PartyRole::Intermediary => b"29",
+            PartyRole::Agent => b"30",
+            PartyRole::SubCustodian => b"31",
+            PartyRole::Beneficiary => b"32",
+            PartyRole::InterestedParty => b"33",
+            PartyRole::RegulatoryBody => b"34",
+            PartyRole::LiquidityProvider => b"35",
+            PartyRole::EnteringTrader => b"36",
+            PartyRole::ContraTrader => b"37",
+            PartyRole::PositionAccount => b"38",
+            PartyRole::ContraPositionAccount => b"39",
+            PartyRole::ContraExchange => b"40",
+            PartyRole::InternalCarryAccount => b"41",
+            PartyRole::OrderEntryOperatorId => b"42",
+            PartyRole::SecondaryAccountNumber => b"43",
+            PartyRole::ForeignFirm => b"44",
+            PartyRole::ThirdPartyAllocationFirm => b"45",
+            PartyRole::ClaimingAccount => b"46",
+            PartyRole::AssetManager => b"47",
+            PartyRole::PledgorAccount => b"48",
+            PartyRole::PledgeeAccount => b"49",
+            PartyRole::LargeTraderReportableAccount => b"50",
+            PartyRole::TraderMnemonic => b"51",
+            PartyRole::SenderLocation => b"52",
+            PartyRole::SessionId => b"53",
+            PartyRole::AcceptableCounterparty => b"54",
+            PartyRole::UnacceptableCounterparty => b"55",
+            PartyRole::EnteringUnit => b"56",
+            PartyRole::ExecutingUnit => b"57",
+            PartyRole::IntroducingBroker => b"58",
+            PartyRole::QuoteOriginator => b"59",
+            PartyRole::ReportOriginator => b"60",
+            PartyRole::SystematicInternaliser => b"61",
+            PartyRole::MultilateralTradingFacility => b"62",
+            PartyRole::RegulatedMarket => b"63",
+            PartyRole::MarketMaker => b"64",
+            PartyRole::InvestmentFirm => b"65",
+            PartyRole::HostCompetentAuthority => b"66",
+            PartyRole::HomeCompetentAuthority => b"67",
+            PartyRole::CompetentAuthorityOfLiquidity => b"68",
+            PartyRole::CompetentAuthorityOfVenue => b"69",
+            PartyRole::ReportingIntermediary => b"70",
+            PartyRole::ExecutionVenue => b"71",
+            PartyRole::MarketDataEntryOriginator => b"72",
+            PartyRole::LocationId => b"73",
+            PartyRole::DeskId => b"74",
+            PartyRole::MarketDataMarket => b"75",
+            PartyRole::AllocationEntity => b"76",
+            PartyRole::PrimeBroker => b"77",
+            PartyRole::StepOutFirm => b"78",
+            PartyRole::BrokerClearingId => b"79",
+            PartyRole::CentralRegistrationDepository => b"80",
+            PartyRole::ClearingAccount => b"81",
+            PartyRole::AcceptableSettlingCounterparty => b"82",
+            PartyRole::UnacceptableSettlingCounterparty => b"83",
+            PartyRole::Other(_) => return None,
+        })
+    }
+}
+
+impl From<PartyRole> for Vec<u8> {
+    /// Converts a [`PartyRole`] variant into an **owned `Vec<u8>`**
+    /// containing its FIX wire representation (tag **452** value).
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::field::value::party_role::PartyRole;
+    ///
+    /// assert_eq!(Vec::from(PartyRole::ClientId), b"3");
+    /// assert_eq!(Vec::from(PartyRole::Other(4001)), b"4001");
+    /// ```
+    fn from(val: PartyRole) -> Self {
+        match val {
+            PartyRole::Other(value) => value.to_string().into_bytes(),
+            known => known.as_static_bytes().unwrap_or_default().to_vec(),
+        }
+    }
+}
+
+/// The error type for failed parsing of [`PartyRole`]
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ParseError<'input> {
+    /// Provided byte slice contains data that is not a valid or supported party role.
+    #[error("unsupported party role: {}", String::from_utf8_lossy(.0))]
+    Unsupported(&'input [u8]),
+}
+
+impl FromFixBytes for PartyRole {
+    type Error<'input> = ParseError<'input>;
+
+    const ENUMERATED: bool = true;
+
+    fn from_fix_bytes(bytes: &[u8]) -> Result<Self, Self::Error<'_>>
+    where
+        Self: Sized,
+    {
+        match bytes {
+            b"1" => Ok(PartyRole::ExecutingFirm),
+            b"2" => Ok(PartyRole::BrokerOfCredit),
+            b"3" => Ok(PartyRole::ClientId),
+            b"4" => Ok(PartyRole::ClearingFirm),
+            b"5" => Ok(PartyRole::InvestorId),
+            b"6" => Ok(PartyRole::IntroducingFirm),
+            b"7" => Ok(PartyRole::EnteringFirm),
+            b"8" => Ok(PartyRole::LocateLendingFirm),
+            b"9" => Ok(PartyRole::FundManagerClientId),
+            b"10" => Ok(PartyRole::SettlementLocation),
+            b"11" => Ok(PartyRole::OrderOriginationTrader),
+            b"12" => Ok(PartyRole::ExecutingTrader),
+            b"13" => Ok(PartyRole::OrderOriginationFirm),
+            b"14" => Ok(PartyRole::GiveUpClearingFirm),
+            b"15" => Ok(PartyRole::CorrespondentClearingFirm),
+            b"16" => Ok(PartyRole::ExecutingSystem),
+            b"17" => Ok(PartyRole::ContraFirm),
+            b"18" => Ok(PartyRole::ContraClearingFirm),
+            b"19" => Ok(PartyRole::SponsoringFirm),
+            b"20" => Ok(PartyRole::UnderlyingContraFirm),
+            b"21" => Ok(PartyRole::ClearingOrganization),
+            b"22" => Ok(PartyRole::Exchange),
+            b"24" => Ok(PartyRole::CustomerAccount),
+            b"25" => Ok(PartyRole::CorrespondentClearingOrganization),
+            b"26" => Ok(PartyRole::CorrespondentBroker),
+            b"27" => Ok(PartyRole::BuyerSeller),
+            b"28" => Ok(PartyRole::Custodian),
+            b"29" => Ok(PartyRole::Intermediary),
+            b"30" => Ok(PartyRole::Agent),
+            b"31" => Ok(PartyRole::SubCustodian),
+            b"32" => Ok(PartyRole::Beneficiary),
+            b"33" => Ok(PartyRole::InterestedParty),
+            b"34" => Ok(PartyRole::RegulatoryBody),
+            b"35" => Ok(PartyRole::LiquidityProvider),
+            b"36" => Ok(PartyRole::EnteringTrader),
+            b"37" => Ok(PartyRole::ContraTrader),
+            b"38" => Ok(PartyRole::PositionAccount),
+            b"39" => Ok(PartyRole::ContraPositionAccount),
+            b"40" => Ok(PartyRole::ContraExchange),
+            b"41" => Ok(PartyRole::InternalCarryAccount),
+            b"42" => Ok(PartyRole::OrderEntryOperatorId),
+            b"43" => Ok(PartyRole::SecondaryAccountNumber),
+            b"44" => Ok(PartyRole::ForeignFirm),
+            b"45" => Ok(PartyRole::ThirdPartyAllocationFirm),
+            b"46" => Ok(PartyRole::ClaimingAccount),
+            b"47" => Ok(PartyRole::AssetManager),
+            b"48" => Ok(PartyRole::PledgorAccount),
+            b"49" => Ok(PartyRole::PledgeeAccount),
+            b"50" => Ok(PartyRole::LargeTraderReportableAccount),
+            b"51" => Ok(PartyRole::TraderMnemonic),
+            b"52" => Ok(PartyRole::SenderLocation),
+            b"53" => Ok(PartyRole::SessionId),
+            b"54" => Ok(PartyRole::AcceptableCounterparty),
+            b"55" => Ok(PartyRole::UnacceptableCounterparty),
+            b"56" => Ok(PartyRole::EnteringUnit),
+            b"57" => Ok(PartyRole::ExecutingUnit),
+            b"58" => Ok(PartyRole::IntroducingBroker),
+            b"59" => Ok(PartyRole::QuoteOriginator),
+            b"60" => Ok(PartyRole::ReportOriginator),
+            b"61" => Ok(PartyRole::SystematicInternaliser),
+            b"62" => Ok(PartyRole::MultilateralTradingFacility),
+            b"63" => Ok(PartyRole::RegulatedMarket),
+            b"64" => Ok(PartyRole::MarketMaker),
+            b"65" => Ok(PartyRole::InvestmentFirm),
+            b"66" => Ok(PartyRole::HostCompetentAuthority),
+            b"67" => Ok(PartyRole::HomeCompetentAuthority),
+            b"68" => Ok(PartyRole::CompetentAuthorityOfLiquidity),
+            b"69" => Ok(PartyRole::CompetentAuthorityOfVenue),
+            b"70" => Ok(PartyRole::ReportingIntermediary),
+            b"71" => Ok(PartyRole::ExecutionVenue),
+            b"72" => Ok(PartyRole::MarketDataEntryOriginator),
+            b"73" => Ok(PartyRole::LocationId),
+            b"74" => Ok(PartyRole::DeskId),
+            b"75" => Ok(PartyRole::MarketDataMarket),
+            b"76" => Ok(PartyRole::AllocationEntity),
+            b"77" => Ok(PartyRole::PrimeBroker),
+            b"78" => Ok(PartyRole::StepOutFirm),
+            b"79" => Ok(PartyRole::BrokerClearingId),
+            b"80" => Ok(PartyRole::CentralRegistrationDepository),
+            b"81" => Ok(PartyRole::ClearingAccount),
+            b"82" => Ok(PartyRole::AcceptableSettlingCounterparty),
+            b"83" => Ok(PartyRole::UnacceptableSettlingCounterparty),
+            [b'1'..=b'9', ..] if bytes.iter().all(u8::is_ascii_digit) => std::str::from_utf8(bytes)
+                .ok()
+                .and_then(|value| value.parse().ok())
+                .map(PartyRole::Other)
+                .ok_or(ParseError::Unsupported(bytes)),
+            other => Err(ParseError::Unsupported(other)),
+        }
+    }
+}
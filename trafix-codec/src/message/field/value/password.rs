@@ -0,0 +1,79 @@
+//! Defines the [`Password`] type, representing the FIX **554 `Password`** field value.
+
+use std::convert::Infallible;
+use std::fmt;
+
+use crate::message::field::value::FromFixBytes;
+
+/// Represents the password (`554`) sent in a `Logon` message of an authenticated session.
+///
+/// The [`Debug`](fmt::Debug) representation is redacted, so that credentials do not leak into
+/// logs through debug prints of fields or messages. The value itself is available through
+/// [`Password::expose`].
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct Password(Vec<u8>);
+
+impl Password {
+    /// Returns the tag used for [`Password`].
+    #[must_use]
+    pub const fn tag() -> u32 {
+        554
+    }
+
+    /// Returns the raw bytes of the password.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::field::value::password::Password;
+    ///
+    /// let password = Password::from(b"secret");
+    /// assert_eq!(password.expose(), b"secret");
+    /// assert_eq!(format!("{password:?}"), "554=****");
+    /// ```
+    #[must_use]
+    pub fn expose(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl fmt::Debug for Password {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}=****", Self::tag())
+    }
+}
+
+impl From<Vec<u8>> for Password {
+    fn from(value: Vec<u8>) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&[u8]> for Password {
+    fn from(value: &[u8]) -> Self {
+        Self(value.to_vec())
+    }
+}
+
+impl<const N: usize> From<&[u8; N]> for Password {
+    fn from(value: &[u8; N]) -> Self {
+        Self(value.to_vec())
+    }
+}
+
+impl From<&Password> for Vec<u8> {
+    /// Converts a [`Password`] into an **owned `Vec<u8>`** containing its raw bytes.
+    fn from(val: &Password) -> Self {
+        val.0.clone()
+    }
+}
+
+impl FromFixBytes for Password {
+    type Error<'unused> = Infallible;
+
+    fn from_fix_bytes(bytes: &[u8]) -> Result<Self, Self::Error<'_>>
+    where
+        Self: Sized,
+    {
+        Ok(bytes.into())
+    }
+}
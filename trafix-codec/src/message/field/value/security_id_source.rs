@@ -0,0 +1,181 @@
+//! Defines the [`SecurityIDSource`] enumeration representing the FIX
+//! **22 `SecurityIDSource`** field value.
+
+use crate::message::field::value::FromFixBytes;
+
+/// Represents the source (`22`) of a `SecurityID`, i.e. the identification scheme it belongs to.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SecurityIDSource {
+    /// CUSIP (`22=1`).
+    Cusip,
+
+    /// SEDOL (`22=2`).
+    Sedol,
+
+    /// QUIK (`22=3`).
+    Quik,
+
+    /// ISIN (`22=4`).
+    Isin,
+
+    /// RIC code (`22=5`).
+    Ric,
+
+    /// ISO currency code (`22=6`).
+    IsoCurrencyCode,
+
+    /// ISO country code (`22=7`).
+    IsoCountryCode,
+
+    /// Exchange symbol (`22=8`).
+    ExchangeSymbol,
+
+    /// Consolidated Tape Association (CTA) symbol (`22=9`).
+    ConsolidatedTapeAssociation,
+
+    /// Bloomberg symbol (`22=A`).
+    BloombergSymbol,
+
+    /// Wertpapier (`22=B`).
+    Wertpapier,
+
+    /// Dutch (`22=C`).
+    Dutch,
+
+    /// Valoren (`22=D`).
+    Valoren,
+
+    /// Sicovam (`22=E`).
+    Sicovam,
+
+    /// Belgian (`22=F`).
+    Belgian,
+
+    /// Common, i.e. Clearstream and Euroclear (`22=G`).
+    Common,
+
+    /// Clearing house or clearing organization (`22=H`).
+    ClearingHouse,
+
+    /// ISDA/FpML product specification (`22=I`).
+    IsdaFpml,
+
+    /// Option Price Reporting Authority (`22=J`).
+    OptionPriceReportingAuthority,
+
+    /// Private identification scheme from the user-defined range, i.e. `100` and above
+    /// (e.g. `22=100`).
+    UserDefined(u32),
+}
+
+impl SecurityIDSource {
+    /// Returns the tag used for [`SecurityIDSource`].
+    #[must_use]
+    pub const fn tag() -> u32 {
+        22
+    }
+
+    /// Returns the **static byte slice** representation of this [`SecurityIDSource`], or `None`
+    /// for [`SecurityIDSource::UserDefined`], whose value has to be formatted.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::field::value::security_id_source::SecurityIDSource;
+    ///
+    /// assert_eq!(SecurityIDSource::Isin.as_static_bytes(), Some(b"4".as_slice()));
+    /// assert_eq!(SecurityIDSource::UserDefined(100).as_static_bytes(), None);
+    /// ```
+    #[must_use]
+    pub const fn as_static_bytes(&self) -> Option<&'static [u8]> {
+        Some(match self {
+            SecurityIDSource::Cusip => b"1",
+            SecurityIDSource::Sedol => b"2",
+            SecurityIDSource::Quik => b"3",
+            SecurityIDSource::Isin => b"4",
+            SecurityIDSource::Ric => b"5",
+            SecurityIDSource::IsoCurrencyCode => b"6",
+            SecurityIDSource::IsoCountryCode => b"7",
+            SecurityIDSource::ExchangeSymbol => b"8",
+            SecurityIDSource::ConsolidatedTapeAssociation => b"9",
+            SecurityIDSource::BloombergSymbol => b"A",
+            SecurityIDSource::Wertpapier => b"B",
+            SecurityIDSource::Dutch => b"C",
+            SecurityIDSource::Valoren => b"D",
+            SecurityIDSource::Sicovam => b"E",
+            SecurityIDSource::Belgian => b"F",
+            SecurityIDSource::Common => b"G",
+            SecurityIDSource::ClearingHouse => b"H",
+            SecurityIDSource::IsdaFpml => b"I",
+            SecurityIDSource::OptionPriceReportingAuthority => b"J",
+            SecurityIDSource::UserDefined(_) => return None,
+        })
+    }
+}
+
+impl From<SecurityIDSource> for Vec<u8> {
+    /// Converts a [`SecurityIDSource`] variant into an **owned `Vec<u8>`**
+    /// containing its FIX wire representation (tag **22** value).
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::field::value::security_id_source::SecurityIDSource;
+    ///
+    /// assert_eq!(Vec::from(SecurityIDSource::Isin), b"4");
+    /// assert_eq!(Vec::from(SecurityIDSource::UserDefined(101)), b"101");
+    /// ```
+    fn from(val: SecurityIDSource) -> Self {
+        match val {
+            SecurityIDSource::UserDefined(value) => value.to_string().into_bytes(),
+            known => known.as_static_bytes().unwrap_or_default().to_vec(),
+        }
+    }
+}
+
+/// The error type for failed parsing of [`SecurityIDSource`]
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ParseError<'input> {
+    /// Provided byte slice contains data that is not a valid or supported security ID source.
+    #[error("unsupported security ID source: {}", String::from_utf8_lossy(.0))]
+    Unsupported(&'input [u8]),
+}
+
+impl FromFixBytes for SecurityIDSource {
+    type Error<'input> = ParseError<'input>;
+
+    const ENUMERATED: bool = true;
+
+    fn from_fix_bytes(bytes: &[u8]) -> Result<Self, Self::Error<'_>>
+    where
+        Self: Sized,
+    {
+        match bytes {
+            b"1" => Ok(SecurityIDSource::Cusip),
+            b"2" => Ok(SecurityIDSource::Sedol),
+            b"3" => Ok(SecurityIDSource::Quik),
+            b"4" => Ok(SecurityIDSource::Isin),
+            b"5" => Ok(SecurityIDSource::Ric),
+            b"6" => Ok(SecurityIDSource::IsoCurrencyCode),
+            b"7" => Ok(SecurityIDSource::IsoCountryCode),
+            b"8" => Ok(SecurityIDSource::ExchangeSymbol),
+            b"9" => Ok(SecurityIDSource::ConsolidatedTapeAssociation),
+            b"A" => Ok(SecurityIDSource::BloombergSymbol),
+            b"B" => Ok(SecurityIDSource::Wertpapier),
+            b"C" => Ok(SecurityIDSource::Dutch),
+            b"D" => Ok(SecurityIDSource::Valoren),
+            b"E" => Ok(SecurityIDSource::Sicovam),
+            b"F" => Ok(SecurityIDSource::Belgian),
+            b"G" => Ok(SecurityIDSource::Common),
+            b"H" => Ok(SecurityIDSource::ClearingHouse),
+            b"I" => Ok(SecurityIDSource::IsdaFpml),
+            b"J" => Ok(SecurityIDSource::OptionPriceReportingAuthority),
+            [b'1'..=b'9', b'0'..=b'9', b'0'..=b'9', ..] if bytes.iter().all(u8::is_ascii_digit) => {
+                std::str::from_utf8(bytes)
+                    .ok()
+                    .and_then(|value| value.parse().ok())
+                    .map(SecurityIDSource::UserDefined)
+                    .ok_or(ParseError::Unsupported(bytes))
+            }
+            other => Err(ParseError::Unsupported(other)),
+        }
+    }
+}
@@ -0,0 +1,570 @@
+//! Defines the [`SecurityType`] enumeration representing the FIX
+//! **167 `SecurityType`** field value.
+
+use crate::message::field::value::FromFixBytes;
+
+/// Represents the type (`167`) of a security, e.g. a common stock or a future.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SecurityType {
+    /// Common stock (`167=CS`).
+    CommonStock,
+
+    /// Preferred stock (`167=PS`).
+    PreferredStock,
+
+    /// Future (`167=FUT`).
+    Future,
+
+    /// Option (`167=OPT`).
+    Option,
+
+    /// Foreign exchange contract (`167=FOR`).
+    ForeignExchangeContract,
+
+    /// Corporate bond (`167=CORP`).
+    CorporateBond,
+
+    /// Municipal bond (`167=MUNI`).
+    MunicipalBond,
+
+    /// US Treasury bill (`167=TBILL`).
+    TreasuryBill,
+
+    /// US Treasury bond (`167=TBOND`).
+    TreasuryBond,
+
+    /// Mutual fund (`167=MF`).
+    MutualFund,
+
+    /// Warrant (`167=WAR`).
+    Warrant,
+
+    /// Repurchase agreement (`167=REPO`).
+    Repurchase,
+
+    /// Multileg instrument (`167=MLEG`).
+    MultiLeg,
+
+    /// Wildcard entry, e.g. in a `SecurityDefinitionRequest` (`167=?`).
+    NoSecurityType,
+
+    /// Euro supranational coupons (`167=EUSUPRA`).
+    EuroSupranationalCoupons,
+
+    /// Federal agency coupon (`167=FAC`).
+    FederalAgencyCoupon,
+
+    /// Federal agency discount note (`167=FADN`).
+    FederalAgencyDiscountNote,
+
+    /// Private export funding (`167=PEF`).
+    PrivateExportFunding,
+
+    /// USD supranational coupons (`167=SUPRA`).
+    UsdSupranationalCoupons,
+
+    /// FX spot (`167=FXSPOT`).
+    FxSpot,
+
+    /// FX forward (`167=FXFWD`).
+    FxForward,
+
+    /// FX swap (`167=FXSWAP`).
+    FxSwap,
+
+    /// FX non-deliverable forward (`167=FXNDF`).
+    FxNonDeliverableForward,
+
+    /// Corporate private placement (`167=CPP`).
+    CorporatePrivatePlacement,
+
+    /// Convertible bond (`167=CB`).
+    ConvertibleBond,
+
+    /// Dual currency (`167=DUAL`).
+    DualCurrency,
+
+    /// Euro corporate bond (`167=EUCORP`).
+    EuroCorporateBond,
+
+    /// Indexed linked (`167=XLINKD`).
+    IndexedLinked,
+
+    /// Structured notes (`167=STRUCT`).
+    StructuredNotes,
+
+    /// Yankee corporate bond (`167=YANK`).
+    YankeeCorporateBond,
+
+    /// Brady bond (`167=BRADY`).
+    BradyBond,
+
+    /// Euro sovereigns (`167=EUSOV`).
+    EuroSovereigns,
+
+    /// Interest strip from any bond or note (`167=TINT`).
+    TreasuryInterestStrip,
+
+    /// Treasury inflation protected securities (`167=TIPS`).
+    TreasuryInflationProtected,
+
+    /// Principal strip of a callable bond or note (`167=TCAL`).
+    TreasuryPrincipalStripCallable,
+
+    /// Principal strip from a non-callable bond or note (`167=TPRN`).
+    TreasuryPrincipalStripNonCallable,
+
+    /// US Treasury note, deprecated in favour of `TNOTE` (`167=UST`).
+    TreasuryNoteDeprecated,
+
+    /// US Treasury bill, deprecated in favour of `TBILL` (`167=USTB`).
+    TreasuryBillDeprecated,
+
+    /// US Treasury note (`167=TNOTE`).
+    TreasuryNote,
+
+    /// Term loan (`167=TERM`).
+    TermLoan,
+
+    /// Revolver loan (`167=RVLV`).
+    RevolverLoan,
+
+    /// Revolver/term loan (`167=RVLVTRM`).
+    RevolverTermLoan,
+
+    /// Bridge loan (`167=BRIDGE`).
+    BridgeLoan,
+
+    /// Letter of credit (`167=LOFC`).
+    LetterOfCredit,
+
+    /// Swing line facility (`167=SWING`).
+    SwingLineFacility,
+
+    /// Debtor in possession (`167=DINP`).
+    DebtorInPossession,
+
+    /// Defaulted (`167=DEFLTED`).
+    Defaulted,
+
+    /// Withdrawn (`167=WITHDRN`).
+    Withdrawn,
+
+    /// Replaced (`167=REPLACD`).
+    Replaced,
+
+    /// Matured (`167=MATURED`).
+    Matured,
+
+    /// Amended and restated (`167=AMENDED`).
+    AmendedAndRestated,
+
+    /// Retired (`167=RETIRED`).
+    Retired,
+
+    /// Bankers acceptance (`167=BA`).
+    BankersAcceptance,
+
+    /// Bank notes (`167=BN`).
+    BankNotes,
+
+    /// Bill of exchanges (`167=BOX`).
+    BillOfExchanges,
+
+    /// Certificate of deposit (`167=CD`).
+    CertificateOfDeposit,
+
+    /// Call loans (`167=CL`).
+    CallLoans,
+
+    /// Commercial paper (`167=CP`).
+    CommercialPaper,
+
+    /// Deposit notes (`167=DN`).
+    DepositNotes,
+
+    /// Euro certificate of deposit (`167=EUCD`).
+    EuroCertificateOfDeposit,
+
+    /// Euro commercial paper (`167=EUCP`).
+    EuroCommercialPaper,
+
+    /// Liquidity note (`167=LQN`).
+    LiquidityNote,
+
+    /// Medium term notes (`167=MTN`).
+    MediumTermNotes,
+
+    /// Overnight (`167=ONITE`).
+    Overnight,
+
+    /// Promissory note (`167=PN`).
+    PromissoryNote,
+
+    /// Plazos fijos (`167=PZFJ`).
+    PlazosFijos,
+
+    /// Forward (`167=FORWARD`).
+    Forward,
+
+    /// Buy sellback (`167=BUYSELL`).
+    BuySellback,
+
+    /// Securities loan (`167=SECLOAN`).
+    SecuritiesLoan,
+
+    /// Securities pledge (`167=SECPLEDGE`).
+    SecuritiesPledge,
+
+    /// Short term loan note (`167=STN`).
+    ShortTermLoanNote,
+
+    /// Time deposit (`167=TD`).
+    TimeDeposit,
+
+    /// Extended comm note (`167=XCN`).
+    ExtendedCommNote,
+
+    /// Yankee certificate of deposit (`167=YCD`).
+    YankeeCertificateOfDeposit,
+
+    /// Asset-backed securities (`167=ABS`).
+    AssetBackedSecurities,
+
+    /// Corporate mortgage-backed securities (`167=CMBS`).
+    CorporateMortgageBackedSecurities,
+
+    /// Collateralized mortgage obligation (`167=CMO`).
+    CollateralizedMortgageObligation,
+
+    /// IOETTE mortgage (`167=IET`).
+    IoetteMortgage,
+
+    /// Mortgage-backed securities (`167=MBS`).
+    MortgageBackedSecurities,
+
+    /// Mortgage interest only (`167=MIO`).
+    MortgageInterestOnly,
+
+    /// Mortgage principal only (`167=MPO`).
+    MortgagePrincipalOnly,
+
+    /// Mortgage private placement (`167=MPP`).
+    MortgagePrivatePlacement,
+
+    /// Miscellaneous pass-through (`167=MPT`).
+    MiscellaneousPassThrough,
+
+    /// Pfandbriefe (`167=PFAND`).
+    Pfandbriefe,
+
+    /// To be announced (`167=TBA`).
+    ToBeAnnounced,
+
+    /// Other anticipation notes, e.g. BAN or GAN (`167=AN`).
+    OtherAnticipationNotes,
+
+    /// Certificate of obligation (`167=COFO`).
+    CertificateOfObligation,
+
+    /// Certificate of participation (`167=COFP`).
+    CertificateOfParticipation,
+
+    /// General obligation bonds (`167=GO`).
+    GeneralObligationBonds,
+
+    /// Mandatory tender (`167=MT`).
+    MandatoryTender,
+
+    /// Revenue anticipation note (`167=RAN`).
+    RevenueAnticipationNote,
+
+    /// Revenue bonds (`167=REV`).
+    RevenueBonds,
+
+    /// Special assessment (`167=SPCLA`).
+    SpecialAssessment,
+
+    /// Special obligation (`167=SPCLO`).
+    SpecialObligation,
+
+    /// Special tax (`167=SPCLT`).
+    SpecialTax,
+
+    /// Tax anticipation note (`167=TAN`).
+    TaxAnticipationNote,
+
+    /// Tax allocation (`167=TAXA`).
+    TaxAllocation,
+
+    /// Tax exempt commercial paper (`167=TECP`).
+    TaxExemptCommercialPaper,
+
+    /// Tax and revenue anticipation note (`167=TRAN`).
+    TaxAndRevenueAnticipationNote,
+
+    /// Variable rate demand note (`167=VRDN`).
+    VariableRateDemandNote,
+
+    /// No security type, as opposed to the wildcard `?` (`167=NONE`).
+    NoneGiven,
+}
+
+impl SecurityType {
+    /// Returns the tag used for [`SecurityType`].
+    #[must_use]
+    pub const fn tag() -> u32 {
+        167
+    }
+}
+
+impl From<SecurityType> for &'static [u8] {
+    /// Converts a [`SecurityType`] variant into its **static byte slice**
+    /// representation, corresponding to the FIX wire value of tag **167**.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::field::value::security_type::SecurityType;
+    /// let bytes: &'static [u8] = SecurityType::Future.into();
+    /// assert_eq!(bytes, b"FUT");
+    /// ```
+    #[allow(clippy::too_many_lines)]
+    fn from(val: SecurityType) -> Self {
+        match val {
+            SecurityType::CommonStock => b"CS",
+            SecurityType::PreferredStock => b"PS",
+            SecurityType::Future => b"FUT",
+            SecurityType::Option => b"OPT",
+            SecurityType::ForeignExchangeContract => b"FOR",
+            SecurityType::CorporateBond => b"CORP",
+            SecurityType::MunicipalBond => b"MUNI",
+            SecurityType::TreasuryBill => b"TBILL",
+            SecurityType::TreasuryBond => b"TBOND",
+            SecurityType::MutualFund => b"MF",
+            SecurityType::Warrant => b"WAR",
+            SecurityType::Repurchase => b"REPO",
+            SecurityType::MultiLeg => b"MLEG",
+            SecurityType::NoSecurityType => b"?",
+            SecurityType::EuroSupranationalCoupons => b"EUSUPRA",
+            SecurityType::FederalAgencyCoupon => b"FAC",
+            SecurityType::FederalAgencyDiscountNote => b"FADN",
+            SecurityType::PrivateExportFunding => b"PEF",
+            SecurityType::UsdSupranationalCoupons => b"SUPRA",
+            SecurityType::FxSpot => b"FXSPOT",
+            SecurityType::FxForward => b"FXFWD",
+            SecurityType::FxSwap => b"FXSWAP",
+            SecurityType::FxNonDeliverableForward => b"FXNDF",
+            SecurityType::CorporatePrivatePlacement => b"CPP",
+            SecurityType::ConvertibleBond => b"CB",
+            SecurityType::DualCurrency => b"DUAL",
+            SecurityType::EuroCorporateBond => b"EUCORP",
+            SecurityType::IndexedLinked => b"XLINKD",
+            SecurityType::StructuredNotes => b"STRUCT",
+            SecurityType::YankeeCorporateBond => b"YANK",
+            SecurityType::BradyBond => b"BRADY",
+            SecurityType::EuroSovereigns => b"EUSOV",
+            SecurityType::TreasuryInterestStrip => b"TINT",
+            SecurityType::TreasuryInflationProtected => b"TIPS",
+            SecurityType::TreasuryPrincipalStripCallable => b"TCAL",
+            SecurityType::TreasuryPrincipalStripNonCallable => b"TPRN",
+            SecurityType::TreasuryNoteDeprecated => b"UST",
+            SecurityType::TreasuryBillDeprecated => b"USTB",
+            SecurityType::TreasuryNote => b"TNOTE",
+            SecurityType::TermLoan => b"TERM",
+            SecurityType::RevolverLoan => b"RVLV",
+            SecurityType::RevolverTermLoan => b"RVLVTRM",
+            SecurityType::BridgeLoan => b"BRIDGE",
+            SecurityType::LetterOfCredit => b"LOFC",
+            SecurityType::SwingLineFacility => b"SWING",
+            SecurityType::DebtorInPossession => b"DINP",
+            SecurityType::Defaulted => b"DEFLTED",
+            SecurityType::Withdrawn => b"WITHDRN",
+            SecurityType::Replaced => b"REPLACD",
+            SecurityType::Matured => b"MATURED",
+            SecurityType::AmendedAndRestated => b"AMENDED",
+            SecurityType::Retired => b"RETIRED",
+            SecurityType::BankersAcceptance => b"BA",
+            SecurityType::BankNotes => b"BN",
+            SecurityType::BillOfExchanges => b"BOX",
+            SecurityType::CertificateOfDeposit => b"CD",
+            SecurityType::CallLoans => b"CL",
+            SecurityType::CommercialPaper => b"CP",
+            SecurityType::DepositNotes => b"DN",
+            SecurityType::EuroCertificateOfDeposit => b"EUCD",
+            SecurityType::EuroCommercialPaper => b"EUCP",
+            SecurityType::LiquidityNote => b"LQN",
+            SecurityType::MediumTermNotes => b"MTN",
+            SecurityType::Overnight => b"ONITE",
+            SecurityType::PromissoryNote => b"PN",
+            SecurityType::PlazosFijos => b"PZFJ",
+            SecurityType::Forward => b"FORWARD",
+            SecurityType::BuySellback => b"BUYSELL",
+            SecurityType::SecuritiesLoan => b"SECLOAN",
+            SecurityType::SecuritiesPledge => b"SECPLEDGE",
+            SecurityType::ShortTermLoanNote => b"STN",
+            SecurityType::TimeDeposit => b"TD",
+            SecurityType::ExtendedCommNote => b"XCN",
+            SecurityType::YankeeCertificateOfDeposit => b"YCD",
+            SecurityType::AssetBackedSecurities => b"ABS",
+            SecurityType::CorporateMortgageBackedSecurities => b"CMBS",
+            SecurityType::CollateralizedMortgageObligation => b"CMO",
+            SecurityType::IoetteMortgage => b"IET",
+            SecurityType::MortgageBackedSecurities => b"MBS",
+            SecurityType::MortgageInterestOnly => b"MIO",
+            SecurityType::MortgagePrincipalOnly => b"MPO",
+            SecurityType::MortgagePrivatePlacement => b"MPP",
+            SecurityType::MiscellaneousPassThrough => b"MPT",
+            SecurityType::Pfandbriefe => b"PFAND",
+            SecurityType::ToBeAnnounced => b"TBA",
+            SecurityType::OtherAnticipationNotes => b"AN",
+            SecurityType::CertificateOfObligation => b"COFO",
+            SecurityType::CertificateOfParticipation => b"COFP",
+            SecurityType::GeneralObligationBonds => b"GO",
+            SecurityType::MandatoryTender => b"MT",
+            SecurityType::RevenueAnticipationNote => b"RAN",
+            SecurityType::RevenueBonds => b"REV",
+            SecurityType::SpecialAssessment => b"SPCLA",
+            SecurityType::SpecialObligation => b"SPCLO",
+            SecurityType::SpecialTax => b"SPCLT",
+            SecurityType::TaxAnticipationNote => b"TAN",
+            SecurityType::TaxAllocation => b"TAXA",
+            SecurityType::TaxExemptCommercialPaper => b"TECP",
+            SecurityType::TaxAndRevenueAnticipationNote => b"TRAN",
+            SecurityType::VariableRateDemandNote => b"VRDN",
+            SecurityType::NoneGiven => b"NONE",
+        }
+    }
+}
+
+impl From<SecurityType> for Vec<u8> {
+    /// Converts a [`SecurityType`] variant into an **owned `Vec<u8>`**
+    /// containing its FIX wire representation (tag **167** value).
+    fn from(val: SecurityType) -> Self {
+        <&[u8]>::from(val).to_vec()
+    }
+}
+
+/// The error type for failed parsing of [`SecurityType`]
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ParseError<'input> {
+    /// Provided byte slice contains data that is not a valid or supported security type.
+    #[error("unsupported security type: {}", String::from_utf8_lossy(.0))]
+    Unsupported(&'input [u8]),
+}
+
+impl FromFixBytes for SecurityType {
+    type Error<'input> = ParseError<'input>;
+
+    const ENUMERATED: bool = true;
+
+    #[allow(clippy::too_many_lines)]
+    fn from_fix_bytes(bytes: &[u8]) -> Result<Self, Self::Error<'_>>
+    where
+        Self: Sized,
+    {
+        match bytes {
+            b"CS" => Ok(SecurityType::CommonStock),
+            b"PS" => Ok(SecurityType::PreferredStock),
+            b"FUT" => Ok(SecurityType::Future),
+            b"OPT" => Ok(SecurityType::Option),
+            b"FOR" => Ok(SecurityType::ForeignExchangeContract),
+            b"CORP" => Ok(SecurityType::CorporateBond),
+            b"MUNI" => Ok(SecurityType::MunicipalBond),
+            b"TBILL" => Ok(SecurityType::TreasuryBill),
+            b"TBOND" => Ok(SecurityType::TreasuryBond),
+            b"MF" => Ok(SecurityType::MutualFund),
+            b"WAR" => Ok(SecurityType::Warrant),
+            b"REPO" => Ok(SecurityType::Repurchase),
+            b"MLEG" => Ok(SecurityType::MultiLeg),
+            b"?" => Ok(SecurityType::NoSecurityType),
+            b"EUSUPRA" => Ok(SecurityType::EuroSupranationalCoupons),
+            b"FAC" => Ok(SecurityType::FederalAgencyCoupon),
+            b"FADN" => Ok(SecurityType::FederalAgencyDiscountNote),
+            b"PEF" => Ok(SecurityType::PrivateExportFunding),
+            b"SUPRA" => Ok(SecurityType::UsdSupranationalCoupons),
+            b"FXSPOT" => Ok(SecurityType::FxSpot),
+            b"FXFWD" => Ok(SecurityType::FxForward),
+            b"FXSWAP" => Ok(SecurityType::FxSwap),
+            b"FXNDF" => Ok(SecurityType::FxNonDeliverableForward),
+            b"CPP" => Ok(SecurityType::CorporatePrivatePlacement),
+            b"CB" => Ok(SecurityType::ConvertibleBond),
+            b"DUAL" => Ok(SecurityType::DualCurrency),
+            b"EUCORP" => Ok(SecurityType::EuroCorporateBond),
+            b"XLINKD" => Ok(SecurityType::IndexedLinked),
+            b"STRUCT" => Ok(SecurityType::StructuredNotes),
+            b"YANK" => Ok(SecurityType::YankeeCorporateBond),
+            b"BRADY" => Ok(SecurityType::BradyBond),
+            b"EUSOV" => Ok(SecurityType::EuroSovereigns),
+            b"TINT" => Ok(SecurityType::TreasuryInterestStrip),
+            b"TIPS" => Ok(SecurityType::TreasuryInflationProtected),
+            b"TCAL" => Ok(SecurityType::TreasuryPrincipalStripCallable),
+            b"TPRN" => Ok(SecurityType::TreasuryPrincipalStripNonCallable),
+            b"UST" => Ok(SecurityType::TreasuryNoteDeprecated),
+            b"USTB" => Ok(SecurityType::TreasuryBillDeprecated),
+            b"TNOTE" => Ok(SecurityType::TreasuryNote),
+            b"TERM" => Ok(SecurityType::TermLoan),
+            b"RVLV" => Ok(SecurityType::RevolverLoan),
+            b"RVLVTRM" => Ok(SecurityType::RevolverTermLoan),
+            b"BRIDGE" => Ok(SecurityType::BridgeLoan),
+            b"LOFC" => Ok(SecurityType::LetterOfCredit),
+            b"SWING" => Ok(SecurityType::SwingLineFacility),
+            b"DINP" => Ok(SecurityType::DebtorInPossession),
+            b"DEFLTED" => Ok(SecurityType::Defaulted),
+            b"WITHDRN" => Ok(SecurityType::Withdrawn),
+            b"REPLACD" => Ok(SecurityType::Replaced),
+            b"MATURED" => Ok(SecurityType::Matured),
+            b"AMENDED" => Ok(SecurityType::AmendedAndRestated),
+            b"RETIRED" => Ok(SecurityType::Retired),
+            b"BA" => Ok(SecurityType::BankersAcceptance),
+            b"BN" => Ok(SecurityType::BankNotes),
+            b"BOX" => Ok(SecurityType::BillOfExchanges),
+            b"CD" => Ok(SecurityType::CertificateOfDeposit),
+            b"CL" => Ok(SecurityType::CallLoans),
+            b"CP" => Ok(SecurityType::CommercialPaper),
+            b"DN" => Ok(SecurityType::DepositNotes),
+            b"EUCD" => Ok(SecurityType::EuroCertificateOfDeposit),
+            b"EUCP" => Ok(SecurityType::EuroCommercialPaper),
+            b"LQN" => Ok(SecurityType::LiquidityNote),
+            b"MTN" => Ok(SecurityType::MediumTermNotes),
+            b"ONITE" => Ok(SecurityType::Overnight),
+            b"PN" => Ok(SecurityType::PromissoryNote),
+            b"PZFJ" => Ok(SecurityType::PlazosFijos),
+            b"FORWARD" => Ok(SecurityType::Forward),
+            b"BUYSELL" => Ok(SecurityType::BuySellback),
+            b"SECLOAN" => Ok(SecurityType::SecuritiesLoan),
+            b"SECPLEDGE" => Ok(SecurityType::SecuritiesPledge),
+            b"STN" => Ok(SecurityType::ShortTermLoanNote),
+            b"TD" => Ok(SecurityType::TimeDeposit),
+            b"XCN" => Ok(SecurityType::ExtendedCommNote),
+            b"YCD" => Ok(SecurityType::YankeeCertificateOfDeposit),
+            b"ABS" => Ok(SecurityType::AssetBackedSecurities),
+            b"CMBS" => Ok(SecurityType::CorporateMortgageBackedSecurities),
+            b"CMO" => Ok(SecurityType::CollateralizedMortgageObligation),
+            b"IET" => Ok(SecurityType::IoetteMortgage),
+            b"MBS" => Ok(SecurityType::MortgageBackedSecurities),
+            b"MIO" => Ok(SecurityType::MortgageInterestOnly),
+            b"MPO" => Ok(SecurityType::MortgagePrincipalOnly),
+            b"MPP" => Ok(SecurityType::MortgagePrivatePlacement),
+            b"MPT" => Ok(SecurityType::MiscellaneousPassThrough),
+            b"PFAND" => Ok(SecurityType::Pfandbriefe),
+            b"TBA" => Ok(SecurityType::ToBeAnnounced),
+            b"AN" => Ok(SecurityType::OtherAnticipationNotes),
+            b"COFO" => Ok(SecurityType::CertificateOfObligation),
+            b"COFP" => Ok(SecurityType::CertificateOfParticipation),
+            b"GO" => Ok(SecurityType::GeneralObligationBonds),
+            b"MT" => Ok(SecurityType::MandatoryTender),
+            b"RAN" => Ok(SecurityType::RevenueAnticipationNote),
+            b"REV" => Ok(SecurityType::RevenueBonds),
+            b"SPCLA" => Ok(SecurityType::SpecialAssessment),
+            b"SPCLO" => Ok(SecurityType::SpecialObligation),
+            b"SPCLT" => Ok(SecurityType::SpecialTax),
+            b"TAN" => Ok(SecurityType::TaxAnticipationNote),
+            b"TAXA" => Ok(SecurityType::TaxAllocation),
+            b"TECP" => Ok(SecurityType::TaxExemptCommercialPaper),
+            b"TRAN" => Ok(SecurityType::TaxAndRevenueAnticipationNote),
+            b"VRDN" => Ok(SecurityType::VariableRateDemandNote),
+            b"NONE" => Ok(SecurityType::NoneGiven),
+            other => Err(ParseError::Unsupported(other)),
+        }
+    }
+}
@@ -0,0 +1,158 @@
+//! Defines the [`SessionRejectReason`] enumeration representing the FIX
+//! **373 `SessionRejectReason`** field value.
+
+use crate::message::field::value::FromFixBytes;
+
+/// Represents the reason (`373`) a session-level `Reject` was sent.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SessionRejectReason {
+    /// Invalid tag number (`373=0`).
+    InvalidTagNumber,
+
+    /// Required tag missing (`373=1`).
+    RequiredTagMissing,
+
+    /// Tag not defined for this message type (`373=2`).
+    TagNotDefinedForMessageType,
+
+    /// Undefined tag (`373=3`).
+    UndefinedTag,
+
+    /// Tag specified without a value (`373=4`).
+    TagWithoutValue,
+
+    /// Value is incorrect, i.e. out of range, for this tag (`373=5`).
+    ValueIncorrect,
+
+    /// Incorrect data format for value (`373=6`).
+    IncorrectDataFormat,
+
+    /// Decryption problem (`373=7`).
+    DecryptionProblem,
+
+    /// Signature problem (`373=8`).
+    SignatureProblem,
+
+    /// `CompID` problem (`373=9`).
+    CompIdProblem,
+
+    /// `SendingTime` accuracy problem (`373=10`).
+    SendingTimeAccuracyProblem,
+
+    /// Invalid `MsgType` (`373=11`).
+    InvalidMsgType,
+
+    /// XML validation error (`373=12`).
+    XmlValidationError,
+
+    /// Tag appears more than once (`373=13`).
+    TagAppearsMoreThanOnce,
+
+    /// Tag specified out of required order (`373=14`).
+    TagOutOfOrder,
+
+    /// Repeating group fields out of order (`373=15`).
+    RepeatingGroupOutOfOrder,
+
+    /// Incorrect `NumInGroup` count for repeating group (`373=16`).
+    IncorrectNumInGroup,
+
+    /// Non-data value includes field delimiter, i.e. the SOH character (`373=17`).
+    NonDataValueIncludesDelimiter,
+
+    /// Other reason (`373=99`).
+    Other,
+}
+
+impl SessionRejectReason {
+    /// Returns the tag used for [`SessionRejectReason`].
+    #[must_use]
+    pub const fn tag() -> u32 {
+        373
+    }
+}
+
+impl From<SessionRejectReason> for &'static [u8] {
+    /// Converts a [`SessionRejectReason`] variant into its **static byte slice**
+    /// representation, corresponding to the FIX wire value of tag **373**.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::field::value::session_reject_reason::SessionRejectReason;
+    /// let bytes: &'static [u8] = SessionRejectReason::RequiredTagMissing.into();
+    /// assert_eq!(bytes, b"1");
+    /// ```
+    fn from(val: SessionRejectReason) -> Self {
+        match val {
+            SessionRejectReason::InvalidTagNumber => b"0",
+            SessionRejectReason::RequiredTagMissing => b"1",
+            SessionRejectReason::TagNotDefinedForMessageType => b"2",
+            SessionRejectReason::UndefinedTag => b"3",
+            SessionRejectReason::TagWithoutValue => b"4",
+            SessionRejectReason::ValueIncorrect => b"5",
+            SessionRejectReason::IncorrectDataFormat => b"6",
+            SessionRejectReason::DecryptionProblem => b"7",
+            SessionRejectReason::SignatureProblem => b"8",
+            SessionRejectReason::CompIdProblem => b"9",
+            SessionRejectReason::SendingTimeAccuracyProblem => b"10",
+            SessionRejectReason::InvalidMsgType => b"11",
+            SessionRejectReason::XmlValidationError => b"12",
+            SessionRejectReason::TagAppearsMoreThanOnce => b"13",
+            SessionRejectReason::TagOutOfOrder => b"14",
+            SessionRejectReason::RepeatingGroupOutOfOrder => b"15",
+            SessionRejectReason::IncorrectNumInGroup => b"16",
+            SessionRejectReason::NonDataValueIncludesDelimiter => b"17",
+            SessionRejectReason::Other => b"99",
+        }
+    }
+}
+
+impl From<SessionRejectReason> for Vec<u8> {
+    /// Converts a [`SessionRejectReason`] variant into an **owned `Vec<u8>`**
+    /// containing its FIX wire representation (tag **373** value).
+    fn from(val: SessionRejectReason) -> Self {
+        <&[u8]>::from(val).to_vec()
+    }
+}
+
+/// The error type for failed parsing of [`SessionRejectReason`]
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ParseError<'input> {
+    /// Provided byte slice contains data that is not a valid or supported session reject reason.
+    #[error("unsupported session reject reason: {}", String::from_utf8_lossy(.0))]
+    Unsupported(&'input [u8]),
+}
+
+impl FromFixBytes for SessionRejectReason {
+    type Error<'input> = ParseError<'input>;
+
+    const ENUMERATED: bool = true;
+
+    fn from_fix_bytes(bytes: &[u8]) -> Result<Self, Self::Error<'_>>
+    where
+        Self: Sized,
+    {
+        match bytes {
+            b"0" => Ok(SessionRejectReason::InvalidTagNumber),
+            b"1" => Ok(SessionRejectReason::RequiredTagMissing),
+            b"2" => Ok(SessionRejectReason::TagNotDefinedForMessageType),
+            b"3" => Ok(SessionRejectReason::UndefinedTag),
+            b"4" => Ok(SessionRejectReason::TagWithoutValue),
+            b"5" => Ok(SessionRejectReason::ValueIncorrect),
+            b"6" => Ok(SessionRejectReason::IncorrectDataFormat),
+            b"7" => Ok(SessionRejectReason::DecryptionProblem),
+            b"8" => Ok(SessionRejectReason::SignatureProblem),
+            b"9" => Ok(SessionRejectReason::CompIdProblem),
+            b"10" => Ok(SessionRejectReason::SendingTimeAccuracyProblem),
+            b"11" => Ok(SessionRejectReason::InvalidMsgType),
+            b"12" => Ok(SessionRejectReason::XmlValidationError),
+            b"13" => Ok(SessionRejectReason::TagAppearsMoreThanOnce),
+            b"14" => Ok(SessionRejectReason::TagOutOfOrder),
+            b"15" => Ok(SessionRejectReason::RepeatingGroupOutOfOrder),
+            b"16" => Ok(SessionRejectReason::IncorrectNumInGroup),
+            b"17" => Ok(SessionRejectReason::NonDataValueIncludesDelimiter),
+            b"99" => Ok(SessionRejectReason::Other),
+            other => Err(ParseError::Unsupported(other)),
+        }
+    }
+}
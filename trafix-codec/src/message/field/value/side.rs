@@ -0,0 +1,144 @@
+//! Defines the [`Side`] enumeration representing the FIX
+//! **54 `Side`** field value.
+
+use crate::message::field::value::FromFixBytes;
+
+/// Represents the side (`54`) of an order, e.g. sent in a `NewOrderSingle` or an
+/// `OrderCancelRequest`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Side {
+    /// Buy (`54=1`).
+    Buy,
+
+    /// Sell (`54=2`).
+    Sell,
+
+    /// Buy minus (`54=3`).
+    BuyMinus,
+
+    /// Sell plus (`54=4`).
+    SellPlus,
+
+    /// Sell short (`54=5`).
+    SellShort,
+
+    /// Sell short exempt (`54=6`).
+    SellShortExempt,
+
+    /// Undisclosed (`54=7`), valid for IOI and list order messages only.
+    Undisclosed,
+
+    /// Cross (`54=8`), for cross orders.
+    Cross,
+
+    /// Cross short (`54=9`).
+    CrossShort,
+
+    /// Cross short exempt (`54=A`).
+    CrossShortExempt,
+
+    /// As defined (`54=B`), for multileg instruments.
+    AsDefined,
+
+    /// Opposite (`54=C`), for multileg instruments.
+    Opposite,
+
+    /// Subscribe (`54=D`), e.g. to a fund.
+    Subscribe,
+
+    /// Redeem (`54=E`), e.g. from a fund.
+    Redeem,
+
+    /// Lend (`54=F`), for financing.
+    Lend,
+
+    /// Borrow (`54=G`), for financing.
+    Borrow,
+}
+
+impl Side {
+    /// Returns the tag used for [`Side`].
+    #[must_use]
+    pub const fn tag() -> u32 {
+        54
+    }
+}
+
+impl From<Side> for &'static [u8] {
+    /// Converts a [`Side`] variant into its **static byte slice**
+    /// representation, corresponding to the FIX wire value of tag **54**.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::field::value::side::Side;
+    /// let bytes: &'static [u8] = Side::Sell.into();
+    /// assert_eq!(bytes, b"2");
+    /// ```
+    fn from(val: Side) -> Self {
+        match val {
+            Side::Buy => b"1",
+            Side::Sell => b"2",
+            Side::BuyMinus => b"3",
+            Side::SellPlus => b"4",
+            Side::SellShort => b"5",
+            Side::SellShortExempt => b"6",
+            Side::Undisclosed => b"7",
+            Side::Cross => b"8",
+            Side::CrossShort => b"9",
+            Side::CrossShortExempt => b"A",
+            Side::AsDefined => b"B",
+            Side::Opposite => b"C",
+            Side::Subscribe => b"D",
+            Side::Redeem => b"E",
+            Side::Lend => b"F",
+            Side::Borrow => b"G",
+        }
+    }
+}
+
+impl From<Side> for Vec<u8> {
+    /// Converts a [`Side`] variant into an **owned `Vec<u8>`**
+    /// containing its FIX wire representation (tag **54** value).
+    fn from(val: Side) -> Self {
+        <&[u8]>::from(val).to_vec()
+    }
+}
+
+/// The error type for failed parsing of [`Side`]
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ParseError<'input> {
+    /// Provided byte slice contains data that is not a valid or supported side.
+    #[error("unsupported side: {}", String::from_utf8_lossy(.0))]
+    Unsupported(&'input [u8]),
+}
+
+impl FromFixBytes for Side {
+    type Error<'input> = ParseError<'input>;
+
+    const ENUMERATED: bool = true;
+
+    fn from_fix_bytes(bytes: &[u8]) -> Result<Self, Self::Error<'_>>
+    where
+        Self: Sized,
+    {
+        match bytes {
+            b"1" => Ok(Side::Buy),
+            b"2" => Ok(Side::Sell),
+            b"3" => Ok(Side::BuyMinus),
+            b"4" => Ok(Side::SellPlus),
+            b"5" => Ok(Side::SellShort),
+            b"6" => Ok(Side::SellShortExempt),
+            b"7" => Ok(Side::Undisclosed),
+            b"8" => Ok(Side::Cross),
+            b"9" => Ok(Side::CrossShort),
+            b"A" => Ok(Side::CrossShortExempt),
+            b"B" => Ok(Side::AsDefined),
+            b"C" => Ok(Side::Opposite),
+            b"D" => Ok(Side::Subscribe),
+            b"E" => Ok(Side::Redeem),
+            b"F" => Ok(Side::Lend),
+            b"G" => Ok(Side::Borrow),
+            other => Err(ParseError::Unsupported(other)),
+        }
+    }
+}
@@ -0,0 +1,79 @@
+//! Defines the [`SubscriptionRequestType`] enumeration representing the FIX
+//! **263 `SubscriptionRequestType`** field value.
+
+use crate::message::field::value::FromFixBytes;
+
+/// Represents the type of subscription (`263`) requested, e.g. by a `MarketDataRequest`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SubscriptionRequestType {
+    /// Snapshot (`263=0`), requesting a one-off snapshot of the current state.
+    Snapshot,
+
+    /// Snapshot plus updates (`263=1`), subscribing to the current state and all future
+    /// updates.
+    Subscribe,
+
+    /// Disable previous snapshot plus update request (`263=2`), unsubscribing from updates.
+    Unsubscribe,
+}
+
+impl SubscriptionRequestType {
+    /// Returns the tag used for [`SubscriptionRequestType`].
+    #[must_use]
+    pub const fn tag() -> u32 {
+        263
+    }
+}
+
+impl From<SubscriptionRequestType> for &'static [u8] {
+    /// Converts a [`SubscriptionRequestType`] variant into its **static byte slice**
+    /// representation, corresponding to the FIX wire value of tag **263**.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::field::value::subscription_request_type::SubscriptionRequestType;
+    /// let bytes: &'static [u8] = SubscriptionRequestType::Subscribe.into();
+    /// assert_eq!(bytes, b"1");
+    /// ```
+    fn from(val: SubscriptionRequestType) -> Self {
+        match val {
+            SubscriptionRequestType::Snapshot => b"0",
+            SubscriptionRequestType::Subscribe => b"1",
+            SubscriptionRequestType::Unsubscribe => b"2",
+        }
+    }
+}
+
+impl From<SubscriptionRequestType> for Vec<u8> {
+    /// Converts a [`SubscriptionRequestType`] variant into an **owned `Vec<u8>`**
+    /// containing its FIX wire representation (tag **263** value).
+    fn from(val: SubscriptionRequestType) -> Self {
+        <&[u8]>::from(val).to_vec()
+    }
+}
+
+/// The error type for failed parsing of [`SubscriptionRequestType`]
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ParseError<'input> {
+    /// Provided byte slice contains data that is not a valid or supported subscription type.
+    #[error("unsupported subscription request type: {}", String::from_utf8_lossy(.0))]
+    Unsupported(&'input [u8]),
+}
+
+impl FromFixBytes for SubscriptionRequestType {
+    type Error<'input> = ParseError<'input>;
+
+    const ENUMERATED: bool = true;
+
+    fn from_fix_bytes(bytes: &[u8]) -> Result<Self, Self::Error<'_>>
+    where
+        Self: Sized,
+    {
+        match bytes {
+            b"0" => Ok(SubscriptionRequestType::Snapshot),
+            b"1" => Ok(SubscriptionRequestType::Subscribe),
+            b"2" => Ok(SubscriptionRequestType::Unsubscribe),
+            other => Err(ParseError::Unsupported(other)),
+        }
+    }
+}
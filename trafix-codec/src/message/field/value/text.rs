@@ -0,0 +1,60 @@
+//! Defines helpers for values of the FIX **58 `Text`** field, see [`Text`].
+
+use crate::message::field::value::aliases::Text;
+
+/// Returns the first `max` bytes of `bytes` as [`Text`], for venues limiting the length of
+/// free format text.
+///
+/// If `bytes` is valid UTF-8, the text is cut at the last character boundary within the limit,
+/// so no multi-byte character is split and the result stays valid UTF-8. Other encodings are
+/// cut at exactly `max` bytes.
+///
+/// Example usage:
+/// ```
+/// use trafix_codec::message::field::value::text;
+///
+/// assert_eq!(text::truncated(b"unknown symbol", 7), b"unknown");
+///
+/// // the two-byte 'é' does not fit and is dropped as a whole
+/// assert_eq!(text::truncated("café".as_bytes(), 4), b"caf");
+/// ```
+#[must_use]
+pub fn truncated(bytes: &[u8], max: usize) -> Text {
+    if bytes.len() <= max {
+        return bytes.to_vec();
+    }
+
+    let end = match std::str::from_utf8(bytes) {
+        Ok(text) => (0..=max)
+            .rev()
+            .find(|index| text.is_char_boundary(*index))
+            .unwrap_or(0),
+        Err(_) => max,
+    };
+
+    bytes[..end].to_vec()
+}
+
+#[cfg(test)]
+mod test {
+    use super::truncated;
+
+    #[test]
+    fn exact_boundary() {
+        assert_eq!(truncated(b"Bye", 3), b"Bye");
+        assert_eq!(truncated(b"Bye", 4), b"Bye");
+        assert_eq!(truncated("café".as_bytes(), 5), "café".as_bytes());
+        assert_eq!(truncated(b"", 0), b"");
+    }
+
+    #[test]
+    fn over_limit() {
+        assert_eq!(truncated(b"Bye", 2), b"By");
+        assert_eq!(truncated(b"Bye", 0), b"");
+        assert_eq!(truncated("café".as_bytes(), 4), b"caf");
+        assert_eq!(truncated("日本".as_bytes(), 5), "日".as_bytes());
+
+        // not UTF-8, cut at exactly the limit
+        assert_eq!(truncated(b"caf\xE9s", 4), b"caf\xE9");
+    }
+}
@@ -0,0 +1,103 @@
+//! Defines the [`TimeInForce`] enumeration representing the FIX
+//! **59 `TimeInForce`** field value.
+
+use crate::message::field::value::FromFixBytes;
+
+/// Represents how long an order remains in effect (`59`).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TimeInForce {
+    /// Day order (`59=0`), expiring at the end of the trading day.
+    Day,
+
+    /// Good till cancel (`59=1`), in effect until canceled.
+    GoodTillCancel,
+
+    /// At the opening (`59=2`), executed at the opening of the market.
+    AtTheOpening,
+
+    /// Immediate or cancel (`59=3`), unfilled quantity is canceled immediately.
+    ImmediateOrCancel,
+
+    /// Fill or kill (`59=4`), filled completely and immediately, or canceled.
+    FillOrKill,
+
+    /// Good till crossing (`59=5`), in effect until the next crossing session.
+    GoodTillCrossing,
+
+    /// Good till date (`59=6`), in effect until the `ExpireTime` (`126`).
+    GoodTillDate,
+
+    /// At the close (`59=7`), executed at the close of the market.
+    AtTheClose,
+}
+
+impl TimeInForce {
+    /// Returns the tag used for [`TimeInForce`].
+    #[must_use]
+    pub const fn tag() -> u32 {
+        59
+    }
+}
+
+impl From<TimeInForce> for &'static [u8] {
+    /// Converts a [`TimeInForce`] variant into its **static byte slice**
+    /// representation, corresponding to the FIX wire value of tag **59**.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::field::value::time_in_force::TimeInForce;
+    /// let bytes: &'static [u8] = TimeInForce::GoodTillDate.into();
+    /// assert_eq!(bytes, b"6");
+    /// ```
+    fn from(val: TimeInForce) -> Self {
+        match val {
+            TimeInForce::Day => b"0",
+            TimeInForce::GoodTillCancel => b"1",
+            TimeInForce::AtTheOpening => b"2",
+            TimeInForce::ImmediateOrCancel => b"3",
+            TimeInForce::FillOrKill => b"4",
+            TimeInForce::GoodTillCrossing => b"5",
+            TimeInForce::GoodTillDate => b"6",
+            TimeInForce::AtTheClose => b"7",
+        }
+    }
+}
+
+impl From<TimeInForce> for Vec<u8> {
+    /// Converts a [`TimeInForce`] variant into an **owned `Vec<u8>`**
+    /// containing its FIX wire representation (tag **59** value).
+    fn from(val: TimeInForce) -> Self {
+        <&[u8]>::from(val).to_vec()
+    }
+}
+
+/// The error type for failed parsing of [`TimeInForce`]
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ParseError<'input> {
+    /// Provided byte slice contains data that is not a valid or supported time in force.
+    #[error("unsupported time in force: {}", String::from_utf8_lossy(.0))]
+    Unsupported(&'input [u8]),
+}
+
+impl FromFixBytes for TimeInForce {
+    type Error<'input> = ParseError<'input>;
+
+    const ENUMERATED: bool = true;
+
+    fn from_fix_bytes(bytes: &[u8]) -> Result<Self, Self::Error<'_>>
+    where
+        Self: Sized,
+    {
+        match bytes {
+            b"0" => Ok(TimeInForce::Day),
+            b"1" => Ok(TimeInForce::GoodTillCancel),
+            b"2" => Ok(TimeInForce::AtTheOpening),
+            b"3" => Ok(TimeInForce::ImmediateOrCancel),
+            b"4" => Ok(TimeInForce::FillOrKill),
+            b"5" => Ok(TimeInForce::GoodTillCrossing),
+            b"6" => Ok(TimeInForce::GoodTillDate),
+            b"7" => Ok(TimeInForce::AtTheClose),
+            other => Err(ParseError::Unsupported(other)),
+        }
+    }
+}
@@ -0,0 +1,337 @@
+//! Defines the [`UtcTimestamp`] type, representing the FIX `UTCTimestamp` field value format
+//! (`YYYYMMDD-HH:MM:SS[.sss]`).
+
+use std::fmt;
+use std::time::{Duration, SystemTime};
+
+use crate::message::field::value::FromFixBytes;
+use crate::message::field::value::local_mkt_date::{LocalMktDate, ParseError, parse_component};
+
+/// Represents a UTC date and time (`YYYYMMDD-HH:MM:SS[.sss]`), e.g. the `SendingTime` (`52`) or
+/// `TransactTime` (`60`).
+///
+/// Fractional seconds are optional and may be given with millisecond (`.sss`), microsecond
+/// (`.ssssss`) or nanosecond (`.sssssssss`) precision. The precision is retained, so that
+/// encoding a decoded timestamp produces the exact same bytes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct UtcTimestamp {
+    /// Date component of the timestamp.
+    date: LocalMktDate,
+
+    /// Hour component, in range `0..=23`.
+    hour: u8,
+
+    /// Minute component, in range `0..=59`.
+    minute: u8,
+
+    /// Second component, in range `0..=60` (leap second included).
+    second: u8,
+
+    /// Fraction of the second in nanoseconds, in range `0..1_000_000_000`.
+    nanos: u32,
+
+    /// Number of fractional second digits used on the wire (`0`, `3`, `6` or `9`).
+    precision: u8,
+}
+
+impl UtcTimestamp {
+    /// Creates a new [`UtcTimestamp`] with second precision from its components.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::field::value::{
+    ///     local_mkt_date::LocalMktDate, utc_timestamp::UtcTimestamp,
+    /// };
+    ///
+    /// let date = LocalMktDate::new(2018, 9, 20).unwrap();
+    /// let timestamp = UtcTimestamp::new(date, 18, 14, 19).unwrap();
+    /// assert_eq!(Vec::from(&timestamp), b"20180920-18:14:19");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseError::OutOfRange`] if any of the time components is out of its valid
+    /// range.
+    pub fn new(date: LocalMktDate, hour: u8, minute: u8, second: u8) -> Result<Self, ParseError> {
+        if hour > 23 {
+            return Err(ParseError::OutOfRange("hour"));
+        }
+
+        if minute > 59 {
+            return Err(ParseError::OutOfRange("minute"));
+        }
+
+        if second > 60 {
+            return Err(ParseError::OutOfRange("second"));
+        }
+
+        Ok(Self {
+            date,
+            hour,
+            minute,
+            second,
+            nanos: 0,
+            precision: 0,
+        })
+    }
+
+    /// Sets the millisecond fraction of this timestamp, switching it to millisecond precision.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseError::OutOfRange`] if `millis` is not in range `0..1000`.
+    pub fn with_millis(self, millis: u16) -> Result<Self, ParseError> {
+        if millis >= 1000 {
+            return Err(ParseError::OutOfRange("millisecond"));
+        }
+
+        Ok(Self {
+            nanos: u32::from(millis) * 1_000_000,
+            precision: 3,
+            ..self
+        })
+    }
+
+    /// Returns the date component.
+    #[must_use]
+    pub const fn date(&self) -> LocalMktDate {
+        self.date
+    }
+
+    /// Returns the hour component (`0..=23`).
+    #[must_use]
+    pub const fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    /// Returns the minute component (`0..=59`).
+    #[must_use]
+    pub const fn minute(&self) -> u8 {
+        self.minute
+    }
+
+    /// Returns the second component (`0..=60`).
+    #[must_use]
+    pub const fn second(&self) -> u8 {
+        self.second
+    }
+
+    /// Returns the fraction of the second in nanoseconds.
+    #[must_use]
+    pub const fn nanos(&self) -> u32 {
+        self.nanos
+    }
+}
+
+impl fmt::Display for UtcTimestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}-{:02}:{:02}:{:02}",
+            self.date, self.hour, self.minute, self.second
+        )?;
+
+        match self.precision {
+            3 => write!(f, ".{:03}", self.nanos / 1_000_000),
+            6 => write!(f, ".{:06}", self.nanos / 1_000),
+            9 => write!(f, ".{:09}", self.nanos),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl From<&UtcTimestamp> for Vec<u8> {
+    /// Converts a [`UtcTimestamp`] into its `YYYYMMDD-HH:MM:SS[.sss]` wire representation.
+    fn from(val: &UtcTimestamp) -> Self {
+        val.to_string().into_bytes()
+    }
+}
+
+impl From<&UtcTimestamp> for SystemTime {
+    /// Converts a [`UtcTimestamp`] into the [`SystemTime`] it represents.
+    ///
+    /// Leap seconds are not accounted for, i.e. a second of `60` is treated as the first second
+    /// of the following minute.
+    ///
+    /// Example usage:
+    /// ```
+    /// use std::time::{Duration, SystemTime};
+    ///
+    /// use trafix_codec::message::field::value::{
+    ///     local_mkt_date::LocalMktDate, utc_timestamp::UtcTimestamp,
+    /// };
+    ///
+    /// let date = LocalMktDate::new(1970, 1, 2).unwrap();
+    /// let timestamp = UtcTimestamp::new(date, 0, 0, 1).unwrap();
+    ///
+    /// assert_eq!(
+    ///     SystemTime::from(&timestamp),
+    ///     SystemTime::UNIX_EPOCH + Duration::from_secs(86_401)
+    /// );
+    /// ```
+    fn from(val: &UtcTimestamp) -> Self {
+        let date = val.date;
+
+        // days since the unix epoch, see http://howardhinnant.github.io/date_algorithms.html
+        let month = i64::from(date.month());
+        let year = i64::from(date.year()) - i64::from(month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let day_of_year =
+            (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + i64::from(date.day()) - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        let days = era * 146_097 + day_of_era - 719_468;
+
+        let seconds = days * 86_400
+            + i64::from(val.hour) * 3_600
+            + i64::from(val.minute) * 60
+            + i64::from(val.second);
+
+        let nanos = Duration::from_nanos(u64::from(val.nanos));
+
+        if seconds >= 0 {
+            SystemTime::UNIX_EPOCH + Duration::from_secs(seconds.unsigned_abs()) + nanos
+        } else {
+            SystemTime::UNIX_EPOCH - Duration::from_secs(seconds.unsigned_abs()) + nanos
+        }
+    }
+}
+
+impl FromFixBytes for UtcTimestamp {
+    type Error<'unused> = ParseError;
+
+    fn from_fix_bytes(bytes: &[u8]) -> Result<Self, Self::Error<'_>>
+    where
+        Self: Sized,
+    {
+        let malformed = || ParseError::Malformed(bytes.to_vec());
+
+        let (date, time) = bytes.split_at_checked(8).ok_or_else(malformed)?;
+        let date = LocalMktDate::from_fix_bytes(date).map_err(|_| malformed())?;
+
+        let (time, fraction) = match time.get(9) {
+            None => (time, None),
+            Some(b'.') => (&time[..9], Some(&time[10..])),
+            Some(_) => return Err(malformed()),
+        };
+
+        let [b'-', h1, h0, b':', m1, m0, b':', s1, s0] = time else {
+            return Err(malformed());
+        };
+
+        let (Some(hour), Some(minute), Some(second)) = (
+            parse_component(&[*h1, *h0]),
+            parse_component(&[*m1, *m0]),
+            parse_component(&[*s1, *s0]),
+        ) else {
+            return Err(malformed());
+        };
+
+        let timestamp = UtcTimestamp::new(date, hour, minute, second)?;
+
+        let Some(fraction) = fraction else {
+            return Ok(timestamp);
+        };
+
+        let scale = match fraction.len() {
+            3 => 1_000_000,
+            6 => 1_000,
+            9 => 1,
+            _ => return Err(malformed()),
+        };
+
+        let nanos = parse_component::<u32>(fraction).ok_or_else(malformed)? * scale;
+
+        Ok(Self {
+            nanos,
+            precision: u8::try_from(fraction.len()).expect("at most 9 digits"),
+            ..timestamp
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::{Duration, SystemTime};
+
+    use super::UtcTimestamp;
+    use crate::message::field::value::FromFixBytes;
+    use crate::message::field::value::local_mkt_date::{LocalMktDate, ParseError};
+
+    #[test]
+    fn parse_valid() {
+        let timestamp = UtcTimestamp::from_fix_bytes(b"20180920-18:14:19.508").unwrap();
+
+        let expected = UtcTimestamp::new(LocalMktDate::new(2018, 9, 20).unwrap(), 18, 14, 19)
+            .unwrap()
+            .with_millis(508)
+            .unwrap();
+
+        assert_eq!(timestamp, expected);
+        assert_eq!(timestamp.nanos(), 508_000_000);
+    }
+
+    #[test]
+    fn round_trip() {
+        for input in [
+            &b"20180920-18:14:19"[..],
+            b"20180920-18:14:19.008",
+            b"20180920-00:00:60.000120",
+            b"20180920-23:59:59.000000001",
+        ] {
+            let timestamp = UtcTimestamp::from_fix_bytes(input).unwrap();
+            assert_eq!(Vec::from(&timestamp), input);
+        }
+    }
+
+    #[test]
+    fn system_time() {
+        for (input, seconds) in [
+            (&b"19700101-00:00:00"[..], 0),
+            (b"20000229-12:00:00", 951_825_600),
+            (b"20180920-18:14:19", 1_537_467_259),
+        ] {
+            let timestamp = UtcTimestamp::from_fix_bytes(input).unwrap();
+
+            assert_eq!(
+                SystemTime::from(&timestamp),
+                SystemTime::UNIX_EPOCH + Duration::from_secs(seconds)
+            );
+        }
+
+        let timestamp = UtcTimestamp::from_fix_bytes(b"19691231-23:59:59.500").unwrap();
+        assert_eq!(
+            SystemTime::from(&timestamp),
+            SystemTime::UNIX_EPOCH - Duration::from_millis(500)
+        );
+    }
+
+    #[test]
+    fn parse_invalid() {
+        for input in [
+            &b"20180920"[..],
+            b"20180920-",
+            b"20180920 18:14:19",
+            b"20180920-18-14-19",
+            b"20180920-18:14:19.",
+            b"20180920-18:14:19.5",
+            b"20180920-18:14:19.5080",
+            b"20180920-1a:14:19",
+            b"20181320-18:14:19",
+        ] {
+            assert_eq!(
+                UtcTimestamp::from_fix_bytes(input),
+                Err(ParseError::Malformed(input.to_vec()))
+            );
+        }
+
+        assert_eq!(
+            UtcTimestamp::from_fix_bytes(b"20180920-24:00:00"),
+            Err(ParseError::OutOfRange("hour"))
+        );
+        assert_eq!(
+            UtcTimestamp::from_fix_bytes(b"20180920-18:60:00"),
+            Err(ParseError::OutOfRange("minute"))
+        );
+    }
+}
@@ -0,0 +1,248 @@
+//! Implementation of the serialization of messages to FIXML, the XML representation of FIX,
+//! available with the `fixml` feature.
+
+use crate::{
+    constants,
+    message::{
+        Message,
+        field::{Field, value::password::Password},
+    },
+};
+
+impl Message {
+    /// Serializes this message to FIXML, in the QuickFIX-style layout: a `<FIXML>` root with
+    /// `<Header>`, `<Body>` and `<Trailer>` sections, each containing one element per field,
+    /// named after the field, e.g. `<SenderCompID>SENDER</SenderCompID>`.
+    ///
+    /// Fields are assigned to the sections by their tags, and keep their relative order within
+    /// each section. The `BodyLength` (`9`) and `CheckSum` (`10`) only exist on the wire, so they
+    /// are omitted. Fields without a known name are emitted as `<Field tag="N">`.
+    ///
+    /// Values are escaped. Values that can not be represented as XML text, i.e. that are not
+    /// valid UTF-8 or contain characters not allowed in XML such as SOH, e.g. binary data fields,
+    /// are emitted base64-encoded instead, marked with an `encoding="base64"` attribute. The
+    /// password (`554`) is redacted.
+    ///
+    /// This is a one-way conversion, FIXML can not be decoded back into a [`Message`].
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::{
+    ///     Message,
+    ///     field::{
+    ///         Field,
+    ///         value::{begin_string::BeginString, msg_type::MsgType},
+    ///     },
+    /// };
+    ///
+    /// let fixml = Message::builder(BeginString::FIX44, MsgType::Logout)
+    ///     .with_header(Field::MsgSeqNum(2))
+    ///     .with_field(Field::Text(b"Bye".to_vec()))
+    ///     .build()
+    ///     .to_fixml();
+    ///
+    /// assert!(fixml.contains("<MsgSeqNum>2</MsgSeqNum>"));
+    /// assert!(fixml.contains("<Text>Bye</Text>"));
+    /// ```
+    #[must_use]
+    pub fn to_fixml(&self) -> String {
+        let (header, rest): (Vec<&Field>, Vec<&Field>) = self
+            .fields()
+            .partition(|field| constants::HEADER_TAGS.contains(&field.tag()));
+        let (trailer, body): (Vec<&Field>, Vec<&Field>) = rest
+            .into_iter()
+            .partition(|field| constants::TRAILER_TAGS.contains(&field.tag()));
+
+        let mut fixml = String::from("<FIXML>\n  <Header>\n");
+
+        write_element(&mut fixml, 8, &Vec::from(self.header.begin_string));
+        write_element(&mut fixml, 35, &Vec::from(&self.header.msg_type));
+
+        for field in header {
+            write_element(&mut fixml, field.tag(), &field.value());
+        }
+
+        fixml.push_str("  </Header>\n  <Body>\n");
+
+        for field in body {
+            write_element(&mut fixml, field.tag(), &field.value());
+        }
+
+        fixml.push_str("  </Body>\n  <Trailer>\n");
+
+        for field in trailer {
+            write_element(&mut fixml, field.tag(), &field.value());
+        }
+
+        fixml.push_str("  </Trailer>\n</FIXML>\n");
+
+        fixml
+    }
+}
+
+/// Appends the element of the field with the given tag and value to `fixml`, on its own line.
+fn write_element(fixml: &mut String, tag: u32, value: &[u8]) {
+    let value = if tag == Password::tag() {
+        b"****"
+    } else {
+        value
+    };
+
+    let text = std::str::from_utf8(value)
+        .ok()
+        .filter(|text| text.chars().all(is_xml_char));
+
+    let (value, encoding) = match text {
+        Some(text) => (escape(text), ""),
+        None => (base64(value), " encoding=\"base64\""),
+    };
+
+    let name = Field::tag_name(tag);
+    let start = match name {
+        Some(name) => name.to_string(),
+        None => format!("Field tag=\"{tag}\""),
+    };
+    let end = name.unwrap_or("Field");
+
+    for part in ["    <", &start, encoding, ">", &value, "</", end, ">\n"] {
+        fixml.push_str(part);
+    }
+}
+
+/// Returns whether the given character is allowed in XML 1.0 documents.
+fn is_xml_char(character: char) -> bool {
+    matches!(character, '\t' | '\n' | '\r' | ' '..='\u{D7FF}' | '\u{E000}'..='\u{FFFD}')
+        || character >= '\u{10000}'
+}
+
+/// Encodes the given bytes as standard base64, with padding.
+fn base64(bytes: &[u8]) -> String {
+    /// Characters of the standard base64 alphabet, indexed by their 6-bit value.
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+
+    for chunk in bytes.chunks(3) {
+        let group = chunk
+            .iter()
+            .enumerate()
+            .fold(0_u32, |group, (index, byte)| {
+                group | u32::from(*byte) << (16 - 8 * index)
+            });
+
+        for index in 0..4 {
+            if index <= chunk.len() {
+                let sextet = (group >> (18 - 6 * index)) & 0x3F;
+                encoded.push(char::from(ALPHABET[sextet as usize]));
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+
+    encoded
+}
+
+/// Escapes the characters with special meaning in XML.
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+
+    for character in value.chars() {
+        match character {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            other => escaped.push(other),
+        }
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod test {
+    use crate::message::{Message, field::value::begin_string::BeginString};
+
+    #[test]
+    fn to_fixml() {
+        let input = "8=FIX.4.4\x019=60\x0135=8\x0149=SELLER\x0156=BUYER\x0134=12\x0155=AT&T\x0154=1\x0158=<fill>\x019999=x\x0110=123\x01";
+        let msg = Message::decode(input).expect("message is valid");
+
+        insta::assert_snapshot!(msg.to_fixml(), @r#"
+        <FIXML>
+          <Header>
+            <BeginString>FIX.4.4</BeginString>
+            <MsgType>8</MsgType>
+            <SenderCompID>SELLER</SenderCompID>
+            <TargetCompID>BUYER</TargetCompID>
+            <MsgSeqNum>12</MsgSeqNum>
+          </Header>
+          <Body>
+            <Symbol>AT&amp;T</Symbol>
+            <Side>1</Side>
+            <Text>&lt;fill&gt;</Text>
+            <Field tag="9999">x</Field>
+          </Body>
+          <Trailer>
+          </Trailer>
+        </FIXML>
+        "#);
+    }
+
+    #[test]
+    fn binary_values() {
+        let input = b"8=FIX.4.4\x019=31\x0135=5\x0195=4\x0196=a\x01b\xFF\x0158=\x07\x019999=x\x1B\x0110=142\x01";
+        let msg = Message::decode(input).expect("message is valid");
+
+        // values that are not valid XML text are base64-encoded
+        insta::assert_snapshot!(msg.to_fixml(), @r#"
+        <FIXML>
+          <Header>
+            <BeginString>FIX.4.4</BeginString>
+            <MsgType>5</MsgType>
+          </Header>
+          <Body>
+            <Field tag="95">4</Field>
+            <Field tag="96" encoding="base64">YQFi/w==</Field>
+            <Text encoding="base64">Bw==</Text>
+            <Field tag="9999" encoding="base64">eBs=</Field>
+          </Body>
+          <Trailer>
+          </Trailer>
+        </FIXML>
+        "#);
+    }
+
+    #[test]
+    fn redacts_password() {
+        let msg = Message::logon(
+            BeginString::FIX44,
+            b"SENDER".into(),
+            b"TARGET".into(),
+            30,
+            Some(b"trader".to_vec()),
+            Some(b"secret".into()),
+        );
+
+        insta::assert_snapshot!(msg.to_fixml(), @r"
+        <FIXML>
+          <Header>
+            <BeginString>FIX.4.4</BeginString>
+            <MsgType>A</MsgType>
+            <SenderCompID>SENDER</SenderCompID>
+            <TargetCompID>TARGET</TargetCompID>
+          </Header>
+          <Body>
+            <EncryptMethod>0</EncryptMethod>
+            <HeartBtInt>30</HeartBtInt>
+            <Username>trader</Username>
+            <Password>****</Password>
+          </Body>
+          <Trailer>
+          </Trailer>
+        </FIXML>
+        ");
+    }
+}
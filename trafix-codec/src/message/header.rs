@@ -0,0 +1,398 @@
+//! Implementation of structural validation of the standard header of messages.
+
+use crate::{
+    constants,
+    message::{Message, field::Field},
+};
+
+/// Pairs of header field tags, where the presence of the first field requires the presence of
+/// the second one.
+const HEADER_DEPENDENCIES: &[(u32, u32)] = &[
+    // OnBehalfOfSubID and OnBehalfOfLocationID require OnBehalfOfCompID
+    (116, 115),
+    (144, 115),
+    // DeliverToSubID and DeliverToLocationID require DeliverToCompID
+    (129, 128),
+    (145, 128),
+    // data fields and their lengths come in pairs
+    (90, 91),
+    (91, 90),
+    (212, 213),
+    (213, 212),
+];
+
+/// Structural problem of the standard header of a message, as reported by
+/// [`Message::validate_header`].
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum HeaderError {
+    /// A header field required in every message is missing.
+    #[error("required header field {0} is missing")]
+    MissingField(u32),
+
+    /// A header field occurs more than once.
+    #[error("header field {0} occurs more than once")]
+    DuplicateField(u32),
+
+    /// A header field occurs after the first body field.
+    #[error("header field {0} occurs after the first body field")]
+    MisplacedField(u32),
+
+    /// A header field is present without another header field it depends on.
+    #[error("header field {tag} requires header field {required}")]
+    MissingDependency {
+        /// Tag of the present field.
+        tag: u32,
+
+        /// Tag of the missing field required by it.
+        required: u32,
+    },
+
+    /// The `PossDupFlag` (`43`) is set, but the `OrigSendingTime` (`122`) of the original
+    /// message is missing.
+    #[error("possible duplicate lacks OrigSendingTime (122)")]
+    MissingOrigSendingTime,
+}
+
+/// Problem of a gap filling `SequenceReset`, as reported by [`Message::validate_gap_fill`].
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum GapFillError {
+    /// The `PossDupFlag` (`43`) is missing or not set to `Y`.
+    #[error("gap fill lacks PossDupFlag=Y (43)")]
+    MissingPossDupFlag,
+
+    /// The `OrigSendingTime` (`122`) is missing.
+    #[error("gap fill lacks OrigSendingTime (122)")]
+    MissingOrigSendingTime,
+}
+
+/// Mismatch of the comp IDs of an inbound message, as reported by [`Message::check_comp_ids`].
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum CompIDError {
+    /// The comp ID field with the given tag is missing.
+    #[error("message lacks the comp id with tag {0}")]
+    Missing(u32),
+
+    /// The comp ID field with the given tag does not hold the expected comp ID.
+    #[error(
+        "comp id with tag {tag} is {}, expected {}",
+        String::from_utf8_lossy(received),
+        String::from_utf8_lossy(expected)
+    )]
+    Mismatch {
+        /// Tag of the mismatched field, `56` for the `TargetCompID` or `49` for the
+        /// `SenderCompID`.
+        tag: u32,
+
+        /// Expected comp ID.
+        expected: Vec<u8>,
+
+        /// Comp ID carried by the message.
+        received: Vec<u8>,
+    },
+}
+
+impl Message {
+    /// Validates the structure of the standard header of this message, collecting all problems
+    /// found instead of stopping at the first one, e.g. for rejecting the message with a
+    /// precise list of faults.
+    ///
+    /// The `BeginString`, `BodyLength` and `MsgType` are validated positionally while decoding,
+    /// so this checks the remaining header fields:
+    ///
+    /// - the `SenderCompID` (`49`), `TargetCompID` (`56`), `MsgSeqNum` (`34`) and
+    ///   `SendingTime` (`52`) are present,
+    /// - no header field occurs twice, except for the fields of the hops (`627`) group, nor
+    ///   after the first body field,
+    /// - fields qualifying a routing comp ID, e.g. the `OnBehalfOfSubID` (`116`), come with
+    ///   that comp ID, e.g. the `OnBehalfOfCompID` (`115`),
+    /// - header data fields come with their lengths and vice versa,
+    /// - a possible duplicate (`43=Y`) carries its `OrigSendingTime` (`122`).
+    ///
+    /// Header fields are recognized by their tags, whether they were added to the header or to
+    /// the body of the message, as the decoder stores all fields in order of appearance.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::{Message, header::HeaderError};
+    ///
+    /// let input = "8=FIX.4.4\x019=35\x0135=0\x0149=SENDER\x0156=TARGET\x0134=1\x0134=2\x0110=095\x01";
+    /// let errors = Message::decode(input).unwrap().validate_header().unwrap_err();
+    ///
+    /// assert_eq!(
+    ///     errors,
+    ///     vec![HeaderError::MissingField(52), HeaderError::DuplicateField(34)]
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns all [`HeaderError`]s found, in the order of the checks listed above.
+    pub fn validate_header(&self) -> Result<(), Vec<HeaderError>> {
+        let mut errors = Vec::new();
+        let present = self.present_tags();
+
+        for tag in constants::REQUIRED_HEADER_TAGS {
+            if !present.contains(tag) {
+                errors.push(HeaderError::MissingField(*tag));
+            }
+        }
+
+        let mut seen = Vec::new();
+        let mut in_body = false;
+
+        for tag in self.fields().map(Field::tag) {
+            if !constants::HEADER_TAGS.contains(&tag) {
+                in_body = true;
+                continue;
+            }
+
+            // the fields of the hops group repeat once per hop
+            let error = if seen.contains(&tag) && !constants::HOP_TAGS.contains(&tag) {
+                Some(HeaderError::DuplicateField(tag))
+            } else {
+                seen.push(tag);
+                None
+            };
+
+            let misplaced = in_body.then_some(HeaderError::MisplacedField(tag));
+
+            // each problem is reported once, even if the field occurs many times
+            for error in [misplaced, error].into_iter().flatten() {
+                if !errors.contains(&error) {
+                    errors.push(error);
+                }
+            }
+        }
+
+        for (tag, required) in HEADER_DEPENDENCIES {
+            if present.contains(tag) && !present.contains(required) {
+                errors.push(HeaderError::MissingDependency {
+                    tag: *tag,
+                    required: *required,
+                });
+            }
+        }
+
+        let poss_dup = self
+            .fields()
+            .any(|field| field.tag() == 43 && field.value() == b"Y");
+
+        if poss_dup && !present.contains(&122) {
+            errors.push(HeaderError::MissingOrigSendingTime);
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Validates that a gap filling `SequenceReset` (see [`Message::is_gap_fill`]) carries the
+    /// `PossDupFlag` (`43`) set to `Y` and the `OrigSendingTime` (`122`), as gap fills are sent
+    /// in place of resent messages. Other messages, including hard resets, are not checked.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::{Message, header::GapFillError};
+    ///
+    /// let input = "8=FIX.4.4\x019=22\x0135=4\x0143=Y\x01123=Y\x0136=10\x0110=009\x01";
+    /// let msg = Message::decode(input).unwrap();
+    ///
+    /// assert_eq!(
+    ///     msg.validate_gap_fill(),
+    ///     Err(GapFillError::MissingOrigSendingTime)
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`GapFillError`] for the first missing field, checking the `PossDupFlag`
+    /// first.
+    pub fn validate_gap_fill(&self) -> Result<(), GapFillError> {
+        if !self.is_gap_fill() {
+            return Ok(());
+        }
+
+        let poss_dup = self
+            .fields()
+            .any(|field| field.tag() == 43 && field.value() == b"Y");
+
+        if !poss_dup {
+            return Err(GapFillError::MissingPossDupFlag);
+        }
+
+        if !self.fields().any(|field| field.tag() == 122) {
+            return Err(GapFillError::MissingOrigSendingTime);
+        }
+
+        Ok(())
+    }
+
+    /// Checks that an inbound message is addressed to us, i.e. that its `TargetCompID` (`56`)
+    /// is our own comp ID and its `SenderCompID` (`49`) is the comp ID of the counterparty. A
+    /// message failing the check is misrouted and must be rejected.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::{
+    ///     Message, field::value::begin_string::BeginString, header::CompIDError,
+    /// };
+    ///
+    /// let logon =
+    ///     Message::minimal_logon(BeginString::FIX44, b"CLIENT".into(), b"VENUE".into(), 30);
+    ///
+    /// assert_eq!(logon.check_comp_ids(b"VENUE", b"CLIENT"), Ok(()));
+    /// assert_eq!(
+    ///     logon.check_comp_ids(b"CLIENT", b"VENUE"),
+    ///     Err(CompIDError::Mismatch {
+    ///         tag: 56,
+    ///         expected: b"CLIENT".to_vec(),
+    ///         received: b"VENUE".to_vec(),
+    ///     })
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`CompIDError`] for the first missing or mismatched comp ID, checking the
+    /// `TargetCompID` first.
+    pub fn check_comp_ids(
+        &self,
+        expected_target: &[u8],
+        expected_sender: &[u8],
+    ) -> Result<(), CompIDError> {
+        let (mut sender, mut target) = (None, None);
+
+        for field in self.fields() {
+            match field {
+                Field::SenderCompID(value) if sender.is_none() => sender = Some(value),
+                Field::TargetCompID(value) if target.is_none() => target = Some(value),
+                _ => {}
+            }
+        }
+
+        for (tag, received, expected) in
+            [(56, target, expected_target), (49, sender, expected_sender)]
+        {
+            let received = received.ok_or(CompIDError::Missing(tag))?;
+
+            if **received != *expected {
+                return Err(CompIDError::Mismatch {
+                    tag,
+                    expected: expected.to_vec(),
+                    received: received.to_vec(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::message::{
+        Message,
+        field::{Field, value::begin_string::BeginString, value::msg_type::MsgType},
+        header::{CompIDError, GapFillError, HeaderError},
+    };
+
+    #[test]
+    fn valid_header() {
+        let input = "8=FIX.4.4\x019=78\x0135=0\x0149=SENDER\x0156=TARGET\x0134=2\x0143=Y\x0152=20180920-18:14:19\x01122=20180920-18:14:18\x0110=227\x01";
+        let msg = Message::decode(input).expect("message is valid");
+
+        assert_eq!(msg.validate_header(), Ok(()));
+    }
+
+    #[test]
+    fn hops() {
+        let input = "8=FIX.4.4\x019=119\x0135=0\x0149=SENDER\x0156=TARGET\x0134=2\x0152=20180920-18:14:19\x01627=2\x01628=HOP1\x01629=20180920-18:14:17\x01628=HOP2\x01629=20180920-18:14:18\x0110=101\x01";
+        let msg = Message::decode(input).expect("message is valid");
+
+        // the hop fields repeat once per hop
+        assert_eq!(msg.validate_header(), Ok(()));
+    }
+
+    #[test]
+    fn collects_all_errors() {
+        let input = "8=FIX.4.4\x019=60\x0135=0\x0149=SENDER\x0156=TARGET\x0143=Y\x01116=DESK\x0191=X\x0158=Hi\x0134=2\x0134=3\x0110=182\x01";
+        let errors = Message::decode(input)
+            .expect("message is valid")
+            .validate_header()
+            .unwrap_err();
+
+        assert_eq!(
+            errors,
+            vec![
+                HeaderError::MissingField(52),
+                HeaderError::MisplacedField(34),
+                HeaderError::DuplicateField(34),
+                HeaderError::MissingDependency {
+                    tag: 116,
+                    required: 115
+                },
+                HeaderError::MissingDependency {
+                    tag: 91,
+                    required: 90
+                },
+                HeaderError::MissingOrigSendingTime,
+            ]
+        );
+    }
+
+    #[test]
+    fn gap_fill() {
+        let valid = "8=FIX.4.4\x019=44\x0135=4\x0143=Y\x01122=20180920-18:14:18\x01123=Y\x0136=10\x0110=078\x01";
+        let no_poss_dup = "8=FIX.4.4\x019=17\x0135=4\x01123=Y\x0136=10\x0110=015\x01";
+        let hard_reset = "8=FIX.4.4\x019=17\x0135=4\x01123=N\x0136=10\x0110=004\x01";
+
+        let decode = |input| Message::decode(input).expect("message is valid");
+
+        assert_eq!(decode(valid).validate_gap_fill(), Ok(()));
+        assert_eq!(
+            decode(no_poss_dup).validate_gap_fill(),
+            Err(GapFillError::MissingPossDupFlag)
+        );
+        assert_eq!(decode(hard_reset).validate_gap_fill(), Ok(()));
+    }
+
+    #[test]
+    fn comp_ids() {
+        let input = "8=FIX.4.4\x019=29\x0135=0\x0149=CLIENT\x0156=VENUE\x0134=2\x0110=070\x01";
+        let msg = Message::decode(input).expect("message is valid");
+
+        assert_eq!(msg.check_comp_ids(b"VENUE", b"CLIENT"), Ok(()));
+
+        // misrouted message
+        assert_eq!(
+            msg.check_comp_ids(b"OTHER", b"CLIENT"),
+            Err(CompIDError::Mismatch {
+                tag: 56,
+                expected: b"OTHER".to_vec(),
+                received: b"VENUE".to_vec(),
+            })
+        );
+
+        // unknown counterparty
+        assert_eq!(
+            msg.check_comp_ids(b"VENUE", b"OTHER"),
+            Err(CompIDError::Mismatch {
+                tag: 49,
+                expected: b"OTHER".to_vec(),
+                received: b"CLIENT".to_vec(),
+            })
+        );
+
+        let msg = Message::builder(BeginString::FIX44, MsgType::Heartbeat)
+            .with_header(Field::TargetCompID(b"VENUE".into()))
+            .with_field(Field::MsgSeqNum(1))
+            .build();
+
+        assert_eq!(
+            msg.check_comp_ids(b"VENUE", b"CLIENT"),
+            Err(CompIDError::Missing(49))
+        );
+    }
+}
@@ -0,0 +1,219 @@
+//! Implementation of structured access to the fields of the Instrument component block.
+
+use crate::{
+    constants,
+    message::{
+        Message, MessageBuilder,
+        field::{
+            Field,
+            value::{
+                aliases::{SecurityID, Symbol},
+                cfi_code::CFICode,
+                currency::Currency,
+                security_id_source::SecurityIDSource,
+                security_type::SecurityType,
+            },
+        },
+    },
+};
+
+/// The fields identifying an instrument, as used by order, quote and market data messages
+/// alike.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Instrument {
+    /// Ticker symbol of the instrument (`55`).
+    pub symbol: Symbol,
+
+    /// Identifier of the instrument (`48`), if given.
+    pub security_id: Option<SecurityID>,
+
+    /// Identification scheme of the `security_id` (`22`), if given.
+    pub security_id_source: Option<SecurityIDSource>,
+
+    /// Classification of the instrument (`461`), if given.
+    pub cfi_code: Option<CFICode>,
+
+    /// Type of the instrument (`167`), if given.
+    pub security_type: Option<SecurityType>,
+
+    /// Currency of the instrument (`15`), if given.
+    pub currency: Option<Currency>,
+}
+
+impl Instrument {
+    /// Creates a new [`Instrument`] identified by its symbol only.
+    #[must_use]
+    pub fn new(symbol: Symbol) -> Self {
+        Self {
+            symbol,
+            security_id: None,
+            security_id_source: None,
+            cfi_code: None,
+            security_type: None,
+            currency: None,
+        }
+    }
+
+    /// Returns the fields of this instrument in canonical order, i.e. `Symbol` (`55`),
+    /// `SecurityID` (`48`), `SecurityIDSource` (`22`), `CFICode` (`461`), `SecurityType`
+    /// (`167`) and `Currency` (`15`), omitting the absent ones.
+    #[must_use]
+    pub fn into_fields(self) -> Vec<Field> {
+        [
+            Some(Field::Symbol(self.symbol)),
+            self.security_id.map(Field::SecurityID),
+            self.security_id_source.map(Field::SecurityIDSource),
+            self.cfi_code.map(Field::CFICode),
+            self.security_type.map(Field::SecurityType),
+            self.currency.map(Field::Currency),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl Message {
+    /// Returns the [`Instrument`] of this message, or [`None`] if this message has no `Symbol`
+    /// (`55`).
+    ///
+    /// The instrument is made up of the first `Symbol` and the fields directly following it. It
+    /// ends at the next `Symbol`, e.g. of another entry of a repeating group, or at the first
+    /// field that belongs neither to the Instrument component block nor is the `Currency`
+    /// (`15`), so fields of other instruments or legs are not mixed in.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::{
+    ///     Message, field::value::security_id_source::SecurityIDSource,
+    /// };
+    ///
+    /// let input = "8=FIX.4.4\x019=33\x0135=V\x0155=SAP\x0148=DE0007164600\x0122=4\x0110=135\x01";
+    /// let instrument = Message::decode(input).unwrap().instrument().unwrap();
+    ///
+    /// assert_eq!(instrument.symbol, b"SAP");
+    /// assert_eq!(instrument.security_id_source, Some(SecurityIDSource::Isin));
+    /// assert_eq!(instrument.currency, None);
+    /// ```
+    #[must_use]
+    pub fn instrument(&self) -> Option<Instrument> {
+        let mut fields = self
+            .fields()
+            .skip_while(|field| !matches!(field, Field::Symbol(_)));
+
+        let Some(Field::Symbol(symbol)) = fields.next() else {
+            return None;
+        };
+
+        let mut instrument = Instrument::new(symbol.clone());
+
+        let fields = fields.take_while(|field| {
+            let tag = field.tag();
+            tag != 55 && (constants::INSTRUMENT_TAGS.contains(&tag) || tag == 15)
+        });
+
+        for field in fields {
+            match field {
+                Field::SecurityID(value) if instrument.security_id.is_none() => {
+                    instrument.security_id = Some(value.clone());
+                }
+                Field::SecurityIDSource(value) if instrument.security_id_source.is_none() => {
+                    instrument.security_id_source = Some(*value);
+                }
+                Field::CFICode(value) if instrument.cfi_code.is_none() => {
+                    instrument.cfi_code = Some(*value);
+                }
+                Field::SecurityType(value) if instrument.security_type.is_none() => {
+                    instrument.security_type = Some(*value);
+                }
+                Field::Currency(value) if instrument.currency.is_none() => {
+                    instrument.currency = Some(*value);
+                }
+                _ => {}
+            }
+        }
+
+        Some(instrument)
+    }
+}
+
+impl<const IS_INIT: bool> MessageBuilder<IS_INIT> {
+    /// Adds the fields of the given [`Instrument`] to the message body, in canonical order (see
+    /// [`Instrument::into_fields`]).
+    #[must_use]
+    pub fn with_instrument(mut self, instrument: Instrument) -> MessageBuilder<true> {
+        self.inner.body.fields.extend(instrument.into_fields());
+
+        MessageBuilder {
+            inner: self.inner,
+            canonical: self.canonical,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::message::{
+        Message,
+        field::{
+            Field,
+            value::{
+                begin_string::BeginString, cfi_code::CFICode, currency::Currency,
+                msg_type::MsgType, security_id_source::SecurityIDSource,
+                security_type::SecurityType,
+            },
+        },
+        instrument::Instrument,
+    };
+
+    #[test]
+    fn round_trip() {
+        let instrument = Instrument {
+            symbol: b"SAP".to_vec(),
+            security_id: Some(b"DE0007164600".to_vec()),
+            security_id_source: Some(SecurityIDSource::Isin),
+            cfi_code: Some(CFICode::new(b"ESVUFR").unwrap()),
+            security_type: Some(SecurityType::CommonStock),
+            currency: Some(Currency::new(b"EUR").unwrap()),
+        };
+
+        let msg = Message::builder(BeginString::FIX44, MsgType::NewOrderSingle)
+            .with_field(Field::ClOrdID(b"ORDER-1".to_vec()))
+            .with_instrument(instrument.clone())
+            .build();
+
+        assert_eq!(
+            msg.fields().map(Field::tag).collect::<Vec<_>>(),
+            vec![11, 55, 48, 22, 461, 167, 15]
+        );
+
+        let decoded = Message::decode(msg.encode()).expect("message is valid");
+        assert_eq!(decoded.instrument(), Some(instrument));
+    }
+
+    #[test]
+    fn adjacent_fields_only() {
+        // the fields of the second related symbol are not mixed into the first instrument
+        let input = "8=FIX.4.4\x019=61\x0135=V\x01146=2\x0155=SAP\x0148=DE0007164600\x0122=4\x0155=MSFT\x01167=CS\x0115=USD\x0110=119\x01";
+        let instrument = Message::decode(input).unwrap().instrument().unwrap();
+
+        assert_eq!(instrument.symbol, b"SAP");
+        assert_eq!(instrument.security_id, Some(b"DE0007164600".to_vec()));
+        assert_eq!(instrument.security_type, None);
+        assert_eq!(instrument.currency, None);
+
+        // the instrument ends at the first field not belonging to it
+        let input =
+            "8=FIX.4.4\x019=38\x0135=D\x0155=SAP\x0122=4\x0154=1\x0148=DE0007164600\x0110=082\x01";
+        let instrument = Message::decode(input).unwrap().instrument().unwrap();
+
+        assert_eq!(instrument.security_id_source, Some(SecurityIDSource::Isin));
+        assert_eq!(instrument.security_id, None);
+    }
+
+    #[test]
+    fn no_instrument() {
+        let input = "8=FIX.4.4\x019=5\x0135=0\x0110=163\x01";
+        assert_eq!(Message::decode(input).unwrap().instrument(), None);
+    }
+}
@@ -0,0 +1,105 @@
+//! Implementation of the [`FixMap`], a lightweight `tag -> value` view of a FIX message.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::decoder;
+use crate::message::field::value::password::Password;
+
+/// Represents a decoded FIX message as a plain `tag -> value` lookup table.
+///
+/// Unlike [`Message`](crate::message::Message), values are not parsed into typed fields and the
+/// order of fields is not preserved. Repeated tags keep all of their values, in order of
+/// appearance. The framing fields `BeginString` (`8`), `BodyLength` (`9`), `MsgType` (`35`) and
+/// `CheckSum` (`10`) are included as well.
+///
+/// The [`Debug`](fmt::Debug) representation redacts the values of the password (`554`).
+#[derive(Default, Clone, PartialEq)]
+pub struct FixMap {
+    /// Values of all fields in the message, keyed by their tag.
+    fields: BTreeMap<u32, Vec<Vec<u8>>>,
+}
+
+impl FixMap {
+    /// Decodes a [`FixMap`] from given bytes. The message framing, `BodyLength` and `CheckSum`
+    /// are validated the same way as for [`Message::decode`](crate::message::Message::decode).
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::map::FixMap;
+    ///
+    /// let map = FixMap::decode("8=FIX.4.4\x019=5\x0135=0\x0110=163\x01").unwrap();
+    /// assert_eq!(map.get(35), Some(&b"0"[..]));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error`](decoder::Error) on invalid input.
+    pub fn decode(input: impl AsRef<[u8]>) -> Result<Self, decoder::Error> {
+        decoder::decode_map(input)
+    }
+
+    /// Returns the first value of the field with the given tag, if present.
+    #[must_use]
+    pub fn get(&self, tag: u32) -> Option<&[u8]> {
+        self.fields
+            .get(&tag)
+            .and_then(|values| values.first())
+            .map(Vec::as_slice)
+    }
+
+    /// Returns all values of the field with the given tag, in order of appearance. Returns an
+    /// empty slice if the field is not present.
+    #[must_use]
+    pub fn get_all(&self, tag: u32) -> &[Vec<u8>] {
+        self.fields.get(&tag).map_or(&[], Vec::as_slice)
+    }
+
+    /// Appends a value for the field with the given tag.
+    pub(crate) fn insert(&mut self, tag: u32, value: Vec<u8>) {
+        self.fields.entry(tag).or_default().push(value);
+    }
+}
+
+impl fmt::Debug for FixMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut fields = f.debug_map();
+
+        for (tag, values) in &self.fields {
+            if *tag == Password::tag() {
+                fields.entry(tag, &vec![format_args!("****"); values.len()]);
+            } else {
+                fields.entry(tag, values);
+            }
+        }
+
+        fields.finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::message::map::FixMap;
+
+    #[test]
+    fn repeated_tags() {
+        let mut map = FixMap::default();
+
+        map.insert(448, b"PARTY1".to_vec());
+        map.insert(448, b"PARTY2".to_vec());
+
+        assert_eq!(map.get(448), Some(&b"PARTY1"[..]));
+        assert_eq!(map.get_all(448), &[b"PARTY1".to_vec(), b"PARTY2".to_vec()]);
+    }
+
+    #[test]
+    fn debug_redacts_password() {
+        let mut map = FixMap::default();
+
+        map.insert(35, b"A".to_vec());
+        map.insert(554, b"secret".to_vec());
+
+        assert_eq!(format!("{map:?}"), "{35: [[65]], 554: [****]}");
+        assert_eq!(map.get(554), Some(&b"secret"[..]));
+    }
+}
@@ -0,0 +1,417 @@
+//! Implementation of structured access to and validation of `MarketDataRequest` (`35=V`)
+//! messages.
+
+use crate::message::{
+    Message,
+    field::{
+        Field,
+        value::{
+            aliases::{MDReqID, MarketDepth},
+            md_req_rej_reason::MDReqRejReason,
+            md_update_type::MDUpdateType,
+            msg_type::MsgType,
+            subscription_request_type::SubscriptionRequestType,
+        },
+    },
+};
+
+/// Depth of the order book requested by the `MarketDepth` (`264`) of a `MarketDataRequest`,
+/// modeling the special meaning of `0` and `1`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BookDepth {
+    /// Full book depth (`264=0`).
+    FullBook,
+
+    /// Top of book only (`264=1`).
+    TopOfBook,
+
+    /// The given number of best price levels (`264=N`, `N` > 1).
+    Levels(MarketDepth),
+}
+
+impl From<MarketDepth> for BookDepth {
+    fn from(value: MarketDepth) -> Self {
+        match value {
+            0 => BookDepth::FullBook,
+            1 => BookDepth::TopOfBook,
+            levels => BookDepth::Levels(levels),
+        }
+    }
+}
+
+impl From<BookDepth> for MarketDepth {
+    fn from(value: BookDepth) -> Self {
+        match value {
+            BookDepth::FullBook => 0,
+            BookDepth::TopOfBook => 1,
+            BookDepth::Levels(levels) => levels,
+        }
+    }
+}
+
+/// Possible errors when validating a `MarketDataRequest` (`35=V`) message.
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum MarketDataRequestError {
+    /// The message is not a `MarketDataRequest`.
+    #[error("message is not a market data request")]
+    NotMarketDataRequest,
+
+    /// A field required for the type of the request is missing.
+    #[error("market data request is missing required field with tag {0}")]
+    MissingField(u32),
+
+    /// A snapshot or subscription request does not request any symbol.
+    #[error("market data request does not request any symbol")]
+    NoSymbols,
+
+    /// The `SubscriptionRequestType` (`263`) of a request does not fit its purpose, e.g. an
+    /// unsubscribe request is validated against another unsubscribe request.
+    #[error("unexpected subscription request type")]
+    UnexpectedSubscriptionRequestType,
+
+    /// An unsubscribe request refers to a different `MDReqID` (`262`) than the subscription it
+    /// is meant to cancel.
+    #[error("unsubscribe request does not match the MDReqID of the subscription")]
+    MDReqIDMismatch,
+}
+
+impl Message {
+    /// Returns the requested [`BookDepth`], as given by the `MarketDepth` (`264`) of this message,
+    /// if present.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::{
+    ///     Message,
+    ///     field::value::{
+    ///         begin_string::BeginString, subscription_request_type::SubscriptionRequestType,
+    ///     },
+    ///     market_data::BookDepth,
+    /// };
+    ///
+    /// let msg = Message::market_data_request(
+    ///     BeginString::FIX44,
+    ///     b"REQ-1".to_vec(),
+    ///     SubscriptionRequestType::Subscribe,
+    ///     0,
+    ///     [b"MSFT".to_vec()],
+    /// );
+    ///
+    /// assert_eq!(msg.book_depth(), Some(BookDepth::FullBook));
+    /// ```
+    #[must_use]
+    pub fn book_depth(&self) -> Option<BookDepth> {
+        self.fields().find_map(|field| match field {
+            Field::MarketDepth(value) => Some(BookDepth::from(*value)),
+            _ => None,
+        })
+    }
+
+    /// Returns the `MDUpdateType` (`265`) of this message, if present.
+    #[must_use]
+    pub fn md_update_type(&self) -> Option<MDUpdateType> {
+        self.fields().find_map(|field| match field {
+            Field::MDUpdateType(value) => Some(*value),
+            _ => None,
+        })
+    }
+
+    /// Returns the `MDReqRejReason` (`281`) of this message, if present. It is carried by a
+    /// `MarketDataRequestReject` (`35=Y`) to explain why a request was rejected.
+    #[must_use]
+    pub fn md_req_rej_reason(&self) -> Option<MDReqRejReason> {
+        self.fields().find_map(|field| match field {
+            Field::MDReqRejReason(value) => Some(*value),
+            _ => None,
+        })
+    }
+
+    /// Validates that this `MarketDataRequest` (`35=V`) carries the fields required for its
+    /// `SubscriptionRequestType` (`263`).
+    ///
+    /// Every request must carry the `MDReqID` (`262`) and the `SubscriptionRequestType`. A
+    /// snapshot (`263=0`) or subscription (`263=1`) additionally requires the `MarketDepth`
+    /// (`264`) and at least one `Symbol` (`55`) in the related symbols group (`146`), while an
+    /// unsubscribe (`263=2`) only refers to the subscription by its `MDReqID`.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::{
+    ///     Message,
+    ///     field::value::{
+    ///         begin_string::BeginString, subscription_request_type::SubscriptionRequestType,
+    ///     },
+    /// };
+    ///
+    /// let unsubscribe = Message::market_data_request(
+    ///     BeginString::FIX44,
+    ///     b"REQ-1".to_vec(),
+    ///     SubscriptionRequestType::Unsubscribe,
+    ///     0,
+    ///     [],
+    /// );
+    ///
+    /// assert!(unsubscribe.validate_market_data_request().is_ok());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MarketDataRequestError`] describing the first violated requirement.
+    pub fn validate_market_data_request(&self) -> Result<(), MarketDataRequestError> {
+        if self.header.msg_type != MsgType::MarketDataRequest {
+            return Err(MarketDataRequestError::NotMarketDataRequest);
+        }
+
+        let mut md_req_id = false;
+        let mut subscription_request_type = None;
+        let mut market_depth = false;
+        let mut symbols = false;
+
+        for field in self.fields() {
+            match field {
+                Field::MDReqID(_) => md_req_id = true,
+                Field::SubscriptionRequestType(value) => subscription_request_type = Some(*value),
+                Field::MarketDepth(_) => market_depth = true,
+                Field::Symbol(_) => symbols = true,
+                _ => {}
+            }
+        }
+
+        if !md_req_id {
+            return Err(MarketDataRequestError::MissingField(262));
+        }
+
+        match subscription_request_type {
+            None => Err(MarketDataRequestError::MissingField(263)),
+            Some(SubscriptionRequestType::Unsubscribe) => Ok(()),
+            Some(_) if !market_depth => Err(MarketDataRequestError::MissingField(264)),
+            Some(_) if !symbols => Err(MarketDataRequestError::NoSymbols),
+            Some(_) => Ok(()),
+        }
+    }
+
+    /// Validates that this unsubscribe `MarketDataRequest` (`263=2`) cancels the given
+    /// `subscription`, i.e. that both requests are valid and carry the same `MDReqID` (`262`).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MarketDataRequestError`] if either request is invalid, see
+    /// [`Message::validate_market_data_request`], if this request is not an unsubscribe or the
+    /// `subscription` is not a snapshot plus updates request, or if their `MDReqID`s differ.
+    pub fn validate_unsubscribe(
+        &self,
+        subscription: &Message,
+    ) -> Result<(), MarketDataRequestError> {
+        self.validate_market_data_request()?;
+        subscription.validate_market_data_request()?;
+
+        if self.subscription_request_type() != Some(SubscriptionRequestType::Unsubscribe)
+            || subscription.subscription_request_type() != Some(SubscriptionRequestType::Subscribe)
+        {
+            return Err(MarketDataRequestError::UnexpectedSubscriptionRequestType);
+        }
+
+        if self.md_req_id() != subscription.md_req_id() {
+            return Err(MarketDataRequestError::MDReqIDMismatch);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the `MDReqID` (`262`) of this message, if present.
+    fn md_req_id(&self) -> Option<&MDReqID> {
+        self.fields().find_map(|field| match field {
+            Field::MDReqID(value) => Some(value),
+            _ => None,
+        })
+    }
+
+    /// Returns the `SubscriptionRequestType` (`263`) of this message, if present.
+    fn subscription_request_type(&self) -> Option<SubscriptionRequestType> {
+        self.fields().find_map(|field| match field {
+            Field::SubscriptionRequestType(value) => Some(*value),
+            _ => None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::message::{
+        Message,
+        field::{
+            Field,
+            value::{
+                begin_string::BeginString, md_req_rej_reason::MDReqRejReason,
+                md_update_action::MDUpdateAction, md_update_type::MDUpdateType, msg_type::MsgType,
+                subscription_request_type::SubscriptionRequestType,
+            },
+        },
+        market_data::{BookDepth, MarketDataRequestError},
+    };
+
+    fn request(subscription_request_type: SubscriptionRequestType, md_req_id: &[u8]) -> Message {
+        Message::market_data_request(
+            BeginString::FIX44,
+            md_req_id.to_vec(),
+            subscription_request_type,
+            1,
+            [b"MSFT".to_vec()],
+        )
+    }
+
+    #[test]
+    fn field_requirements() {
+        for subscription_request_type in [
+            SubscriptionRequestType::Snapshot,
+            SubscriptionRequestType::Subscribe,
+        ] {
+            let msg = request(subscription_request_type, b"REQ-1");
+            assert_eq!(msg.validate_market_data_request(), Ok(()));
+
+            let msg = Message::builder(BeginString::FIX44, MsgType::MarketDataRequest)
+                .with_field(Field::MDReqID(b"REQ-1".to_vec()))
+                .with_field(Field::SubscriptionRequestType(subscription_request_type))
+                .with_field(Field::MarketDepth(1))
+                .build();
+            assert_eq!(
+                msg.validate_market_data_request(),
+                Err(MarketDataRequestError::NoSymbols)
+            );
+
+            let msg = Message::builder(BeginString::FIX44, MsgType::MarketDataRequest)
+                .with_field(Field::MDReqID(b"REQ-1".to_vec()))
+                .with_field(Field::SubscriptionRequestType(subscription_request_type))
+                .build();
+            assert_eq!(
+                msg.validate_market_data_request(),
+                Err(MarketDataRequestError::MissingField(264))
+            );
+        }
+
+        // unsubscribe requests only carry the MDReqID
+        let msg = request(SubscriptionRequestType::Unsubscribe, b"REQ-1");
+        assert_eq!(
+            msg.fields().cloned().collect::<Vec<_>>(),
+            vec![
+                Field::MDReqID(b"REQ-1".to_vec()),
+                Field::SubscriptionRequestType(SubscriptionRequestType::Unsubscribe),
+            ]
+        );
+        assert_eq!(msg.validate_market_data_request(), Ok(()));
+
+        let msg = Message::builder(BeginString::FIX44, MsgType::MarketDataRequest)
+            .with_field(Field::SubscriptionRequestType(
+                SubscriptionRequestType::Unsubscribe,
+            ))
+            .build();
+        assert_eq!(
+            msg.validate_market_data_request(),
+            Err(MarketDataRequestError::MissingField(262))
+        );
+
+        let msg = Message::builder(BeginString::FIX44, MsgType::MarketDataRequest)
+            .with_field(Field::MDReqID(b"REQ-1".to_vec()))
+            .build();
+        assert_eq!(
+            msg.validate_market_data_request(),
+            Err(MarketDataRequestError::MissingField(263))
+        );
+    }
+
+    #[test]
+    fn depth_and_update_type() {
+        let input = "8=FIX.4.4\x019=47\x0135=V\x01262=REQ-1\x01263=1\x01264=5\x01265=1\x01146=1\x0155=MSFT\x0110=046\x01";
+        let msg = Message::decode(input).expect("market data request is valid");
+
+        assert_eq!(msg.book_depth(), Some(BookDepth::Levels(5)));
+        assert_eq!(msg.md_update_type(), Some(MDUpdateType::IncrementalRefresh));
+
+        let encoded = msg.encode();
+        assert!(encoded.windows(7).any(|window| window == b"\x01265=1\x01"));
+
+        assert_eq!(BookDepth::from(0), BookDepth::FullBook);
+        assert_eq!(BookDepth::from(1), BookDepth::TopOfBook);
+        assert_eq!(u32::from(BookDepth::Levels(10)), 10);
+
+        let msg = request(SubscriptionRequestType::Unsubscribe, b"REQ-1");
+        assert_eq!(msg.book_depth(), None);
+        assert_eq!(msg.md_update_type(), None);
+    }
+
+    #[test]
+    fn validate_unsubscribe() {
+        let subscription = request(SubscriptionRequestType::Subscribe, b"REQ-1");
+
+        let unsubscribe = request(SubscriptionRequestType::Unsubscribe, b"REQ-1");
+        assert_eq!(unsubscribe.validate_unsubscribe(&subscription), Ok(()));
+
+        let unsubscribe = request(SubscriptionRequestType::Unsubscribe, b"REQ-2");
+        assert_eq!(
+            unsubscribe.validate_unsubscribe(&subscription),
+            Err(MarketDataRequestError::MDReqIDMismatch)
+        );
+
+        let snapshot = request(SubscriptionRequestType::Snapshot, b"REQ-1");
+        assert_eq!(
+            snapshot.validate_unsubscribe(&subscription),
+            Err(MarketDataRequestError::UnexpectedSubscriptionRequestType)
+        );
+    }
+
+    #[test]
+    fn incremental_refresh() {
+        let input = "8=FIX.4.4\x019=97\x0135=X\x01262=REQ-1\x01268=3\x01\
+            279=0\x01269=0\x01270=100.5\x01271=10\x01\
+            279=1\x01269=1\x01270=101\x01271=5\x01\
+            279=2\x01269=0\x01270=99.5\x01\
+            10=188\x01";
+
+        let msg = Message::decode(input).expect("message is valid");
+
+        let actions: Vec<_> = msg
+            .fields()
+            .filter_map(|field| match field {
+                Field::MDUpdateAction(action) => Some(*action),
+                _ => None,
+            })
+            .collect();
+
+        assert_eq!(
+            actions,
+            vec![
+                MDUpdateAction::New,
+                MDUpdateAction::Change,
+                MDUpdateAction::Delete
+            ]
+        );
+        assert!(msg.fields().any(|field| *field == Field::NoMDEntries(3)));
+
+        // unknown actions are rejected
+        let input = "8=FIX.4.4\x019=11\x0135=X\x01279=9\x0110=017\x01";
+        assert!(Message::decode(input).is_err());
+    }
+
+    #[test]
+    fn request_reject() {
+        let input = "8=FIX.4.4\x019=93\x0135=Y\x0149=VENUE\x0156=CLIENT\x0134=7\x01\
+            52=20240102-09:30:00\x01262=REQ-1\x01281=3\x0158=Not entitled to level 2\x01\
+            10=126\x01";
+
+        let msg = Message::decode(input).expect("message is valid");
+
+        assert_eq!(msg.msg_type(), &MsgType::MarketDataRequestReject);
+        assert_eq!(
+            msg.md_req_rej_reason(),
+            Some(MDReqRejReason::InsufficientPermissions)
+        );
+        assert!(
+            msg.fields()
+                .any(|field| *field == Field::MDReqID(b"REQ-1".to_vec()))
+        );
+
+        // unknown reasons are rejected
+        let input = "8=FIX.4.4\x019=11\x0135=Y\x01281=Z\x0110=044\x01";
+        assert!(Message::decode(input).is_err());
+    }
+}
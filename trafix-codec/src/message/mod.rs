@@ -1,14 +1,54 @@
 //! Implementation of the message module.
 
+pub mod execution;
 pub mod field;
+#[cfg(feature = "fixml")]
+pub mod fixml;
+pub mod header;
+pub mod instrument;
+pub mod map;
+pub mod market_data;
+pub mod order;
+pub mod parties;
+pub mod reject;
+#[cfg(feature = "time")]
+pub mod skew;
+
+pub use crate::decoder::{
+    DecodeOptions, FieldValidator, Occurrence, Sections, decode_all_lines, decode_into,
+    decode_with_digest, field_offsets, frame_iter, frame_iter_with, split_sections,
+};
+pub use crate::encoder::{
+    EncodeOptions, Encoder, Error as EncodeError, FramingOverrides, append_checksum,
+};
+
+use std::collections::HashSet;
 
 use bytes::Bytes;
 
 use crate::{
-    decoder, encoder,
+    constants, decoder,
+    digest::BodyDigest,
+    encoder,
     message::field::{
         Field,
-        value::{begin_string::BeginString, msg_type::MsgType},
+        raw::RawField,
+        value::{
+            aliases::{
+                BidPx, ClOrdID, HeartBtInt, LastMsgSeqNumProcessed, MDReqID, MarketDepth,
+                MaxMessageSize, MsgSeqNum, NewSeqNo, OfferPx, OrderQty, OrigClOrdID, QuoteID,
+                SenderCompID, SendingTime, Symbol, TargetCompID, TestMessageIndicator, TestReqID,
+                Text, TransactTime, Username, XmlData,
+            },
+            begin_string::BeginString,
+            business_reject_reason::BusinessRejectReason,
+            comp_id::Interner,
+            encrypt_method::EncryptMethod,
+            msg_type::MsgType,
+            password::Password,
+            side::Side,
+            subscription_request_type::SubscriptionRequestType,
+        },
     },
 };
 
@@ -17,7 +57,7 @@ use crate::{
 /// The header always contains the protocol [`BeginString`] (tag 8)
 /// and the message type [`MsgType`] (tag 35), and may include
 /// additional session or routing fields.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Header {
     /// The `BeginString` identifying the FIX protocol version.
     #[allow(dead_code)]
@@ -31,10 +71,42 @@ pub struct Header {
     pub(crate) fields: Vec<Field>,
 }
 
+impl Header {
+    /// Encodes only this header, i.e. the `BeginString` (`8`), the `MsgType` (`35`) and the
+    /// optional header fields, each terminated with SOH, e.g. for caching the header of
+    /// messages that share it.
+    ///
+    /// The `BodyLength` (`9`) is excluded, as it depends on the body, so the encoded header is
+    /// not a valid prefix of a message as is: the caller frames the message by inserting the
+    /// `BodyLength` after the `BeginString`, and appending the body and the `CheckSum` (`10`).
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::{
+    ///     Message,
+    ///     field::{
+    ///         Field,
+    ///         value::{begin_string::BeginString, msg_type::MsgType},
+    ///     },
+    /// };
+    ///
+    /// let msg = Message::builder(BeginString::FIX44, MsgType::Heartbeat)
+    ///     .with_header(Field::MsgSeqNum(1))
+    ///     .with_field(Field::TestReqID(b"PING".to_vec()))
+    ///     .build();
+    ///
+    /// assert_eq!(msg.header().encode().as_ref(), b"8=FIX.4.4\x0135=0\x0134=1\x01");
+    /// ```
+    #[must_use]
+    pub fn encode(&self) -> Bytes {
+        encoder::encode_header(self)
+    }
+}
+
 /// Represents the body section of a FIX message.
 ///
 /// The body always contains the fields forming the message business content.
-#[derive(Default, Debug)]
+#[derive(Default, Debug, Clone)]
 pub struct Body {
     /// Collection of fields forming this message body.
     pub(crate) fields: Vec<Field>,
@@ -44,13 +116,17 @@ pub struct Body {
 ///
 /// The header holds protocol and session metadata, while the body
 /// carries message-specific fields defined by the message type.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Message {
     /// The message header containing version, type, and optional routing fields.
-    header: Header,
+    pub(crate) header: Header,
 
     /// The message body forming the message business content.
-    body: Body,
+    pub(crate) body: Body,
+
+    /// Tags of the repeated header fields dropped while decoding, in order of appearance, see
+    /// [`DecodeOptions::dedup_header_fields`].
+    pub(crate) dropped_tags: Vec<u32>,
 }
 
 impl Message {
@@ -81,140 +157,2127 @@ impl Message {
             inner: Message {
                 header,
                 body: Body::default(),
+                dropped_tags: Vec::new(),
             },
+            canonical: false,
         }
     }
 
-    /// Encodes this message into a valid, final wire-format `Bytes` buffer, auto populating fields
-    /// `BodyLength` and `Checksum`.
+    /// Creates a [`Message`] from the given fields, adding standard header fields, e.g. the
+    /// `SenderCompID` (`49`), to the header and all other fields to the body, each in the order
+    /// given.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::{
+    ///     Message,
+    ///     field::{
+    ///         Field,
+    ///         value::{begin_string::BeginString, msg_type::MsgType},
+    ///     },
+    /// };
+    ///
+    /// let fields = vec![Field::Text(b"Bye".to_vec()), Field::MsgSeqNum(2)];
+    /// let msg = Message::from_fields(BeginString::FIX44, MsgType::Logout, fields).unwrap();
+    ///
+    /// // the header field is encoded first
+    /// let expected = b"8=FIX.4.4\x019=17\x0135=5\x0134=2\x0158=Bye\x0110=125\x01";
+    /// assert_eq!(msg.encode().as_ref(), expected);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BuildError::ReservedTag`] if a field uses one of the framing tags (`8`, `9`,
+    /// `10` or `35`).
+    pub fn from_fields(
+        begin_string: BeginString,
+        msg_type: MsgType,
+        fields: impl IntoIterator<Item = Field>,
+    ) -> Result<Message, BuildError> {
+        let mut message = Message::builder(begin_string, msg_type).inner;
+
+        for field in fields {
+            let tag = field.tag();
+
+            if constants::STRUCTURAL_TAGS.contains(&tag) {
+                return Err(BuildError::ReservedTag(tag));
+            }
+
+            if constants::HEADER_TAGS.contains(&tag) {
+                message.header.fields.push(field);
+            } else {
+                message.body.fields.push(field);
+            }
+        }
+
+        Ok(message)
+    }
+
+    /// Creates a `BusinessMessageReject` (`35=j`) message rejecting a message of type
+    /// `ref_msg_type` for the given `reason`, with an optional explanatory `text`.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::{
+    ///     Message,
+    ///     field::value::{
+    ///         begin_string::BeginString, business_reject_reason::BusinessRejectReason,
+    ///         msg_type::MsgType,
+    ///     },
+    /// };
+    ///
+    /// let msg = Message::business_reject(
+    ///     BeginString::FIX44,
+    ///     MsgType::DontKnowTrade,
+    ///     BusinessRejectReason::UnsupportedMessageType,
+    ///     Some(b"DK not supported".to_vec()),
+    /// );
+    /// ```
     #[must_use]
-    pub fn encode(self) -> Bytes {
-        encoder::encode(&self.header, &self.body)
+    pub fn business_reject(
+        begin_string: BeginString,
+        ref_msg_type: MsgType,
+        reason: BusinessRejectReason,
+        text: Option<Text>,
+    ) -> Message {
+        let builder = Message::builder(begin_string, MsgType::BusinessMessageReject)
+            .with_field(Field::RefMsgType(ref_msg_type))
+            .with_field(Field::BusinessRejectReason(reason));
+
+        match text {
+            Some(text) => builder.with_field(Field::Text(text)).build(),
+            None => builder.build(),
+        }
     }
 
-    /// Decodes a [`Message`] from given bytes. See [`decode`] for more information.
+    /// Creates a `MarketDataRequest` (`35=V`) message identified by `md_req_id`, requesting
+    /// market data of the given `market_depth` for all of the given `symbols`.
     ///
-    /// # Errors
+    /// The symbols are emitted as the related symbols repeating group, i.e. `NoRelatedSym`
+    /// (`146`) followed by a `Symbol` (`55`) field for each of the symbols.
     ///
-    /// Returns [`Error`] on invalid input.
-    pub fn decode(input: impl AsRef<[u8]>) -> Result<Self, decoder::Error> {
-        decoder::decode(input)
+    /// An [`Unsubscribe`](SubscriptionRequestType::Unsubscribe) request only refers to the
+    /// subscription by its `md_req_id`, so the `market_depth` and `symbols` are ignored and the
+    /// message carries just the `MDReqID` (`262`) and `SubscriptionRequestType` (`263`). See
+    /// [`Message::validate_market_data_request`] for the requirements of each type.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::{
+    ///     Message,
+    ///     field::value::{
+    ///         begin_string::BeginString, subscription_request_type::SubscriptionRequestType,
+    ///     },
+    /// };
+    ///
+    /// let msg = Message::market_data_request(
+    ///     BeginString::FIX44,
+    ///     b"REQ-1".to_vec(),
+    ///     SubscriptionRequestType::Subscribe,
+    ///     1,
+    ///     [b"MSFT".to_vec(), b"AAPL".to_vec()],
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of symbols does not fit into a [`u32`].
+    #[must_use]
+    pub fn market_data_request(
+        begin_string: BeginString,
+        md_req_id: MDReqID,
+        subscription_request_type: SubscriptionRequestType,
+        market_depth: MarketDepth,
+        symbols: impl IntoIterator<Item = Symbol>,
+    ) -> Message {
+        let builder = Message::builder(begin_string, MsgType::MarketDataRequest)
+            .with_field(Field::MDReqID(md_req_id))
+            .with_field(Field::SubscriptionRequestType(subscription_request_type));
+
+        if subscription_request_type == SubscriptionRequestType::Unsubscribe {
+            return builder.build();
+        }
+
+        let symbols: Vec<Symbol> = symbols.into_iter().collect();
+        let no_related_sym = u32::try_from(symbols.len()).expect("number of symbols fits in u32");
+
+        let mut builder = builder
+            .with_field(Field::MarketDepth(market_depth))
+            .with_field(Field::NoRelatedSym(no_related_sym));
+
+        for symbol in symbols {
+            builder = builder.with_field(Field::Symbol(symbol));
+        }
+
+        builder.build()
     }
-}
 
-/// Generic builder for constructing [`Message`] instances.
-///
-/// The builder supports chaining calls to add header or body fields.
-/// Type-state (`IS_INIT`) tracks whether at least one body field was added,
-/// allowing [`MessageBuilder::build()`] to only be available after initialization.
-pub struct MessageBuilder<const IS_INIT: bool> {
-    /// The message being constructed.
-    inner: Message,
-}
+    /// Creates a `Quote` (`35=S`) message identified by `quote_id`, quoting the instrument
+    /// `symbol` at the given bid (`132`) and offer (`133`) prices. A one-sided quote carries only
+    /// one of the prices.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::{
+    ///     Message,
+    ///     field::value::{begin_string::BeginString, fix_decimal::FixDecimal},
+    /// };
+    ///
+    /// let msg = Message::quote(
+    ///     BeginString::FIX44,
+    ///     b"QUOTE-1".to_vec(),
+    ///     b"MSFT".to_vec(),
+    ///     Some(FixDecimal::new(10_120, 2)),
+    ///     Some(FixDecimal::new(10_130, 2)),
+    /// );
+    /// ```
+    #[must_use]
+    pub fn quote(
+        begin_string: BeginString,
+        quote_id: QuoteID,
+        symbol: Symbol,
+        bid_px: Option<BidPx>,
+        offer_px: Option<OfferPx>,
+    ) -> Message {
+        let mut builder = Message::builder(begin_string, MsgType::Quote)
+            .with_field(Field::QuoteID(quote_id))
+            .with_field(Field::Symbol(symbol));
 
-impl<const IS_INIT: bool> MessageBuilder<IS_INIT> {
-    /// Adds a field to the message header.
+        if let Some(bid_px) = bid_px {
+            builder = builder.with_field(Field::BidPx(bid_px));
+        }
+
+        if let Some(offer_px) = offer_px {
+            builder = builder.with_field(Field::OfferPx(offer_px));
+        }
+
+        builder.build()
+    }
+
+    /// Creates a spec-minimal `Logon` (`35=A`) message from `sender` to `target`, without
+    /// encryption (`98=0`) and with the given heartbeat interval in seconds (`108`).
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::{Message, field::value::begin_string::BeginString};
+    ///
+    /// let logon = Message::minimal_logon(
+    ///     BeginString::FIX44,
+    ///     b"SENDER".into(),
+    ///     b"TARGET".into(),
+    ///     30,
+    /// );
+    /// ```
     #[must_use]
-    pub fn with_header(mut self, field: Field) -> Self {
-        self.inner.header.fields.push(field);
+    pub fn minimal_logon(
+        begin_string: BeginString,
+        sender: SenderCompID,
+        target: TargetCompID,
+        heartbeat_secs: HeartBtInt,
+    ) -> Message {
+        Message::logon(begin_string, sender, target, heartbeat_secs, None, None)
+    }
 
-        self
+    /// Creates a `Logon` (`35=A`) message like [`Message::minimal_logon`], additionally
+    /// carrying the `Username` (`553`) and `Password` (`554`) of an authenticated session, if
+    /// given.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::{Message, field::value::begin_string::BeginString};
+    ///
+    /// let logon = Message::logon(
+    ///     BeginString::FIX44,
+    ///     b"SENDER".into(),
+    ///     b"TARGET".into(),
+    ///     30,
+    ///     Some(b"trader".to_vec()),
+    ///     Some(b"secret".into()),
+    /// );
+    ///
+    /// // the password is redacted in debug output
+    /// let debug = format!("{logon:?}");
+    /// assert!(debug.contains("554=****"));
+    /// assert!(!debug.contains("secret"));
+    /// ```
+    #[must_use]
+    pub fn logon(
+        begin_string: BeginString,
+        sender: SenderCompID,
+        target: TargetCompID,
+        heartbeat_secs: HeartBtInt,
+        username: Option<Username>,
+        password: Option<Password>,
+    ) -> Message {
+        let mut builder = Message::builder(begin_string, MsgType::Logon)
+            .with_header(Field::SenderCompID(sender))
+            .with_header(Field::TargetCompID(target))
+            .with_field(Field::EncryptMethod(EncryptMethod::None))
+            .with_field(Field::HeartBtInt(heartbeat_secs));
+
+        if let Some(username) = username {
+            builder = builder.with_field(Field::Username(username));
+        }
+
+        if let Some(password) = password {
+            builder = builder.with_field(Field::Password(password));
+        }
+
+        builder.build()
     }
 
-    /// Adds a field to the message body.
+    /// Creates a `SequenceReset` (`35=4`) message setting the next expected `MsgSeqNum` to
+    /// `new_seq_no`.
     ///
-    /// Each call appends a new [`Field`] in order of insertion.
-    /// Once at least one field has been added, the builder transitions
-    /// to an initialized state, enabling [`build`](Self::build).
+    /// With `gap_fill` set, the message is a gap fill (`123=Y`), replacing skipped messages
+    /// during a resend. Otherwise it is a hard reset, which omits the `GapFillFlag` and is
+    /// processed regardless of the current sequence numbers.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::{Message, field::value::begin_string::BeginString};
+    ///
+    /// let gap_fill = Message::sequence_reset(BeginString::FIX44, 10, true);
+    /// assert!(gap_fill.is_gap_fill());
+    ///
+    /// let reset = Message::sequence_reset(BeginString::FIX44, 1, false);
+    /// assert!(!reset.is_gap_fill());
+    /// ```
     #[must_use]
-    pub fn with_field(mut self, field: Field) -> MessageBuilder<true> {
-        self.inner.body.fields.push(field);
+    pub fn sequence_reset(
+        begin_string: BeginString,
+        new_seq_no: NewSeqNo,
+        gap_fill: bool,
+    ) -> Message {
+        let builder = Message::builder(begin_string, MsgType::SequenceReset);
 
-        MessageBuilder { inner: self.inner }
+        if gap_fill {
+            builder
+                .with_field(Field::GapFillFlag(true))
+                .with_field(Field::NewSeqNo(new_seq_no))
+                .build()
+        } else {
+            builder.with_field(Field::NewSeqNo(new_seq_no)).build()
+        }
     }
-}
 
-impl MessageBuilder<true> {
-    /// Finalizes and returns the fully constructed [`Message`].
+    /// Creates a `SequenceReset-Reset` (`35=4`) message, forcibly resynchronizing the session by
+    /// setting the next expected `MsgSeqNum` to `new_seq_no`.
+    ///
+    /// Unlike the hard reset produced by [`Message::sequence_reset`], the `GapFillFlag` (`123`)
+    /// is emitted explicitly as `N`, so the message cannot be mistaken for a gap fill by
+    /// counterparties defaulting the absent flag differently.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::{Message, field::value::begin_string::BeginString};
+    ///
+    /// let reset = Message::sequence_reset_reset(BeginString::FIX44, 100);
+    /// assert!(!reset.is_gap_fill());
+    /// ```
+    #[must_use]
+    pub fn sequence_reset_reset(begin_string: BeginString, new_seq_no: NewSeqNo) -> Message {
+        Message::builder(begin_string, MsgType::SequenceReset)
+            .with_field(Field::GapFillFlag(false))
+            .with_field(Field::NewSeqNo(new_seq_no))
+            .build()
+    }
+
+    /// Creates an `OrderCancelRequest` (`35=F`) message, requesting the cancellation of the
+    /// order identified by `orig_cl_ord_id`. The request itself is identified by `cl_ord_id`,
+    /// which becomes the `OrigClOrdID` of any subsequent request on the same order.
     ///
     /// Example usage:
     /// ```
     /// use trafix_codec::message::{
     ///     Message,
-    ///     field::{
-    ///         Field,
-    ///         value::{begin_string::BeginString, msg_type::MsgType},
+    ///     field::value::{
+    ///         begin_string::BeginString, fix_decimal::FixDecimal, local_mkt_date::LocalMktDate,
+    ///         side::Side, utc_timestamp::UtcTimestamp,
     ///     },
     /// };
     ///
-    /// let msg = Message::builder(BeginString::FIX44, MsgType::Logout)
-    ///     .with_field(Field::Custom { tag: 58, value: b"Bye".to_vec() })
-    ///     .build();
+    /// let date = LocalMktDate::new(2018, 9, 20).unwrap();
+    ///
+    /// let cancel = Message::order_cancel_request(
+    ///     BeginString::FIX44,
+    ///     b"ORDER-1".to_vec(),
+    ///     b"CANCEL-1".to_vec(),
+    ///     b"MSFT".to_vec(),
+    ///     Side::Buy,
+    ///     FixDecimal::new(7000, 0),
+    ///     UtcTimestamp::new(date, 18, 14, 19).unwrap(),
+    /// );
     /// ```
     #[must_use]
-    pub fn build(self) -> Message {
-        self.inner
+    pub fn order_cancel_request(
+        begin_string: BeginString,
+        orig_cl_ord_id: OrigClOrdID,
+        cl_ord_id: ClOrdID,
+        symbol: Symbol,
+        side: Side,
+        order_qty: OrderQty,
+        transact_time: TransactTime,
+    ) -> Message {
+        Message::builder(begin_string, MsgType::OrderCancelRequest)
+            .with_field(Field::OrigClOrdID(orig_cl_ord_id))
+            .with_field(Field::ClOrdID(cl_ord_id))
+            .with_field(Field::Symbol(symbol))
+            .with_field(Field::Side(side))
+            .with_field(Field::TransactTime(transact_time))
+            .with_field(Field::OrderQty(order_qty))
+            .build()
     }
-}
-
-#[cfg(test)]
-mod test {
-    use crate::message::{
-        Message,
-        field::{
-            Field,
-            value::{begin_string::BeginString, msg_type::MsgType},
-        },
-    };
 
-    #[test]
-    fn basic_builder() {
-        let builder = Message::builder(BeginString::FIX44, MsgType::Logon);
+    /// Creates a `Logout` (`35=5`) message rejecting the `incoming` `Logon` (`35=A`), carrying
+    /// the given `reason` as `Text` (`58`).
+    ///
+    /// The reply is addressed back to the initiator: its `SenderCompID` (`49`) is the
+    /// `TargetCompID` of the logon and vice versa, see [`Message::reply_comp_ids`]. The
+    /// `BeginString` is taken over from the logon as well.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::{
+    ///     Message,
+    ///     field::value::{begin_string::BeginString, msg_type::MsgType},
+    /// };
+    ///
+    /// let logon =
+    ///     Message::minimal_logon(BeginString::FIX44, b"CLIENT".into(), b"VENUE".into(), 30);
+    ///
+    /// let logout = Message::logout_reply(&logon, b"unknown comp id").unwrap();
+    /// assert_eq!(logout.msg_type(), &MsgType::Logout);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ReplyError::NotLogon`] if `incoming` is not a `Logon`, and
+    /// [`ReplyError::MissingCompID`] if it lacks its `SenderCompID` or `TargetCompID`.
+    pub fn logout_reply(incoming: &Message, reason: &[u8]) -> Result<Message, ReplyError> {
+        if incoming.header.msg_type != MsgType::Logon {
+            return Err(ReplyError::NotLogon);
+        }
 
-        // header
-        assert_eq!(builder.inner.header.begin_string, BeginString::FIX44);
-        assert_eq!(builder.inner.header.msg_type, MsgType::Logon);
+        let (sender, target) = incoming.reply_comp_ids()?;
 
-        // body
-        assert_eq!(builder.inner.body.fields.len(), 0);
+        Ok(
+            Message::builder(incoming.header.begin_string, MsgType::Logout)
+                .with_header(Field::SenderCompID(sender))
+                .with_header(Field::TargetCompID(target))
+                .with_field(Field::Text(reason.to_vec()))
+                .build(),
+        )
     }
 
-    #[test]
-    fn simple_message() {
-        let builder = Message::builder(BeginString::FIX44, MsgType::Logout);
-
-        let custom_header_field = Field::Custom {
-            tag: 22,
-            value: b"custom_header_field".to_vec(),
-        };
+    /// Returns the `SenderCompID` (`49`) and `TargetCompID` (`56`) of a reply to this message,
+    /// i.e. the `TargetCompID` and `SenderCompID` of this message, in that order.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::{Message, field::value::begin_string::BeginString};
+    ///
+    /// let logon =
+    ///     Message::minimal_logon(BeginString::FIX44, b"CLIENT".into(), b"VENUE".into(), 30);
+    ///
+    /// let (sender, target) = logon.reply_comp_ids().unwrap();
+    /// assert_eq!(b"VENUE".to_vec(), sender);
+    /// assert_eq!(b"CLIENT".to_vec(), target);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ReplyError::MissingCompID`] naming the tag of the first missing comp ID.
+    pub fn reply_comp_ids(&self) -> Result<(SenderCompID, TargetCompID), ReplyError> {
+        let (mut sender, mut target) = (None, None);
 
-        let custom_body_field1 = Field::Custom {
-            tag: 40000,
-            value: b"custom_body_field1".to_vec(),
-        };
+        for field in self.fields() {
+            match field {
+                Field::SenderCompID(value) if sender.is_none() => sender = Some(value),
+                Field::TargetCompID(value) if target.is_none() => target = Some(value),
+                _ => {}
+            }
+        }
 
-        let custom_body_field2 = Field::Custom {
-            tag: 50000,
-            value: b"custom_body_field2".to_vec(),
-        };
+        let sender = sender.ok_or(ReplyError::MissingCompID(49))?;
+        let target = target.ok_or(ReplyError::MissingCompID(56))?;
 
-        let msg = builder
-            .with_header(custom_header_field.clone())
-            .with_field(custom_body_field1.clone())
-            .with_field(custom_body_field2.clone())
-            .build();
+        Ok((target.clone(), sender.clone()))
+    }
 
-        // auto-header
-        assert_eq!(msg.header.begin_string, BeginString::FIX44);
-        assert_eq!(msg.header.msg_type, MsgType::Logout);
+    /// Returns `true` if this message is a gap filling `SequenceReset` (`35=4`), i.e. it has the
+    /// `GapFillFlag` (`123`) set to `Y`.
+    ///
+    /// A `SequenceReset` without the flag, or with the flag set to `N`, is a hard reset.
+    #[must_use]
+    pub fn is_gap_fill(&self) -> bool {
+        self.header.msg_type == MsgType::SequenceReset
+            && self
+                .fields()
+                .any(|field| matches!(field, Field::GapFillFlag(true)))
+    }
 
-        // custom header
-        assert_eq!(msg.header.fields.clone().len(), 1);
-        assert_eq!(msg.header.fields[0], custom_header_field);
+    /// Returns the `LastMsgSeqNumProcessed` (`369`) header field of this message, if present,
+    /// i.e. the last sequence number the sender has consumed from the receiver.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::{
+    ///     Message,
+    ///     field::{
+    ///         Field,
+    ///         value::{begin_string::BeginString, msg_type::MsgType},
+    ///     },
+    /// };
+    ///
+    /// let encoded = Message::builder(BeginString::FIX44, MsgType::Heartbeat)
+    ///     .with_header(Field::MsgSeqNum(8))
+    ///     .with_header(Field::LastMsgSeqNumProcessed(5))
+    ///     .with_field(Field::TestReqID(b"PING".to_vec()))
+    ///     .build()
+    ///     .encode();
+    ///
+    /// let decoded = Message::decode(encoded).unwrap();
+    /// assert_eq!(decoded.last_msg_seq_num_processed(), Some(5));
+    /// ```
+    #[must_use]
+    pub fn last_msg_seq_num_processed(&self) -> Option<LastMsgSeqNumProcessed> {
+        self.fields().find_map(|field| match field {
+            Field::LastMsgSeqNumProcessed(seq_num) => Some(*seq_num),
+            _ => None,
+        })
+    }
+
+    /// Returns the raw bytes of the `XmlData` (`213`) field of this message, if present. The
+    /// payload is returned as received, including any embedded SOH bytes.
+    #[must_use]
+    pub fn xml_data(&self) -> Option<&XmlData> {
+        self.fields().find_map(|field| match field {
+            Field::XmlData(xml_data) => Some(xml_data),
+            _ => None,
+        })
+    }
+
+    /// Returns the [`BeginString`] of this message.
+    #[must_use]
+    pub fn begin_string(&self) -> BeginString {
+        self.header.begin_string
+    }
+
+    /// Returns the header section of this message. Decoded messages keep all fields in the
+    /// order they were received in, so their header only holds the `BeginString` and `MsgType`.
+    #[must_use]
+    pub fn header(&self) -> &Header {
+        &self.header
+    }
+
+    /// Returns the [`MsgType`] of this message.
+    #[must_use]
+    pub fn msg_type(&self) -> &MsgType {
+        &self.header.msg_type
+    }
+
+    /// Returns an iterator over all header and body fields of this message, in order. The
+    /// `BeginString` and `MsgType` are not included, see [`Message::begin_string`] and
+    /// [`Message::msg_type`].
+    pub fn fields(&self) -> impl Iterator<Item = &Field> {
+        self.header.fields.iter().chain(&self.body.fields)
+    }
+
+    /// Returns the set of tags of all header and body fields of this message, so that the
+    /// presence of optional fields can be checked without scanning the fields repeatedly. As with
+    /// [`Message::fields`], the `BeginString` and `MsgType` are not included.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::{
+    ///     Message,
+    ///     field::{
+    ///         Field,
+    ///         value::{begin_string::BeginString, msg_type::MsgType},
+    ///     },
+    /// };
+    ///
+    /// let msg = Message::builder(BeginString::FIX44, MsgType::Logout)
+    ///     .with_field(Field::MsgSeqNum(2))
+    ///     .with_field(Field::Text(b"Bye".to_vec()))
+    ///     .build();
+    ///
+    /// let tags = msg.present_tags();
+    /// assert!(tags.contains(&58));
+    /// assert!(!tags.contains(&52));
+    /// ```
+    #[must_use]
+    pub fn present_tags(&self) -> HashSet<u32> {
+        self.fields().map(Field::tag).collect()
+    }
+
+    /// Returns the tags of all fields stored as [`Field::Custom`], in order of appearance,
+    /// including repeated tags. These are usually the tags without a typed [`Field`] variant,
+    /// which makes this suitable for auditing which fields of a stream are not modeled yet.
+    ///
+    /// Note that typed fields are stored as [`Field::Custom`] as well when decoding with
+    /// [`DecodeOptions::raw_fields`], or with [`DecodeOptions::lenient_enums`] and
+    /// [`DecodeOptions::lenient_values`] if their value does not parse. See
+    /// [`Message::degraded_tags`] for the latter.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::Message;
+    ///
+    /// let input = "8=FIX.4.4\x019=23\x0135=0\x0134=1\x019000=x\x0158=Hi\x0110=132\x01";
+    /// let msg = Message::decode(input).unwrap();
+    ///
+    /// assert_eq!(msg.custom_tags().collect::<Vec<_>>(), vec![9000]);
+    /// ```
+    pub fn custom_tags(&self) -> impl Iterator<Item = u32> {
+        self.fields().filter_map(|field| match field {
+            Field::Custom { tag, .. } => Some(*tag),
+            _ => None,
+        })
+    }
+
+    /// Returns the tags of all [`Field::Custom`] fields whose tag has a typed [`Field`] variant,
+    /// in order of appearance. When decoding with [`DecodeOptions::lenient_values`] or
+    /// [`DecodeOptions::lenient_enums`], these are the fields whose value failed to parse.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::{DecodeOptions, Message};
+    ///
+    /// let input = "8=FIX.4.4\x019=32\x0135=0\x0134=99999999999999999999999\x0110=151\x01";
+    /// assert!(Message::decode(input).is_err());
+    ///
+    /// let options = DecodeOptions {
+    ///     lenient_values: true,
+    ///     ..DecodeOptions::default()
+    /// };
+    /// let msg = Message::decode_with(input, &options).unwrap();
+    ///
+    /// assert_eq!(msg.degraded_tags().collect::<Vec<_>>(), vec![34]);
+    /// ```
+    pub fn degraded_tags(&self) -> impl Iterator<Item = u32> {
+        self.custom_tags()
+            .filter(|tag| Field::tag_name(*tag).is_some())
+    }
+
+    /// Returns the tags of the repeated header fields dropped while decoding with
+    /// [`DecodeOptions::dedup_header_fields`], in order of appearance, once per dropped field.
+    /// Messages that were not decoded, or decoded without deduplication, have none.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::{DecodeOptions, Message, Occurrence};
+    ///
+    /// let input = "8=FIX.4.4\x019=15\x0135=0\x0134=2\x0134=3\x0110=131\x01";
+    /// let options = DecodeOptions {
+    ///     dedup_header_fields: Some(Occurrence::First),
+    ///     ..DecodeOptions::default()
+    /// };
+    /// let msg = Message::decode_with(input, &options).unwrap();
+    ///
+    /// assert_eq!(msg.dropped_tags(), [34]);
+    /// ```
+    #[must_use]
+    pub fn dropped_tags(&self) -> &[u32] {
+        &self.dropped_tags
+    }
+
+    /// Replaces the value of the first field with the given tag in place, returning whether a
+    /// field was replaced.
+    ///
+    /// Values of typed fields are validated first: if `new_value` does not parse into the type
+    /// of the field, the field is left untouched and `false` is returned. Values of
+    /// [`Field::Custom`] fields are replaced as given, and [`Field::Raw`] fields are replaced by
+    /// a [`Field::Custom`] with the given value. Values containing the SOH delimiter are
+    /// rejected for all fields, as they would corrupt the encoded message. The `BeginString` and
+    /// `MsgType` are not stored as fields, so they cannot be replaced this way.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::{
+    ///     Message,
+    ///     field::{
+    ///         Field,
+    ///         value::{begin_string::BeginString, msg_type::MsgType},
+    ///     },
+    /// };
+    ///
+    /// let mut msg = Message::builder(BeginString::FIX44, MsgType::Heartbeat)
+    ///     .with_field(Field::MsgSeqNum(1))
+    ///     .build();
+    ///
+    /// assert!(msg.replace_field_value(34, b"2"));
+    /// assert!(!msg.replace_field_value(34, b"two"));
+    /// assert_eq!(msg.fields().next(), Some(&Field::MsgSeqNum(2)));
+    /// ```
+    pub fn replace_field_value(&mut self, tag: u32, new_value: &[u8]) -> bool {
+        if new_value.contains(&constants::SOH) {
+            return false;
+        }
+
+        let Some(field) = self
+            .header
+            .fields
+            .iter_mut()
+            .chain(&mut self.body.fields)
+            .find(|field| field.tag() == tag)
+        else {
+            return false;
+        };
+
+        match field {
+            Field::Custom { value, .. } => {
+                new_value.clone_into(value);
+                return true;
+            }
+            Field::Raw(raw) => {
+                *field = Field::Custom {
+                    tag: raw.tag(),
+                    value: new_value.to_vec(),
+                };
+                return true;
+            }
+            _ => {}
+        }
+
+        match Field::try_new(tag, new_value) {
+            Ok(replacement) => {
+                *field = replacement;
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Rearranges the fields of this message into the canonical order, e.g. after decoding a
+    /// message whose fields were sent in an arbitrary order.
+    ///
+    /// Fields of the standard header are moved into the header, in the order defined by the
+    /// FIX specification. The remaining fields are sorted by tag, keeping fields with the same
+    /// tag in their relative order. Fields of the standard trailer come last.
+    ///
+    /// Entries of known repeating groups, e.g. the parties or the hops of the header, stay
+    /// together after their `NumInGroup` field, in their original order. A group ends at the
+    /// first field not belonging to its entries, or at a new entry beyond its `NumInGroup`
+    /// count.
+    ///
+    /// Normalizing changes the order of the encoded fields, so a decoded message is no longer
+    /// encoded into the exact bytes it was decoded from.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::{Message, field::Field};
+    ///
+    /// let input = "8=FIX.4.4\x019=15\x0135=0\x0158=x\x0134=1\x0110=205\x01";
+    /// let mut msg = Message::decode(input).unwrap();
+    /// msg.normalize();
+    ///
+    /// let tags: Vec<_> = msg.fields().map(Field::tag).collect();
+    /// assert_eq!(tags, [34, 58]);
+    /// ```
+    pub fn normalize(&mut self) {
+        /// Repeating group whose entries are currently being collected.
+        struct Group {
+            /// Tags of the fields belonging to the group entries.
+            members: &'static [&'static [u32]],
+
+            /// Tag of the field starting each entry, once the first entry is found.
+            first: Option<u32>,
+
+            /// Number of entries given by the `NumInGroup` field, but not found yet.
+            remaining: usize,
+
+            /// Whether the group is part of the header, e.g. the hops.
+            in_header: bool,
+        }
+
+        let fields = std::mem::take(&mut self.header.fields)
+            .into_iter()
+            .chain(std::mem::take(&mut self.body.fields));
+
+        // each repeating group forms a single unit along with its entries, other fields form a
+        // unit of their own
+        let mut header: Vec<(usize, Vec<Field>)> = Vec::new();
+        let mut units: Vec<Vec<Field>> = Vec::new();
+        let mut trailer = Vec::new();
+        let mut group: Option<Group> = None;
+
+        for field in fields {
+            let tag = field.tag();
+
+            if let Some(current) = &mut group
+                && current.members.iter().any(|members| members.contains(&tag))
+            {
+                let first = *current.first.get_or_insert(tag);
+
+                // the group ends at a new entry beyond its NumInGroup count
+                if tag != first || current.remaining > 0 {
+                    if tag == first {
+                        current.remaining -= 1;
+                    }
+
+                    let unit = if current.in_header {
+                        header.last_mut().map(|(_, unit)| unit)
+                    } else {
+                        units.last_mut()
+                    };
+
+                    if let Some(unit) = unit {
+                        unit.push(field);
+                        continue;
+                    }
+                }
+            }
+
+            group = constants::REPEATING_GROUPS
+                .iter()
+                .find(|(count_tag, _)| *count_tag == tag)
+                .map(|(_, members)| Group {
+                    members,
+                    first: None,
+                    remaining: std::str::from_utf8(&field.value_ref())
+                        .ok()
+                        .and_then(|count| count.parse().ok())
+                        .unwrap_or(usize::MAX),
+                    in_header: constants::HEADER_TAGS.contains(&tag),
+                });
+
+            if let Some(position) = constants::HEADER_TAGS.iter().position(|t| *t == tag) {
+                header.push((position, vec![field]));
+            } else if let Some(position) = constants::TRAILER_TAGS.iter().position(|t| *t == tag) {
+                trailer.push((position, field));
+            } else {
+                units.push(vec![field]);
+            }
+        }
+
+        // stable sorts, so repeated tags keep their relative order
+        header.sort_by_key(|(position, _)| *position);
+        trailer.sort_by_key(|(position, _)| *position);
+        units.sort_by_key(|unit| unit[0].tag());
+
+        self.header.fields = header.into_iter().flat_map(|(_, unit)| unit).collect();
+        self.body.fields = units
+            .into_iter()
+            .flatten()
+            .chain(trailer.into_iter().map(|(_, field)| field))
+            .collect();
+    }
+
+    /// Returns the `TestReqID` (`112`) of this message, carried by a `TestRequest` and echoed
+    /// back by the `Heartbeat` responding to it.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::{
+    ///     Message,
+    ///     field::{
+    ///         Field,
+    ///         value::{begin_string::BeginString, msg_type::MsgType},
+    ///     },
+    /// };
+    ///
+    /// let msg = Message::builder(BeginString::FIX44, MsgType::TestRequest)
+    ///     .with_field(Field::TestReqID(b"PING-1".to_vec()))
+    ///     .build();
+    ///
+    /// assert_eq!(msg.test_req_id(), Some(&b"PING-1".to_vec()));
+    /// ```
+    #[must_use]
+    pub fn test_req_id(&self) -> Option<&TestReqID> {
+        self.fields().find_map(|field| match field {
+            Field::TestReqID(test_req_id) => Some(test_req_id),
+            _ => None,
+        })
+    }
+
+    /// Returns the `MaxMessageSize` (`383`) of this message, if present. Sent in a `Logon` to
+    /// bound the size of messages the counterparty may send.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::{
+    ///     Message,
+    ///     field::{
+    ///         Field,
+    ///         value::{begin_string::BeginString, msg_type::MsgType},
+    ///     },
+    /// };
+    ///
+    /// let msg = Message::builder(BeginString::FIX44, MsgType::Logon)
+    ///     .with_field(Field::MaxMessageSize(4096))
+    ///     .build();
+    ///
+    /// assert_eq!(msg.max_message_size(), Some(4096));
+    /// ```
+    #[must_use]
+    pub fn max_message_size(&self) -> Option<MaxMessageSize> {
+        self.fields().find_map(|field| match field {
+            Field::MaxMessageSize(max_message_size) => Some(*max_message_size),
+            _ => None,
+        })
+    }
+
+    /// Returns the `TestMessageIndicator` (`464`) of this message, if present.
+    #[must_use]
+    pub fn test_message_indicator(&self) -> Option<TestMessageIndicator> {
+        self.fields().find_map(|field| match field {
+            Field::TestMessageIndicator(indicator) => Some(*indicator),
+            _ => None,
+        })
+    }
+
+    /// Encodes this message into a valid, final wire-format `Bytes` buffer, auto populating fields
+    /// `BodyLength` and `Checksum`.
+    #[must_use]
+    pub fn encode(self) -> Bytes {
+        encoder::encode(&self.header, &self.body)
+    }
+
+    /// Encodes this message the same way as [`Message::encode`], with the wire format configured
+    /// by the given [`EncodeOptions`].
+    #[must_use]
+    pub fn encode_with(self, options: &EncodeOptions) -> Bytes {
+        encoder::encode_with(&self.header, &self.body, options)
+    }
+
+    /// Encodes this message for forwarding it unchanged, copying the raw body the given
+    /// [`BodyDigest`] was calculated over instead of encoding the fields, and calculating the
+    /// `CheckSum` from the digest instead of summing the body again.
+    ///
+    /// This is meant for transparent proxies forwarding messages decoded with
+    /// [`decode_with_digest`]. Only the `BeginString` of this message is encoded, e.g. to rewrite
+    /// it, so the caller must make sure that no other field was changed since decoding: changes
+    /// to the `MsgType`, header or body fields are not reflected in the output. The body is
+    /// emitted exactly as it was received, e.g. keeping the leading zeros of `34=0002`.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::{DecodeOptions, Message, decode_with_digest};
+    ///
+    /// let input = "8=FIX.4.4\x019=10\x0135=0\x0134=1\x0110=165\x01";
+    /// let (msg, digest) = decode_with_digest(input, &DecodeOptions::default()).unwrap();
+    ///
+    /// assert_eq!(msg.encode_with_body_digest(&digest), input.as_bytes());
+    /// ```
+    #[must_use]
+    pub fn encode_with_body_digest(self, body_digest: &BodyDigest<'_>) -> Bytes {
+        encoder::encode_with_body_digest(&self.header, body_digest)
+    }
+
+    /// Encodes this message the same way as [`Message::encode`], but emits the `BodyLength` and
+    /// `Checksum` values set in the given [`FramingOverrides`] instead of calculating them.
+    ///
+    /// This is strictly a testing aid, used to craft deliberately malformed messages.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::{
+    ///     FramingOverrides, Message,
+    ///     field::{
+    ///         Field,
+    ///         value::{begin_string::BeginString, msg_type::MsgType},
+    ///     },
+    /// };
+    ///
+    /// let overrides = FramingOverrides {
+    ///     body_length: Some(1),
+    ///     ..FramingOverrides::default()
+    /// };
+    ///
+    /// let encoded = Message::builder(BeginString::FIX44, MsgType::Heartbeat)
+    ///     .with_field(Field::MsgSeqNum(1))
+    ///     .build()
+    ///     .encode_with_overrides(overrides);
+    ///
+    /// assert!(Message::decode(encoded).is_err());
+    /// ```
+    #[must_use]
+    pub fn encode_with_overrides(self, overrides: FramingOverrides) -> Bytes {
+        encoder::encode_with_overrides(&self.header, &self.body, overrides)
+    }
+
+    /// Same as [`Message::encode`], but fails instead of producing a message whose `BodyLength`
+    /// exceeds [`MAX_BODY_LENGTH`](encoder::MAX_BODY_LENGTH), which strict counterparties would
+    /// reject, or which contains a field with one of the framing tags (`8`, `9`, `10` or `35`)
+    /// emitted by the encoder itself.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EncodeError`] if the message body is too long, or if a field uses a reserved
+    /// tag. Use [`MessageBuilder::try_build`] to validate the message contents beforehand.
+    pub fn try_encode(self) -> Result<Bytes, EncodeError> {
+        encoder::try_encode(&self.header, &self.body)
+    }
+
+    /// Same as [`Message::try_encode`], with the wire format configured by the given
+    /// [`EncodeOptions`]. Also fails if a `Text` (`58`) field is longer than
+    /// [`EncodeOptions::max_text_len`].
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::{
+    ///     EncodeError, EncodeOptions, Message,
+    ///     field::{
+    ///         Field,
+    ///         value::{begin_string::BeginString, msg_type::MsgType, text},
+    ///     },
+    /// };
+    ///
+    /// let options = EncodeOptions {
+    ///     max_text_len: Some(8),
+    ///     ..EncodeOptions::default()
+    /// };
+    ///
+    /// let reason = b"unknown security";
+    /// let logout = |text| {
+    ///     Message::builder(BeginString::FIX44, MsgType::Logout)
+    ///         .with_field(Field::Text(text))
+    ///         .build()
+    /// };
+    ///
+    /// assert_eq!(
+    ///     logout(reason.to_vec()).try_encode_with(&options),
+    ///     Err(EncodeError::TextTooLong { length: 16, max: 8 })
+    /// );
+    /// assert!(logout(text::truncated(reason, 8)).try_encode_with(&options).is_ok());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EncodeError`] if the message body is too long, if a field uses a reserved tag,
+    /// or if a `Text` field is too long.
+    pub fn try_encode_with(self, options: &EncodeOptions) -> Result<Bytes, EncodeError> {
+        encoder::try_encode_with(&self.header, &self.body, options)
+    }
+
+    /// Decodes a [`Message`] from given bytes. See [`decode`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error`] on invalid input.
+    pub fn decode(input: impl AsRef<[u8]>) -> Result<Self, decoder::Error> {
+        decoder::decode(input)
+    }
+
+    /// Decodes a [`Message`] from given bytes, taking the `SenderCompID` and `TargetCompID`
+    /// values from the given [`Interner`]. Within a session these identifiers are the same in
+    /// every message, so interning them avoids allocating them over and over again.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::{Message, field::value::comp_id::Interner};
+    ///
+    /// let interner = Interner::new();
+    /// let input = "8=FIX.4.4\x019=15\x0135=0\x0149=SENDER\x0110=064\x01";
+    ///
+    /// let first = Message::decode_with_interner(input, &interner).unwrap();
+    /// let second = Message::decode_with_interner(input, &interner).unwrap();
+    ///
+    /// assert_eq!(interner.len(), 1);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error`] on invalid input.
+    pub fn decode_with_interner(
+        input: impl AsRef<[u8]>,
+        interner: &Interner,
+    ) -> Result<Self, decoder::Error> {
+        decoder::decode_with_interner(input, interner)
+    }
+
+    /// Decodes a [`Message`] from given bytes, additionally checking that the body fields
+    /// mandatory for its [`MsgType`] are present, e.g. `TransactTime` (`60`) on
+    /// `NewOrderSingle` and cancel/replace messages.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::Message;
+    ///
+    /// // `NewOrderSingle` without `TransactTime`.
+    /// let input = "8=FIX.4.4\x019=24\x0135=D\x0111=ORDER-1\x0155=MSFT\x0110=068\x01";
+    ///
+    /// assert!(Message::decode(input).is_ok());
+    /// assert!(Message::decode_validated(input).is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error`] on invalid input or a missing mandatory field.
+    pub fn decode_validated(input: impl AsRef<[u8]>) -> Result<Self, decoder::Error> {
+        decoder::decode_validated(input)
+    }
+
+    /// Reads the [`BeginString`] of a FIX message without decoding the rest of it. The bytes
+    /// do not need to contain a complete, nor a valid message, which makes this suitable for
+    /// cheap routing of messages by protocol version.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::{Message, field::value::begin_string::BeginString};
+    ///
+    /// let begin_string = Message::peek_begin_string("8=FIX.4.4\x019=").unwrap();
+    /// assert_eq!(begin_string, BeginString::FIX44);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error`] if the bytes do not start with a `BeginString` field, or if the FIX
+    /// version is not recognized, in which case the raw version bytes are contained in the
+    /// error.
+    pub fn peek_begin_string(input: impl AsRef<[u8]>) -> Result<BeginString, decoder::Error> {
+        decoder::peek_begin_string(input)
+    }
+
+    /// Reads the [`MsgType`] of a FIX message without decoding the rest of it. Only the leading
+    /// `BeginString`, `BodyLength` and `MsgType` fields are read.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error`] if the bytes do not start with the mandatory header fields, or if the
+    /// message type is not recognized.
+    pub fn peek_msg_type(input: impl AsRef<[u8]>) -> Result<MsgType, decoder::Error> {
+        decoder::peek_msg_type(input)
+    }
+
+    /// Reads the raw value of the `MsgType` (`35`) of a FIX message without decoding the rest
+    /// of it, nor parsing the message type. See
+    /// [`peek_msg_type_bytes`](decoder::peek_msg_type_bytes) for more details.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error`] if the bytes do not start with the mandatory header fields.
+    pub fn peek_msg_type_bytes(input: &[u8]) -> Result<&[u8], decoder::Error> {
+        decoder::peek_msg_type_bytes(input)
+    }
+
+    /// Reads the `MsgSeqNum` (`34`) of a FIX message without decoding the rest of it, e.g. for
+    /// cheap detection of sequence gaps. See [`peek_seq_num`](decoder::peek_seq_num) for more
+    /// information.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error`] if the bytes do not start with the mandatory header fields, or if the
+    /// header is malformed.
+    pub fn peek_seq_num(input: impl AsRef<[u8]>) -> Result<Option<MsgSeqNum>, decoder::Error> {
+        decoder::peek_seq_num(input)
+    }
+
+    /// Decodes a [`Message`] from given bytes, with the strictness of the decoder configured by
+    /// the given [`DecodeOptions`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error`] on invalid input.
+    pub fn decode_with(
+        input: impl AsRef<[u8]>,
+        options: &DecodeOptions,
+    ) -> Result<Self, decoder::Error> {
+        decoder::decode_with(input, options)
+    }
+}
+
+/// Generic builder for constructing [`Message`] instances.
+///
+/// The builder supports chaining calls to add header or body fields.
+/// Type-state (`IS_INIT`) tracks whether at least one body field was added,
+/// allowing [`MessageBuilder::build()`] to only be available after initialization.
+///
+/// The builder is [`Clone`] in both states, so common fields can be set up once and the builder
+/// cloned to produce many similar messages:
+/// ```
+/// use trafix_codec::message::{
+///     Message,
+///     field::{
+///         Field,
+///         value::{begin_string::BeginString, msg_type::MsgType},
+///     },
+/// };
+///
+/// let common = Message::builder(BeginString::FIX44, MsgType::Heartbeat)
+///     .with_header(Field::SenderCompID(b"SENDER".into()))
+///     .with_header(Field::TargetCompID(b"TARGET".into()));
+///
+/// let first = common.clone().with_field(Field::MsgSeqNum(1)).build();
+/// let second = common.with_field(Field::MsgSeqNum(2)).build();
+/// ```
+#[derive(Clone)]
+pub struct MessageBuilder<const IS_INIT: bool> {
+    /// The message being constructed.
+    inner: Message,
+
+    /// Whether body fields are sorted by tag on [`build`](MessageBuilder::build).
+    canonical: bool,
+}
+
+impl<const IS_INIT: bool> MessageBuilder<IS_INIT> {
+    /// Adds a field to the message header.
+    #[must_use]
+    pub fn with_header(mut self, field: Field) -> Self {
+        self.inner.header.fields.push(field);
+
+        self
+    }
+
+    /// Sets the `SendingTime` (`52`) of the message header to the given timestamp, replacing
+    /// any `SendingTime` set before. The timestamp is encoded verbatim, so that messages built
+    /// with a fixed time, e.g. in tests or when replaying, encode to the same bytes every time.
+    #[must_use]
+    pub fn with_sending_time(mut self, sending_time: SendingTime) -> Self {
+        self.inner
+            .header
+            .fields
+            .retain(|field| !matches!(field, Field::SendingTime(_)));
+        self.inner
+            .header
+            .fields
+            .push(Field::SendingTime(sending_time));
+
+        self
+    }
+
+    /// Adds a field to the message body.
+    ///
+    /// Each call appends a new [`Field`] in order of insertion.
+    /// Once at least one field has been added, the builder transitions
+    /// to an initialized state, enabling [`build`](Self::build).
+    #[must_use]
+    pub fn with_field(mut self, field: Field) -> MessageBuilder<true> {
+        self.inner.body.fields.push(field);
+
+        MessageBuilder {
+            inner: self.inner,
+            canonical: self.canonical,
+        }
+    }
+
+    /// Makes [`build`](MessageBuilder::build) sort the body fields by tag, so that the encoded
+    /// message does not depend on the order in which fields were added. Fields with the same
+    /// tag keep their relative order.
+    ///
+    /// This is meant for reproducible output, e.g. in golden-file or snapshot tests. It must
+    /// not be used for messages where field order is meaningful, such as messages containing
+    /// repeating groups, as sorting would break the group structure.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::{
+    ///     Message,
+    ///     field::{
+    ///         Field,
+    ///         value::{begin_string::BeginString, msg_type::MsgType},
+    ///     },
+    /// };
+    ///
+    /// let first = Message::builder(BeginString::FIX44, MsgType::Heartbeat)
+    ///     .canonical()
+    ///     .with_field(Field::PnL(5))
+    ///     .with_field(Field::MsgSeqNum(1))
+    ///     .build();
+    ///
+    /// let second = Message::builder(BeginString::FIX44, MsgType::Heartbeat)
+    ///     .canonical()
+    ///     .with_field(Field::MsgSeqNum(1))
+    ///     .with_field(Field::PnL(5))
+    ///     .build();
+    ///
+    /// assert_eq!(first.encode(), second.encode());
+    /// ```
+    #[must_use]
+    pub fn canonical(mut self) -> Self {
+        self.canonical = true;
+
+        self
+    }
+
+    /// Adds a pre-encoded `tag=value` field (without the SOH delimiter) to the message body.
+    ///
+    /// The bytes are only checked for a valid layout and are stored as a [`Field::Raw`], which
+    /// is copied into the encoded message as given, without being parsed into a typed [`Field`].
+    /// This is useful for splicing in cached fields that never change.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::{
+    ///     Message,
+    ///     field::value::{begin_string::BeginString, msg_type::MsgType},
+    /// };
+    ///
+    /// let msg = Message::builder(BeginString::FIX44, MsgType::Logout)
+    ///     .with_raw_field(b"58=Bye")
+    ///     .unwrap()
+    ///     .build();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RawFieldError`] if the bytes do not form exactly one `tag=value` field.
+    pub fn with_raw_field(self, bytes: &[u8]) -> Result<MessageBuilder<true>, RawFieldError> {
+        RawField::new(bytes).map(|raw| self.with_field(Field::Raw(raw)))
+    }
+}
+
+/// Possible errors when adding pre-encoded fields to a [`MessageBuilder`].
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum RawFieldError {
+    /// Field does not contain exactly one equals ('=') character.
+    #[error("raw field must contain exactly one '=' character")]
+    Equals,
+
+    /// Tag of the field is empty, or not a valid numeric tag.
+    #[error("raw field contains invalid tag")]
+    BadTag,
+
+    /// Field contains the SOH delimiter.
+    #[error("raw field must not contain the SOH delimiter")]
+    ContainsSoh,
+}
+
+/// Possible errors when creating a reply to a received [`Message`], e.g. with
+/// [`Message::logout_reply`].
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ReplyError {
+    /// The received message is not a `Logon` (`35=A`).
+    #[error("message is not a logon")]
+    NotLogon,
+
+    /// The received message lacks the comp ID field with the given tag, so the reply cannot be
+    /// addressed.
+    #[error("message lacks the comp id with tag {0}")]
+    MissingCompID(u32),
+}
+
+/// Possible errors when finalizing a [`MessageBuilder`] with
+/// [`try_build`](MessageBuilder::try_build).
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum BuildError {
+    /// A field uses one of the framing tags (`8`, `9`, `10` or `35`), which the encoder emits on
+    /// its own, so the encoded message would contain the tag twice.
+    #[error("field with reserved tag {0} must not be added to the message")]
+    ReservedTag(u32),
+
+    /// A [`Field::Custom`] carries a value that does not parse into the type of the predefined
+    /// field with the same tag.
+    #[error("field with tag {0} has an invalid value")]
+    InvalidValue(u32),
+
+    /// A required header field is missing from the message.
+    #[error("required header field with tag {0} is missing")]
+    MissingHeaderField(u32),
+}
+
+impl MessageBuilder<true> {
+    /// Finalizes and returns the fully constructed [`Message`].
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::{
+    ///     Message,
+    ///     field::{
+    ///         Field,
+    ///         value::{begin_string::BeginString, msg_type::MsgType},
+    ///     },
+    /// };
+    ///
+    /// let msg = Message::builder(BeginString::FIX44, MsgType::Logout)
+    ///     .with_field(Field::Custom { tag: 58, value: b"Bye".to_vec() })
+    ///     .build();
+    /// ```
+    #[must_use]
+    pub fn build(mut self) -> Message {
+        if self.canonical {
+            // stable sort, so repeated tags keep their relative order
+            self.inner.body.fields.sort_by_key(Field::tag);
+        }
+
+        self.inner
+    }
+
+    /// Same as [`build`](MessageBuilder::build), but validates the message first, so that a
+    /// structurally invalid message is caught before it is encoded. The message is rejected if
+    ///
+    /// - a field uses one of the framing tags (`8`, `9`, `10` or `35`), e.g. a
+    ///   [`Field::Custom`] with tag `8`,
+    /// - a [`Field::Custom`] or [`Field::Raw`] carries a value that is not valid for the typed
+    ///   field with the same tag, e.g. an unknown `Side` (`54`),
+    /// - any of the required header fields `SenderCompID` (`49`), `TargetCompID` (`56`),
+    ///   `MsgSeqNum` (`34`) and `SendingTime` (`52`) is missing.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::{
+    ///     BuildError, Message,
+    ///     field::{
+    ///         Field,
+    ///         value::{begin_string::BeginString, msg_type::MsgType},
+    ///     },
+    /// };
+    ///
+    /// let result = Message::builder(BeginString::FIX44, MsgType::Logout)
+    ///     .with_field(Field::Custom { tag: 8, value: b"FIX.4.2".to_vec() })
+    ///     .try_build();
+    ///
+    /// assert!(matches!(result, Err(BuildError::ReservedTag(8))));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BuildError`] describing the first check the message failed, in the order
+    /// listed above.
+    pub fn try_build(self) -> Result<Message, BuildError> {
+        let message = self.build();
+
+        if let Some(tag) = encoder::find_reserved_tag(&message.header, &message.body) {
+            return Err(BuildError::ReservedTag(tag));
+        }
+
+        for field in message.fields() {
+            if matches!(field, Field::Custom { .. } | Field::Raw(_))
+                && Field::try_new(field.tag(), &field.value_ref()).is_err()
+            {
+                return Err(BuildError::InvalidValue(field.tag()));
+            }
+        }
+
+        let present = message.present_tags();
+
+        if let Some(tag) = constants::REQUIRED_HEADER_TAGS
+            .iter()
+            .find(|tag| !present.contains(tag))
+        {
+            return Err(BuildError::MissingHeaderField(*tag));
+        }
+
+        Ok(message)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use crate::message::{
+        BuildError, Message, RawFieldError, ReplyError,
+        field::{
+            Field,
+            raw::RawField,
+            value::{
+                FromFixBytes, begin_string::BeginString,
+                business_reject_reason::BusinessRejectReason, encrypt_method::EncryptMethod,
+                fix_decimal::FixDecimal, local_mkt_date::LocalMktDate, msg_type::MsgType,
+                side::Side, subscription_request_type::SubscriptionRequestType,
+                utc_timestamp::UtcTimestamp,
+            },
+        },
+    };
+
+    #[test]
+    fn basic_builder() {
+        let builder = Message::builder(BeginString::FIX44, MsgType::Logon);
+
+        // header
+        assert_eq!(builder.inner.header.begin_string, BeginString::FIX44);
+        assert_eq!(builder.inner.header.msg_type, MsgType::Logon);
+
+        // body
+        assert_eq!(builder.inner.body.fields.len(), 0);
+    }
+
+    #[test]
+    fn simple_message() {
+        let builder = Message::builder(BeginString::FIX44, MsgType::Logout);
+
+        let custom_header_field = Field::Custom {
+            tag: 22,
+            value: b"custom_header_field".to_vec(),
+        };
+
+        let custom_body_field1 = Field::Custom {
+            tag: 40000,
+            value: b"custom_body_field1".to_vec(),
+        };
+
+        let custom_body_field2 = Field::Custom {
+            tag: 50000,
+            value: b"custom_body_field2".to_vec(),
+        };
+
+        let msg = builder
+            .with_header(custom_header_field.clone())
+            .with_field(custom_body_field1.clone())
+            .with_field(custom_body_field2.clone())
+            .build();
+
+        // auto-header
+        assert_eq!(msg.header.begin_string, BeginString::FIX44);
+        assert_eq!(msg.header.msg_type, MsgType::Logout);
+
+        // custom header
+        assert_eq!(msg.header.fields.clone().len(), 1);
+        assert_eq!(msg.header.fields[0], custom_header_field);
 
         // body
         assert_eq!(msg.body.fields.len(), 2);
         assert_eq!(msg.body.fields[0], custom_body_field1);
         assert_eq!(msg.body.fields[1], custom_body_field2);
     }
+
+    #[test]
+    fn signed_field_round_trip() {
+        let encoded = Message::builder(BeginString::FIX44, MsgType::Heartbeat)
+            .with_field(Field::PnL(-1500))
+            .build()
+            .encode();
+
+        let msg = Message::decode(&encoded).expect("encoded message is valid");
+
+        assert_eq!(msg.body.fields, vec![Field::PnL(-1500)]);
+    }
+
+    #[test]
+    fn builder_reuse() {
+        let common = Message::builder(BeginString::FIX44, MsgType::Heartbeat)
+            .with_header(Field::SenderCompID(b"SENDER".into()));
+
+        let first = common.clone().with_field(Field::MsgSeqNum(1));
+        let second = common.with_field(Field::MsgSeqNum(2));
+
+        // initialized builders can be cloned as well
+        let third = second.clone().with_field(Field::PnL(3)).build();
+
+        let first = first.build();
+        let second = second.build();
+
+        assert_eq!(first.header.fields, second.header.fields);
+        assert_eq!(first.body.fields, vec![Field::MsgSeqNum(1)]);
+        assert_eq!(second.body.fields, vec![Field::MsgSeqNum(2)]);
+        assert_eq!(third.body.fields, vec![Field::MsgSeqNum(2), Field::PnL(3)]);
+
+        // cloned messages encode identically
+        assert_eq!(third.clone().encode(), third.encode());
+    }
+
+    #[test]
+    fn try_build() {
+        let date = LocalMktDate::new(2018, 9, 20).unwrap();
+        let builder = Message::builder(BeginString::FIX44, MsgType::Heartbeat)
+            .with_header(Field::SenderCompID(b"SENDER".into()))
+            .with_header(Field::TargetCompID(b"TARGET".into()))
+            .with_header(Field::MsgSeqNum(1))
+            .with_field(Field::SendingTime(
+                UtcTimestamp::new(date, 18, 14, 19).unwrap(),
+            ));
+
+        assert!(builder.clone().try_build().is_ok());
+
+        let result = builder
+            .clone()
+            .with_field(Field::Custom {
+                tag: 35,
+                value: b"0".to_vec(),
+            })
+            .try_build();
+        assert_eq!(result.unwrap_err(), BuildError::ReservedTag(35));
+
+        let result = builder
+            .clone()
+            .with_field(Field::Custom {
+                tag: 54,
+                value: b"Z".to_vec(),
+            })
+            .try_build();
+        assert_eq!(result.unwrap_err(), BuildError::InvalidValue(54));
+
+        // custom fields with valid values pass
+        let result = builder
+            .with_field(Field::Custom {
+                tag: 54,
+                value: b"1".to_vec(),
+            })
+            .try_build();
+        assert!(result.is_ok());
+
+        let result = Message::builder(BeginString::FIX44, MsgType::Heartbeat)
+            .with_header(Field::SenderCompID(b"SENDER".into()))
+            .with_field(Field::MsgSeqNum(1))
+            .try_build();
+        assert_eq!(result.unwrap_err(), BuildError::MissingHeaderField(56));
+    }
+
+    #[test]
+    fn logout_reply() {
+        let logon =
+            Message::minimal_logon(BeginString::FIX44, b"CLIENT".into(), b"VENUE".into(), 30);
+
+        let logout = Message::logout_reply(&logon, b"invalid credentials").unwrap();
+
+        assert_eq!(logout.begin_string(), BeginString::FIX44);
+        assert_eq!(logout.msg_type(), &MsgType::Logout);
+
+        let humanized = String::from_utf8_lossy(&logout.encode()).replace('\x01', "|");
+        insta::assert_snapshot!(humanized, @"8=FIX.4.4|9=47|35=5|49=VENUE|56=CLIENT|58=invalid credentials|10=180|");
+
+        let heartbeat = Message::builder(BeginString::FIX44, MsgType::Heartbeat)
+            .with_header(Field::SenderCompID(b"CLIENT".into()))
+            .with_header(Field::TargetCompID(b"VENUE".into()))
+            .with_field(Field::MsgSeqNum(2))
+            .build();
+        assert_eq!(
+            Message::logout_reply(&heartbeat, b"Bye").unwrap_err(),
+            ReplyError::NotLogon
+        );
+
+        let logon = Message::builder(BeginString::FIX44, MsgType::Logon)
+            .with_header(Field::SenderCompID(b"CLIENT".into()))
+            .with_field(Field::HeartBtInt(30))
+            .build();
+        assert_eq!(
+            Message::logout_reply(&logon, b"Bye").unwrap_err(),
+            ReplyError::MissingCompID(56)
+        );
+    }
+
+    #[test]
+    fn raw_field() {
+        let msg = Message::builder(BeginString::FIX44, MsgType::Logout)
+            .with_field(Field::PnL(-5))
+            .with_raw_field(b"58=Bye")
+            .unwrap()
+            .build();
+
+        assert_eq!(
+            msg.body.fields[1],
+            Field::Raw(RawField::new(b"58=Bye").unwrap())
+        );
+        assert_eq!(msg.body.fields[1].value(), b"Bye");
+
+        // the bytes are emitted as given, without formatting the tag again
+        let encoded = Message::builder(BeginString::FIX44, MsgType::Logout)
+            .with_raw_field(b"0058=Bye")
+            .unwrap()
+            .build()
+            .encode();
+        assert!(encoded.windows(9).any(|window| window == b"\x010058=Bye"));
+
+        let builder = Message::builder(BeginString::FIX44, MsgType::Logout);
+
+        for (input, error) in [
+            (&b"58"[..], RawFieldError::Equals),
+            (b"58=a=b", RawFieldError::Equals),
+            (b"=Bye", RawFieldError::BadTag),
+            (b"5a=Bye", RawFieldError::BadTag),
+            (b"4294967296=Bye", RawFieldError::BadTag),
+            (b"58=Bye\x01", RawFieldError::ContainsSoh),
+        ] {
+            assert_eq!(
+                builder.clone().with_raw_field(input).err(),
+                Some(error),
+                "{}",
+                String::from_utf8_lossy(input)
+            );
+        }
+    }
+
+    #[test]
+    fn business_reject() {
+        let encoded = Message::business_reject(
+            BeginString::FIX44,
+            MsgType::DontKnowTrade,
+            BusinessRejectReason::UnsupportedMessageType,
+            Some(b"DK not supported".to_vec()),
+        )
+        .encode();
+
+        let msg = Message::decode(&encoded).expect("encoded message is valid");
+
+        assert_eq!(msg.header.msg_type, MsgType::BusinessMessageReject);
+        assert_eq!(
+            msg.body.fields,
+            vec![
+                Field::RefMsgType(MsgType::DontKnowTrade),
+                Field::BusinessRejectReason(BusinessRejectReason::UnsupportedMessageType),
+                Field::Text(b"DK not supported".to_vec()),
+            ]
+        );
+    }
+
+    #[test]
+    fn market_data_request() {
+        let encoded = Message::market_data_request(
+            BeginString::FIX44,
+            b"REQ-1".to_vec(),
+            SubscriptionRequestType::Subscribe,
+            1,
+            [b"MSFT".to_vec(), b"AAPL".to_vec()],
+        )
+        .encode();
+
+        let msg = Message::decode(&encoded).expect("encoded message is valid");
+
+        assert_eq!(msg.header.msg_type, MsgType::MarketDataRequest);
+        assert_eq!(
+            msg.body.fields,
+            vec![
+                Field::MDReqID(b"REQ-1".to_vec()),
+                Field::SubscriptionRequestType(SubscriptionRequestType::Subscribe),
+                Field::MarketDepth(1),
+                Field::NoRelatedSym(2),
+                Field::Symbol(b"MSFT".to_vec()),
+                Field::Symbol(b"AAPL".to_vec()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_req_id_round_trip() {
+        let test_request = Message::builder(BeginString::FIX44, MsgType::TestRequest)
+            .with_field(Field::TestReqID(b"PING-1".to_vec()))
+            .build()
+            .encode();
+
+        let test_request = Message::decode(test_request).expect("encoded message is valid");
+        let test_req_id = test_request.test_req_id().expect("test request carries id");
+
+        let heartbeat = Message::builder(BeginString::FIX44, MsgType::Heartbeat)
+            .with_field(Field::TestReqID(test_req_id.clone()))
+            .build()
+            .encode();
+
+        let heartbeat = Message::decode(heartbeat).expect("encoded message is valid");
+
+        assert_eq!(heartbeat.msg_type(), &MsgType::Heartbeat);
+        assert_eq!(heartbeat.test_req_id(), Some(&b"PING-1".to_vec()));
+
+        let logout = Message::builder(BeginString::FIX44, MsgType::Logout)
+            .with_field(Field::MsgSeqNum(1))
+            .build();
+
+        assert_eq!(logout.test_req_id(), None);
+    }
+
+    #[test]
+    fn custom_msg_type_round_trip() {
+        let msg = Message::builder(BeginString::FIX44, MsgType::Custom(b"U1".to_vec()))
+            .with_field(Field::MsgSeqNum(7))
+            .build();
+
+        let encoded = msg.clone().encode();
+        assert!(encoded.windows(6).any(|window| window == b"\x0135=U1"));
+
+        let decoded = Message::decode(encoded).expect("encoded message is valid");
+
+        assert_eq!(decoded.msg_type(), &MsgType::Custom(b"U1".to_vec()));
+        assert_eq!(decoded.encode(), msg.encode());
+
+        // a custom wire value of a dedicated variant decodes into that variant, and equals it
+        let msg = Message::builder(BeginString::FIX44, MsgType::Custom(b"AE".to_vec()))
+            .with_field(Field::MsgSeqNum(7))
+            .build();
+
+        let decoded = Message::decode(msg.clone().encode()).expect("encoded message is valid");
+
+        assert_eq!(decoded.msg_type(), &MsgType::TradeCaptureReport);
+        assert_eq!(decoded.msg_type(), msg.msg_type());
+        assert_eq!(decoded.encode(), msg.encode());
+    }
+
+    #[test]
+    fn xml_data_round_trip() {
+        let input = "8=FIX.4.4\x019=24\x0135=0\x01212=8\x01213=<x>\x01</x>\x0110=199\x01";
+
+        let msg = Message::decode(input).expect("message with embedded SOH is valid");
+
+        assert_eq!(msg.xml_data(), Some(&b"<x>\x01</x>".to_vec()));
+        assert_eq!(msg.encode(), input.as_bytes());
+
+        // a length that does not match the payload is rejected
+        let input = "8=FIX.4.4\x019=24\x0135=0\x01212=7\x01213=<x>\x01</x>\x0110=198\x01";
+        assert!(Message::decode(input).is_err());
+    }
+
+    #[test]
+    fn logon_negotiation_fields() {
+        let msg = Message::decode(
+            "8=FIX.4.4\x019=32\x0135=A\x0198=0\x01108=30\x01383=8192\x01464=Y\x0110=226\x01",
+        )
+        .expect("logon is valid");
+
+        assert_eq!(msg.max_message_size(), Some(8192));
+        assert_eq!(msg.test_message_indicator(), Some(true));
+
+        let msg = Message::builder(BeginString::FIX44, MsgType::Logon)
+            .with_field(Field::TestMessageIndicator(false))
+            .build();
+
+        assert_eq!(msg.max_message_size(), None);
+        assert_eq!(msg.test_message_indicator(), Some(false));
+    }
+
+    #[test]
+    fn order_cancel_request() {
+        let date = LocalMktDate::new(2018, 9, 20).unwrap();
+        let transact_time = UtcTimestamp::new(date, 18, 14, 19).unwrap();
+
+        let msg = Message::order_cancel_request(
+            BeginString::FIX44,
+            b"ORDER-1".to_vec(),
+            b"CANCEL-1".to_vec(),
+            b"MSFT".to_vec(),
+            Side::Sell,
+            FixDecimal::new(7000, 0),
+            transact_time,
+        );
+
+        let encoded = msg.encode();
+        let humanized = String::from_utf8_lossy(&encoded).replace('\x01', "|");
+
+        insta::assert_snapshot!(humanized, @"8=FIX.4.4|9=70|35=F|41=ORDER-1|11=CANCEL-1|55=MSFT|54=2|60=20180920-18:14:19|38=7000|10=074|");
+
+        let decoded = Message::decode(encoded).expect("encoded message is valid");
+
+        assert_eq!(decoded.msg_type(), &MsgType::OrderCancelRequest);
+        assert!(
+            decoded
+                .fields()
+                .any(|field| field == &Field::OrigClOrdID(b"ORDER-1".to_vec()))
+        );
+        assert!(
+            decoded
+                .fields()
+                .any(|field| field == &Field::ClOrdID(b"CANCEL-1".to_vec()))
+        );
+    }
+
+    #[test]
+    fn present_tags() {
+        let msg = Message::builder(BeginString::FIX44, MsgType::Logon)
+            .with_header(Field::SenderCompID(b"SENDER".into()))
+            .with_field(Field::HeartBtInt(30))
+            .with_field(Field::Custom {
+                tag: 9000,
+                value: b"x".to_vec(),
+            })
+            .with_field(Field::Custom {
+                tag: 9000,
+                value: b"y".to_vec(),
+            })
+            .build();
+
+        assert_eq!(msg.present_tags(), HashSet::from([49, 108, 9000]));
+    }
+
+    #[test]
+    fn custom_tags() {
+        let msg = Message::builder(BeginString::FIX44, MsgType::Logon)
+            .with_header(Field::Custom {
+                tag: 1128,
+                value: b"9".to_vec(),
+            })
+            .with_field(Field::HeartBtInt(30))
+            .with_field(Field::Custom {
+                tag: 9000,
+                value: b"x".to_vec(),
+            })
+            .with_field(Field::Custom {
+                tag: 9000,
+                value: b"y".to_vec(),
+            })
+            .build();
+
+        assert_eq!(
+            msg.custom_tags().collect::<Vec<_>>(),
+            vec![1128, 9000, 9000]
+        );
+
+        let msg = Message::builder(BeginString::FIX44, MsgType::Heartbeat)
+            .with_field(Field::MsgSeqNum(1))
+            .build();
+
+        assert_eq!(msg.custom_tags().count(), 0);
+    }
+
+    #[test]
+    fn normalize() {
+        let input = "8=FIX.4.4\x019=94\x0135=D\x0155=MSFT\x01453=2\x01448=B\x01452=3\x01448=A\x01452=1\x0111=X\x0152=20180920-18:14:19\x0149=S\x0134=1\x0156=T\x0158=a\x0158=b\x0110=040\x01";
+
+        let mut msg = Message::decode(input).expect("message is valid");
+        msg.normalize();
+
+        let tags = |fields: &[Field]| fields.iter().map(Field::tag).collect::<Vec<_>>();
+
+        assert_eq!(tags(&msg.header.fields), [49, 56, 34, 52]);
+        // the parties group keeps its entries in order, repeated tags keep their order
+        assert_eq!(
+            tags(&msg.body.fields),
+            [11, 55, 58, 58, 453, 448, 452, 448, 452]
+        );
+        assert_eq!(msg.body.fields[2], Field::Text(b"a".to_vec()));
+        assert_eq!(msg.parties()[1].party_id, b"A");
+
+        // the normalized message is still valid, but no longer byte-exact
+        let encoded = msg.encode();
+        assert_ne!(encoded, input.as_bytes());
+        assert!(Message::decode(encoded).is_ok());
+    }
+
+    #[test]
+    fn normalize_groups() {
+        let input = "8=FIX.4.4\x019=189\x0135=D\x0111=X\x0158=t\x01146=2\x0155=MSFT\x0148=M1\x0122=4\x01167=CS\x0155=IBM\x0148=I1\x0122=4\x01627=2\x01628=H1\x01629=20180920-18:14:19\x01628=H2\x01629=20180920-18:14:20\x0149=S\x0156=T\x0134=2\x0152=20180920-18:14:21\x01453=1\x01448=A\x01452=1\x01448=B\x0110=151\x01";
+
+        let mut msg = Message::decode(input).expect("message is valid");
+        msg.normalize();
+
+        let tags = |fields: &[Field]| fields.iter().map(Field::tag).collect::<Vec<_>>();
+
+        // the hops stay together as a unit instead of being sorted by position
+        assert_eq!(
+            tags(&msg.header.fields),
+            [49, 56, 34, 52, 627, 628, 629, 628, 629]
+        );
+        // the instrument fields belong to the related symbols, and the second PartyID is beyond
+        // the NoPartyIDs count, so it does not belong to the parties
+        assert_eq!(
+            tags(&msg.body.fields),
+            [11, 58, 146, 55, 48, 22, 167, 55, 48, 22, 448, 453, 448, 452]
+        );
+        assert!(Message::decode(msg.encode()).is_ok());
+    }
+
+    #[test]
+    fn canonical_order() {
+        let msg = Message::builder(BeginString::FIX44, MsgType::Logout)
+            .canonical()
+            .with_field(Field::Text(b"one".to_vec()))
+            .with_field(Field::PnL(-5))
+            .with_field(Field::MsgSeqNum(3))
+            .with_field(Field::Text(b"two".to_vec()))
+            .build();
+
+        let encoded = msg.encode();
+        let humanized = String::from_utf8_lossy(&encoded).replace('\x01', "|");
+
+        insta::assert_snapshot!(humanized, @"8=FIX.4.4|9=33|35=5|34=3|58=one|58=two|20000=-5|10=053|");
+    }
+
+    #[test]
+    fn from_fields() {
+        let fields = vec![
+            Field::Text(b"Bye".to_vec()),
+            Field::SenderCompID(b"SENDER".into()),
+            Field::MsgSeqNum(2),
+        ];
+
+        let msg = Message::from_fields(BeginString::FIX44, MsgType::Logout, fields)
+            .expect("no reserved tags");
+
+        assert_eq!(
+            msg.header.fields,
+            vec![Field::SenderCompID(b"SENDER".into()), Field::MsgSeqNum(2)]
+        );
+        assert_eq!(msg.body.fields, vec![Field::Text(b"Bye".to_vec())]);
+
+        let fields = vec![
+            Field::MsgSeqNum(2),
+            Field::Custom {
+                tag: 10,
+                value: b"000".to_vec(),
+            },
+        ];
+
+        assert_eq!(
+            Message::from_fields(BeginString::FIX44, MsgType::Logout, fields).unwrap_err(),
+            BuildError::ReservedTag(10)
+        );
+    }
+
+    #[test]
+    fn fixed_sending_time() {
+        let sending_time = UtcTimestamp::from_fix_bytes(b"20180920-18:14:19.508").unwrap();
+
+        let logon = |sending_time: UtcTimestamp| {
+            Message::builder(BeginString::FIX44, MsgType::Logon)
+                .with_header(Field::SenderCompID(b"SENDER".into()))
+                .with_header(Field::TargetCompID(b"TARGET".into()))
+                .with_header(Field::MsgSeqNum(1))
+                .with_sending_time(sending_time)
+                .with_field(Field::EncryptMethod(EncryptMethod::None))
+                .with_field(Field::HeartBtInt(30))
+                .build()
+                .encode()
+        };
+
+        let encoded = logon(sending_time);
+        let humanized = String::from_utf8_lossy(&encoded).replace('\x01', "|");
+
+        insta::assert_snapshot!(humanized, @"8=FIX.4.4|9=67|35=A|49=SENDER|56=TARGET|34=1|52=20180920-18:14:19.508|98=0|108=30|10=150|");
+
+        // encoding is reproducible
+        assert_eq!(logon(sending_time), encoded);
+    }
+
+    #[test]
+    fn logon_credentials() {
+        let logon = Message::logon(
+            BeginString::FIX44,
+            b"SENDER".into(),
+            b"TARGET".into(),
+            30,
+            Some(b"trader".to_vec()),
+            Some(b"secret".into()),
+        );
+
+        let encoded = logon.encode();
+        let humanized = String::from_utf8_lossy(&encoded).replace('\x01', "|");
+
+        insta::assert_snapshot!(humanized, @"8=FIX.4.4|9=59|35=A|49=SENDER|56=TARGET|98=0|108=30|553=trader|554=secret|10=161|");
+
+        let decoded = Message::decode(encoded).expect("encoded logon is valid");
+        assert!(
+            decoded
+                .fields()
+                .any(|field| *field == Field::Password(b"secret".into()))
+        );
+        assert!(!format!("{decoded:?}").contains("secret"));
+    }
+
+    #[test]
+    fn two_sided_quote() {
+        let quote = Message::quote(
+            BeginString::FIX44,
+            b"QUOTE-1".to_vec(),
+            b"MSFT".to_vec(),
+            Some(FixDecimal::new(10_120, 2)),
+            Some(FixDecimal::new(10_130, 2)),
+        );
+
+        let encoded = quote.clone().encode();
+        let humanized = String::from_utf8_lossy(&encoded).replace('\x01', "|");
+
+        insta::assert_snapshot!(humanized, @"8=FIX.4.4|9=47|35=S|117=QUOTE-1|55=MSFT|132=101.20|133=101.30|10=143|");
+
+        let decoded = Message::decode(encoded).expect("encoded quote is valid");
+        assert_eq!(decoded.msg_type(), &MsgType::Quote);
+        assert_eq!(decoded.body.fields, quote.body.fields);
+    }
+
+    #[test]
+    fn minimal_logon() {
+        let logon =
+            Message::minimal_logon(BeginString::FIX44, b"SENDER".into(), b"TARGET".into(), 30);
+
+        let encoded = logon.encode();
+        let humanized = String::from_utf8_lossy(&encoded).replace('\x01', "|");
+
+        insta::assert_snapshot!(humanized, @"8=FIX.4.4|9=37|35=A|49=SENDER|56=TARGET|98=0|108=30|10=222|");
+
+        let decoded = Message::decode(encoded).expect("encoded logon is valid");
+        assert_eq!(decoded.msg_type(), &MsgType::Logon);
+    }
+
+    #[test]
+    fn multi_byte_msg_type_round_trip() {
+        let msg = Message::builder(BeginString::FIX44, MsgType::TradeCaptureReport)
+            .with_field(Field::MsgSeqNum(7))
+            .build();
+
+        let encoded = msg.encode();
+        assert!(encoded.windows(6).any(|window| window == b"\x0135=AE"));
+
+        let decoded = Message::decode(&encoded).expect("encoded message is valid");
+
+        assert_eq!(decoded.msg_type(), &MsgType::TradeCaptureReport);
+        assert_eq!(decoded.encode(), encoded);
+    }
+
+    #[test]
+    fn replace_field_value() {
+        let mut msg = Message::builder(BeginString::FIX44, MsgType::Logout)
+            .with_header(Field::MsgSeqNum(4))
+            .with_field(Field::Text(b"Bye".to_vec()))
+            .with_raw_field(b"9999=raw")
+            .unwrap()
+            .build();
+
+        assert!(msg.replace_field_value(34, b"5"));
+        assert!(msg.replace_field_value(58, b"Later"));
+        assert!(msg.replace_field_value(9999, b"cooked"));
+
+        // invalid values and missing tags leave the message untouched
+        assert!(!msg.replace_field_value(34, b"-1"));
+        assert!(!msg.replace_field_value(52, b"20180920-18:14:19"));
+
+        // values containing SOH are rejected for free text and custom fields alike
+        assert!(!msg.replace_field_value(58, b"Later\x0134=6"));
+        assert!(!msg.replace_field_value(9999, b"\x01"));
+
+        assert_eq!(
+            msg.fields().cloned().collect::<Vec<_>>(),
+            vec![
+                Field::MsgSeqNum(5),
+                Field::Text(b"Later".to_vec()),
+                Field::Custom {
+                    tag: 9999,
+                    value: b"cooked".to_vec()
+                },
+            ]
+        );
+
+        let decoded = Message::decode(msg.clone().encode()).expect("encoded message is valid");
+        assert_eq!(decoded.encode(), msg.encode());
+    }
+
+    #[test]
+    fn sequence_reset_reset() {
+        let reset = Message::sequence_reset_reset(BeginString::FIX44, 100);
+        assert!(!reset.is_gap_fill());
+
+        let encoded = reset.encode();
+        let decoded = Message::decode(&encoded).expect("encoded message is valid");
+        assert!(!decoded.is_gap_fill());
+
+        let humanized = String::from_utf8_lossy(&encoded).replace('\x01', "|");
+        insta::assert_snapshot!(humanized, @"8=FIX.4.4|9=18|35=4|123=N|36=100|10=053|");
+    }
+
+    #[test]
+    fn sequence_reset() {
+        let gap_fill = Message::sequence_reset(BeginString::FIX44, 10, true);
+
+        assert_eq!(
+            gap_fill.body.fields,
+            vec![Field::GapFillFlag(true), Field::NewSeqNo(10)]
+        );
+        assert!(gap_fill.is_gap_fill());
+
+        let reset = Message::sequence_reset(BeginString::FIX44, 1, false);
+
+        assert_eq!(reset.body.fields, vec![Field::NewSeqNo(1)]);
+        assert!(!reset.is_gap_fill());
+
+        // both forms survive encoding
+        let decoded = Message::decode(gap_fill.encode()).expect("encoded message is valid");
+        assert!(decoded.is_gap_fill());
+
+        let decoded = Message::decode(reset.encode()).expect("encoded message is valid");
+        assert!(!decoded.is_gap_fill());
+
+        // an explicit 'N' flag is a hard reset as well
+        let reset = Message::builder(BeginString::FIX44, MsgType::SequenceReset)
+            .with_field(Field::GapFillFlag(false))
+            .with_field(Field::NewSeqNo(1))
+            .build();
+
+        let encoded = reset.encode();
+        assert!(encoded.windows(7).any(|window| window == b"\x01123=N\x01"));
+        assert!(!Message::decode(encoded).unwrap().is_gap_fill());
+
+        // the flag is only meaningful on a sequence reset
+        let heartbeat = Message::builder(BeginString::FIX44, MsgType::Heartbeat)
+            .with_field(Field::GapFillFlag(true))
+            .build();
+
+        assert!(!heartbeat.is_gap_fill());
+    }
 }
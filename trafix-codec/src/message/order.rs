@@ -0,0 +1,345 @@
+//! Implementation of the construction of `NewOrderSingle` (`35=D`) messages and the validation
+//! of order fields.
+
+use crate::message::{
+    Message,
+    field::{
+        Field,
+        value::{
+            aliases::{ClOrdID, MaxFloor, MinQty, OrderQty, Price, Symbol, TransactTime},
+            begin_string::BeginString,
+            msg_type::MsgType,
+            ord_type::OrdType,
+            side::Side,
+            time_in_force::TimeInForce,
+        },
+    },
+};
+
+/// Parameters of a `NewOrderSingle` (`35=D`) message, see [`Message::new_order_single`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct NewOrderParams {
+    /// Identifier of the order, assigned by the sender (`11`).
+    pub cl_ord_id: ClOrdID,
+
+    /// Ticker symbol of the instrument (`55`).
+    pub symbol: Symbol,
+
+    /// Side of the order (`54`).
+    pub side: Side,
+
+    /// Quantity of the order (`38`).
+    pub order_qty: OrderQty,
+
+    /// Type of the order (`40`).
+    pub ord_type: OrdType,
+
+    /// Price per unit of quantity (`44`), required by limit orders.
+    pub price: Option<Price>,
+
+    /// Time the order was created (`60`).
+    pub transact_time: TransactTime,
+
+    /// Minimum quantity to be executed (`110`), if any.
+    pub min_qty: Option<MinQty>,
+
+    /// Maximum quantity shown at any given time (`111`), if any, e.g. for iceberg orders.
+    pub max_floor: Option<MaxFloor>,
+}
+
+/// Possible errors when creating a `NewOrderSingle` (`35=D`) message from [`NewOrderParams`].
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum NewOrderError {
+    /// The `MinQty` (`110`) is larger than the `OrderQty` (`38`), so the order can never be
+    /// executed.
+    #[error("MinQty (110) exceeds OrderQty (38)")]
+    MinQtyExceedsOrderQty,
+
+    /// The `MaxFloor` (`111`) is larger than the `OrderQty` (`38`), which counterparties reject.
+    #[error("MaxFloor (111) exceeds OrderQty (38)")]
+    MaxFloorExceedsOrderQty,
+}
+
+/// Cross-field problem of the `TimeInForce` (`59`) of an order, as reported by
+/// [`Message::validate_time_in_force`].
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum TimeInForceError {
+    /// A Good-Till-Date order (`59=6`) lacks its `ExpireTime` (`126`).
+    #[error("Good-Till-Date order lacks ExpireTime (126)")]
+    MissingExpireTime,
+}
+
+impl NewOrderParams {
+    /// Validates that the optional quantities fit the `order_qty`, i.e. that neither the
+    /// `min_qty` nor the `max_floor` exceeds it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NewOrderError`] describing the first quantity exceeding the `order_qty`.
+    pub fn validate(&self) -> Result<(), NewOrderError> {
+        let exceeds =
+            |qty: Option<&OrderQty>| qty.is_some_and(|qty| qty.cmp_value(&self.order_qty).is_gt());
+
+        if exceeds(self.min_qty.as_ref()) {
+            return Err(NewOrderError::MinQtyExceedsOrderQty);
+        }
+
+        if exceeds(self.max_floor.as_ref()) {
+            return Err(NewOrderError::MaxFloorExceedsOrderQty);
+        }
+
+        Ok(())
+    }
+}
+
+impl Message {
+    /// Creates a `NewOrderSingle` (`35=D`) message from the given [`NewOrderParams`], after
+    /// validating them with [`NewOrderParams::validate`]. Optional parameters are only emitted
+    /// when set.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::{
+    ///     Message,
+    ///     field::value::{
+    ///         begin_string::BeginString, fix_decimal::FixDecimal, local_mkt_date::LocalMktDate,
+    ///         ord_type::OrdType, side::Side, utc_timestamp::UtcTimestamp,
+    ///     },
+    ///     order::{NewOrderError, NewOrderParams},
+    /// };
+    ///
+    /// let date = LocalMktDate::new(2018, 9, 20).unwrap();
+    ///
+    /// let mut params = NewOrderParams {
+    ///     cl_ord_id: b"ORDER-1".to_vec(),
+    ///     symbol: b"MSFT".to_vec(),
+    ///     side: Side::Buy,
+    ///     order_qty: FixDecimal::new(7000, 0),
+    ///     ord_type: OrdType::Limit,
+    ///     price: Some(FixDecimal::new(10_125, 2)),
+    ///     transact_time: UtcTimestamp::new(date, 18, 14, 19).unwrap(),
+    ///     min_qty: None,
+    ///     max_floor: Some(FixDecimal::new(500, 0)),
+    /// };
+    ///
+    /// assert!(Message::new_order_single(BeginString::FIX44, params.clone()).is_ok());
+    ///
+    /// params.max_floor = Some(FixDecimal::new(8000, 0));
+    /// assert_eq!(
+    ///     Message::new_order_single(BeginString::FIX44, params).unwrap_err(),
+    ///     NewOrderError::MaxFloorExceedsOrderQty
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NewOrderError`] if the parameters are not valid.
+    pub fn new_order_single(
+        begin_string: BeginString,
+        params: NewOrderParams,
+    ) -> Result<Message, NewOrderError> {
+        params.validate()?;
+
+        let mut builder = Message::builder(begin_string, MsgType::NewOrderSingle)
+            .with_field(Field::ClOrdID(params.cl_ord_id))
+            .with_field(Field::Symbol(params.symbol))
+            .with_field(Field::Side(params.side))
+            .with_field(Field::TransactTime(params.transact_time))
+            .with_field(Field::OrderQty(params.order_qty))
+            .with_field(Field::OrdType(params.ord_type));
+
+        if let Some(price) = params.price {
+            builder = builder.with_field(Field::Price(price));
+        }
+
+        if let Some(min_qty) = params.min_qty {
+            builder = builder.with_field(Field::MinQty(min_qty));
+        }
+
+        if let Some(max_floor) = params.max_floor {
+            builder = builder.with_field(Field::MaxFloor(max_floor));
+        }
+
+        Ok(builder.build())
+    }
+
+    /// Validates the fields conditionally required by the `TimeInForce` (`59`) of this message,
+    /// i.e. that a Good-Till-Date order (`59=6`) carries its `ExpireTime` (`126`). Messages
+    /// without a `TimeInForce` are not checked.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TimeInForceError`] if a conditionally required field is missing.
+    pub fn validate_time_in_force(&self) -> Result<(), TimeInForceError> {
+        let good_till_date = self
+            .fields()
+            .any(|field| matches!(field, Field::TimeInForce(TimeInForce::GoodTillDate)));
+
+        if good_till_date
+            && !self
+                .fields()
+                .any(|field| matches!(field, Field::ExpireTime(_)))
+        {
+            return Err(TimeInForceError::MissingExpireTime);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::message::{
+        Message,
+        field::{
+            Field,
+            value::{
+                begin_string::BeginString,
+                fix_decimal::FixDecimal,
+                local_mkt_date::LocalMktDate,
+                msg_type::MsgType,
+                ord_type::OrdType,
+                order_capacity::OrderCapacity,
+                order_restrictions::{OrderRestriction, OrderRestrictions},
+                side::Side,
+                time_in_force::TimeInForce,
+                utc_timestamp::UtcTimestamp,
+            },
+        },
+        order::{NewOrderError, NewOrderParams, TimeInForceError},
+    };
+
+    fn limit_order() -> NewOrderParams {
+        let date = LocalMktDate::new(2018, 9, 20).unwrap();
+
+        NewOrderParams {
+            cl_ord_id: b"ORDER-1".to_vec(),
+            symbol: b"MSFT".to_vec(),
+            side: Side::Buy,
+            order_qty: FixDecimal::new(7000, 0),
+            ord_type: OrdType::Limit,
+            price: Some(FixDecimal::new(10_125, 2)),
+            transact_time: UtcTimestamp::new(date, 18, 14, 19).unwrap(),
+            min_qty: None,
+            max_floor: None,
+        }
+    }
+
+    #[test]
+    fn new_order_single() {
+        let params = NewOrderParams {
+            min_qty: Some(FixDecimal::new(100, 0)),
+            max_floor: Some(FixDecimal::new(5000, 1)),
+            ..limit_order()
+        };
+
+        let encoded = Message::new_order_single(BeginString::FIX44, params)
+            .expect("params are valid")
+            .encode();
+
+        let humanized = String::from_utf8_lossy(&encoded).replace('\x01', "|");
+        insta::assert_snapshot!(humanized, @"8=FIX.4.4|9=91|35=D|11=ORDER-1|55=MSFT|54=1|60=20180920-18:14:19|38=7000|40=2|44=101.25|110=100|111=500.0|10=105|");
+
+        let msg = Message::decode(encoded).expect("encoded message is valid");
+        assert_eq!(msg.fields().count(), 9);
+    }
+
+    #[test]
+    fn quantities_exceeding_order_qty() {
+        // equal quantities are fine, regardless of the scale
+        let params = NewOrderParams {
+            min_qty: Some(FixDecimal::new(7000, 0)),
+            max_floor: Some(FixDecimal::new(70_000, 1)),
+            ..limit_order()
+        };
+        assert_eq!(params.validate(), Ok(()));
+
+        let params = NewOrderParams {
+            min_qty: Some(FixDecimal::new(70_001, 1)),
+            ..limit_order()
+        };
+        assert_eq!(
+            Message::new_order_single(BeginString::FIX44, params).unwrap_err(),
+            NewOrderError::MinQtyExceedsOrderQty
+        );
+
+        let params = NewOrderParams {
+            max_floor: Some(FixDecimal::new(7001, 0)),
+            ..limit_order()
+        };
+        assert_eq!(
+            Message::new_order_single(BeginString::FIX44, params).unwrap_err(),
+            NewOrderError::MaxFloorExceedsOrderQty
+        );
+    }
+
+    #[test]
+    fn good_till_date() {
+        let date = LocalMktDate::new(2018, 9, 21).unwrap();
+        let order = Message::builder(BeginString::FIX44, MsgType::NewOrderSingle)
+            .with_field(Field::ClOrdID(b"ORDER-1".to_vec()))
+            .with_field(Field::TimeInForce(TimeInForce::GoodTillDate));
+
+        let without = order.clone().build();
+        assert_eq!(
+            without.validate_time_in_force(),
+            Err(TimeInForceError::MissingExpireTime)
+        );
+
+        let with = order
+            .with_field(Field::ExpireTime(
+                UtcTimestamp::new(date, 20, 0, 0).unwrap(),
+            ))
+            .build();
+        assert_eq!(with.validate_time_in_force(), Ok(()));
+
+        // the expire time survives a round trip
+        let decoded = Message::decode(with.encode()).expect("encoded message is valid");
+        assert_eq!(decoded.validate_time_in_force(), Ok(()));
+
+        // other orders do not require an expire time
+        let day = Message::builder(BeginString::FIX44, MsgType::NewOrderSingle)
+            .with_field(Field::TimeInForce(TimeInForce::Day))
+            .build();
+        assert_eq!(day.validate_time_in_force(), Ok(()));
+    }
+
+    #[test]
+    fn regulatory_fields() {
+        let input = "8=FIX.4.4\x019=28\x0135=D\x0111=ORDER-1\x01528=A\x01529=4\x0110=150\x01";
+        let msg = Message::decode(input).expect("single restriction is valid");
+
+        assert_eq!(
+            msg.body.fields[1..],
+            [
+                Field::OrderCapacity(OrderCapacity::Agency),
+                Field::OrderRestrictions(
+                    OrderRestrictions::new([OrderRestriction::CompetingMarketMaker]).unwrap()
+                ),
+            ]
+        );
+
+        let input = "8=FIX.4.4\x019=32\x0135=D\x0111=ORDER-2\x01528=R\x01529=1 7 A\x0110=088\x01";
+        let msg = Message::decode(input).expect("multiple restrictions are valid");
+
+        assert_eq!(
+            msg.body.fields[1..],
+            [
+                Field::OrderCapacity(OrderCapacity::RisklessPrincipal),
+                Field::OrderRestrictions(
+                    OrderRestrictions::new([
+                        OrderRestriction::ProgramTrade,
+                        OrderRestriction::ForeignEntity,
+                        OrderRestriction::RisklessArbitrage,
+                    ])
+                    .unwrap()
+                ),
+            ]
+        );
+        assert_eq!(msg.encode(), input.as_bytes());
+
+        // each restriction must be known
+        let input = "8=FIX.4.4\x019=32\x0135=D\x0111=ORDER-2\x01528=R\x01529=1 7 Z\x0110=113\x01";
+        assert!(Message::decode(input).is_err());
+    }
+}
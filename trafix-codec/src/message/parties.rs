@@ -0,0 +1,195 @@
+//! Implementation of structured access to the parties (`453`) repeating group.
+
+use crate::message::{
+    Message,
+    field::{
+        Field,
+        value::{
+            aliases::{PartyID, PartySubID, PartySubIDType},
+            party_id_source::PartyIDSource,
+            party_role::PartyRole,
+        },
+    },
+};
+
+/// A single entry of the parties repeating group, identifying a party involved in an order or
+/// trade, e.g. the executing firm or the client.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Party {
+    /// Identifier of the party (`448`).
+    pub party_id: PartyID,
+
+    /// Identification scheme of the `party_id` (`447`), if given.
+    pub party_id_source: Option<PartyIDSource>,
+
+    /// Role of the party (`452`), if given.
+    pub party_role: Option<PartyRole>,
+
+    /// Entries of the party sub-identifiers repeating group (`802`) nested in this party.
+    pub sub_ids: Vec<PartySub>,
+}
+
+/// A single entry of the party sub-identifiers repeating group, nested in a [`Party`], e.g.
+/// the desk or the contact of a firm.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartySub {
+    /// Sub-identifier of the party (`523`).
+    pub sub_id: PartySubID,
+
+    /// Type of the `sub_id` (`803`), if given.
+    pub sub_id_type: Option<PartySubIDType>,
+}
+
+impl Message {
+    /// Returns the entries of the parties repeating group, i.e. the `PartyID` (`448`),
+    /// `PartyIDSource` (`447`) and `PartyRole` (`452`) fields following the `NoPartyIDs`
+    /// (`453`) field, in order.
+    ///
+    /// Each entry starts with a `PartyID`, and may hold a nested party sub-identifiers group, i.e.
+    /// the `PartySubID` (`523`) and `PartySubIDType` (`803`) fields following `NoPartySubIDs`
+    /// (`802`). The group ends with the first field not belonging to it, or once the number of
+    /// entries given by `NoPartyIDs` is reached. Returns an empty [`Vec`] if this message has no
+    /// parties.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::{Message, field::value::party_role::PartyRole};
+    ///
+    /// let input = "8=FIX.4.4\x019=32\x0135=D\x01453=1\x01448=FIRM\x01447=D\x01452=1\x0110=041\x01";
+    /// let parties = Message::decode(input).unwrap().parties();
+    ///
+    /// assert_eq!(parties[0].party_id, b"FIRM");
+    /// assert_eq!(parties[0].party_role, Some(PartyRole::ExecutingFirm));
+    /// ```
+    #[must_use]
+    pub fn parties(&self) -> Vec<Party> {
+        let mut fields = self
+            .fields()
+            .skip_while(|field| !matches!(field, Field::NoPartyIDs(_)));
+
+        let Some(Field::NoPartyIDs(count)) = fields.next() else {
+            return Vec::new();
+        };
+
+        let count = usize::try_from(*count).unwrap_or(usize::MAX);
+        let mut parties: Vec<Party> = Vec::new();
+        // number of sub-identifiers given by the NoPartySubIDs of the current party
+        let mut sub_count = 0;
+
+        for field in fields {
+            match field {
+                Field::PartyID(party_id) if parties.len() < count => {
+                    sub_count = 0;
+                    parties.push(Party {
+                        party_id: party_id.clone(),
+                        party_id_source: None,
+                        party_role: None,
+                        sub_ids: Vec::new(),
+                    });
+                }
+                Field::PartyIDSource(source) if let Some(party) = parties.last_mut() => {
+                    party.party_id_source = Some(*source);
+                }
+                Field::PartyRole(role) if let Some(party) = parties.last_mut() => {
+                    party.party_role = Some(*role);
+                }
+                Field::NoPartySubIDs(no_sub_ids) if !parties.is_empty() => {
+                    sub_count = usize::try_from(*no_sub_ids).unwrap_or(usize::MAX);
+                }
+                Field::PartySubID(sub_id)
+                    if let Some(party) = parties.last_mut()
+                        && party.sub_ids.len() < sub_count =>
+                {
+                    party.sub_ids.push(PartySub {
+                        sub_id: sub_id.clone(),
+                        sub_id_type: None,
+                    });
+                }
+                Field::PartySubIDType(sub_id_type)
+                    if let Some(sub) = parties
+                        .last_mut()
+                        .and_then(|party| party.sub_ids.last_mut()) =>
+                {
+                    sub.sub_id_type = Some(*sub_id_type);
+                }
+                _ => break,
+            }
+        }
+
+        parties
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::message::{
+        Message,
+        field::value::{party_id_source::PartyIDSource, party_role::PartyRole},
+        parties::{Party, PartySub},
+    };
+
+    #[test]
+    fn parties() {
+        let input = "8=FIX.4.4\x019=76\x0135=D\x0111=ORDER-1\x01453=2\x01448=FIRM\x01447=D\x01452=1\x01448=CLIENT-7\x01447=D\x01452=3\x0155=MSFT\x0110=188\x01";
+
+        let msg = Message::decode(input).expect("parties are valid");
+
+        assert_eq!(
+            msg.parties(),
+            vec![
+                Party {
+                    party_id: b"FIRM".to_vec(),
+                    party_id_source: Some(PartyIDSource::Proprietary),
+                    party_role: Some(PartyRole::ExecutingFirm),
+                    sub_ids: Vec::new(),
+                },
+                Party {
+                    party_id: b"CLIENT-7".to_vec(),
+                    party_id_source: Some(PartyIDSource::Proprietary),
+                    party_role: Some(PartyRole::ClientId),
+                    sub_ids: Vec::new(),
+                },
+            ]
+        );
+
+        // entries beyond the NoPartyIDs count do not belong to the group
+        let input = "8=FIX.4.4\x019=30\x0135=D\x01453=1\x01448=FIRM\x01448=OTHER\x0110=092\x01";
+        let parties = Message::decode(input).unwrap().parties();
+
+        assert_eq!(parties.len(), 1);
+        assert_eq!(parties[0].party_id, b"FIRM");
+    }
+
+    #[test]
+    fn nested_sub_ids() {
+        let input = "8=FIX.4.4\x019=100\x0135=D\x01453=2\x01448=FIRM\x01447=D\x01452=1\x01802=2\x01523=DESK-1\x01803=10\x01523=JDOE\x01803=9\x01448=CLIENT-7\x01452=122\x0155=MSFT\x0110=084\x01";
+
+        let parties = Message::decode(input).expect("parties are valid").parties();
+
+        assert_eq!(parties.len(), 2);
+        assert_eq!(
+            parties[0].sub_ids,
+            vec![
+                PartySub {
+                    sub_id: b"DESK-1".to_vec(),
+                    sub_id_type: Some(10),
+                },
+                PartySub {
+                    sub_id: b"JDOE".to_vec(),
+                    sub_id_type: Some(9),
+                },
+            ]
+        );
+
+        // the nested group does not end the parties group, and later roles are carried verbatim
+        assert_eq!(parties[1].party_id, b"CLIENT-7");
+        assert_eq!(parties[1].party_role, Some(PartyRole::Other(122)));
+        assert!(parties[1].sub_ids.is_empty());
+    }
+
+    #[test]
+    fn no_parties() {
+        let input = "8=FIX.4.4\x019=5\x0135=0\x0110=163\x01";
+        assert!(Message::decode(input).unwrap().parties().is_empty());
+    }
+}
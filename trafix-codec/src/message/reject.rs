@@ -0,0 +1,188 @@
+//! Implementation of structured access to session-level `Reject` (`35=3`) messages.
+
+use crate::message::{
+    Message,
+    field::{
+        Field,
+        value::{
+            aliases::{RefSeqNum, RefTagID, Text},
+            msg_type::MsgType,
+            session_reject_reason::SessionRejectReason,
+        },
+    },
+};
+
+/// Describes what a session-level `Reject` (`35=3`) message is rejecting, and why.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RejectInfo {
+    /// `MsgSeqNum` of the rejected message (`45`).
+    pub ref_seq_num: RefSeqNum,
+
+    /// Tag of the field that caused the rejection (`371`), if given.
+    pub ref_tag_id: Option<RefTagID>,
+
+    /// `MsgType` of the rejected message (`372`), if given.
+    pub ref_msg_type: Option<MsgType>,
+
+    /// Reason of the rejection (`373`), if given.
+    pub session_reject_reason: Option<SessionRejectReason>,
+
+    /// Free format explanation of the rejection (`58`), if given.
+    pub text: Option<Text>,
+}
+
+impl Message {
+    /// Returns the [`RejectInfo`] of a session-level `Reject` (`35=3`) message.
+    ///
+    /// Returns [`None`] if this message is not a `Reject`, or if it does not contain the
+    /// mandatory `RefSeqNum` (`45`) field.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::{Message, field::value::msg_type::MsgType};
+    ///
+    /// let input = "8=FIX.4.4\x019=22\x0135=3\x0145=7\x01372=A\x01373=1\x0110=218\x01";
+    /// let info = Message::decode(input).unwrap().reject_info().unwrap();
+    ///
+    /// assert_eq!(info.ref_seq_num, 7);
+    /// assert_eq!(info.ref_msg_type, Some(MsgType::Logon));
+    /// ```
+    #[must_use]
+    pub fn reject_info(&self) -> Option<RejectInfo> {
+        if self.header.msg_type != MsgType::Reject {
+            return None;
+        }
+
+        let mut ref_seq_num = None;
+        let mut info = RejectInfo {
+            ref_seq_num: 0,
+            ref_tag_id: None,
+            ref_msg_type: None,
+            session_reject_reason: None,
+            text: None,
+        };
+
+        for field in self.fields() {
+            match field {
+                Field::RefSeqNum(value) => ref_seq_num = Some(*value),
+                Field::RefTagID(value) => info.ref_tag_id = Some(*value),
+                Field::RefMsgType(value) => info.ref_msg_type = Some(value.clone()),
+                Field::SessionRejectReason(value) => info.session_reject_reason = Some(*value),
+                Field::Text(value) => info.text = Some(value.clone()),
+                _ => {}
+            }
+        }
+
+        Some(RejectInfo {
+            ref_seq_num: ref_seq_num?,
+            ..info
+        })
+    }
+
+    /// Returns the FIX name of the field referenced by the `RefTagID` (`371`) of a session-level
+    /// `Reject` (`35=3`) message, e.g. `SenderCompID` for `371=49`.
+    ///
+    /// Returns [`None`] if this message is not a `Reject`, if it does not reference a tag, or if
+    /// the referenced tag is not known.
+    ///
+    /// Example usage:
+    /// ```
+    /// use trafix_codec::message::Message;
+    ///
+    /// let input = "8=FIX.4.4\x019=17\x0135=3\x0145=7\x01371=49\x0110=253\x01";
+    /// let rejected = Message::decode(input).unwrap().rejected_tag_name();
+    ///
+    /// assert_eq!(rejected, Some("SenderCompID"));
+    /// ```
+    #[must_use]
+    pub fn rejected_tag_name(&self) -> Option<&'static str> {
+        if self.header.msg_type != MsgType::Reject {
+            return None;
+        }
+
+        self.fields().find_map(|field| match field {
+            Field::RefTagID(tag) => Field::tag_name(*tag),
+            _ => None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::message::{
+        Message,
+        field::{
+            Field,
+            value::{
+                begin_string::BeginString, msg_type::MsgType,
+                session_reject_reason::SessionRejectReason,
+            },
+        },
+        reject::RejectInfo,
+    };
+
+    #[test]
+    fn reject_info() {
+        let encoded = Message::builder(BeginString::FIX44, MsgType::Reject)
+            .with_field(Field::MsgSeqNum(3))
+            .with_field(Field::RefSeqNum(2))
+            .with_field(Field::RefTagID(49))
+            .with_field(Field::RefMsgType(MsgType::Logon))
+            .with_field(Field::SessionRejectReason(
+                SessionRejectReason::CompIdProblem,
+            ))
+            .with_field(Field::Text(b"unknown sender".to_vec()))
+            .build()
+            .encode();
+
+        let msg = Message::decode(encoded).expect("encoded message is valid");
+
+        assert_eq!(
+            msg.reject_info(),
+            Some(RejectInfo {
+                ref_seq_num: 2,
+                ref_tag_id: Some(49),
+                ref_msg_type: Some(MsgType::Logon),
+                session_reject_reason: Some(SessionRejectReason::CompIdProblem),
+                text: Some(b"unknown sender".to_vec()),
+            })
+        );
+    }
+
+    #[test]
+    fn not_a_reject() {
+        let msg = Message::builder(BeginString::FIX44, MsgType::Reject)
+            .with_field(Field::MsgSeqNum(3))
+            .build();
+
+        // RefSeqNum is mandatory
+        assert_eq!(msg.reject_info(), None);
+
+        let msg = Message::builder(BeginString::FIX44, MsgType::Heartbeat)
+            .with_field(Field::RefSeqNum(2))
+            .build();
+
+        assert_eq!(msg.reject_info(), None);
+    }
+
+    #[test]
+    fn rejected_tag_name() {
+        let reject = |ref_tag_id| {
+            Message::builder(BeginString::FIX44, MsgType::Reject)
+                .with_field(Field::RefSeqNum(2))
+                .with_field(Field::RefTagID(ref_tag_id))
+                .build()
+        };
+
+        assert_eq!(reject(49).rejected_tag_name(), Some("SenderCompID"));
+        assert_eq!(reject(10).rejected_tag_name(), Some("CheckSum"));
+        assert_eq!(reject(9999).rejected_tag_name(), None);
+
+        // only rejects are looked at
+        let msg = Message::builder(BeginString::FIX44, MsgType::Heartbeat)
+            .with_field(Field::RefTagID(49))
+            .build();
+
+        assert_eq!(msg.rejected_tag_name(), None);
+    }
+}
@@ -0,0 +1,131 @@
+//! Implementation of the `SendingTime` (`52`) clock skew check, available with the `time`
+//! feature.
+
+use std::time::{Duration, SystemTime};
+
+use crate::message::{Message, field::Field};
+
+/// Possible errors when checking the `SendingTime` (`52`) of a message against the clock.
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum Error {
+    /// Message does not contain a `SendingTime` (`52`) field.
+    #[error("message does not contain a SendingTime (52)")]
+    MissingSendingTime,
+
+    /// `SendingTime` (`52`) differs from the current time by more than the allowed skew.
+    #[error("SendingTime (52) is off by {skew:?}, exceeding the maximum skew of {max_skew:?}")]
+    SkewExceeded {
+        /// Absolute difference between the `SendingTime` and the current time.
+        skew: Duration,
+
+        /// Maximum allowed difference.
+        max_skew: Duration,
+    },
+}
+
+impl Message {
+    /// Checks that the `SendingTime` (`52`) of this message differs from the current UTC time
+    /// by at most `max_skew`, in either direction.
+    ///
+    /// Example usage:
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use trafix_codec::message::{Message, skew::Error};
+    ///
+    /// let input = "8=FIX.4.4\x019=26\x0135=0\x0152=20180920-18:14:19\x0110=234\x01";
+    /// let msg = Message::decode(input).unwrap();
+    ///
+    /// let error = msg.check_sending_time_skew(Duration::from_mins(2));
+    /// assert!(matches!(error, Err(Error::SkewExceeded { .. })));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::MissingSendingTime`] if the message has no `SendingTime`, and
+    /// [`Error::SkewExceeded`] if the difference is larger than `max_skew`.
+    pub fn check_sending_time_skew(&self, max_skew: Duration) -> Result<(), Error> {
+        self.check_sending_time_skew_at(SystemTime::now(), max_skew)
+    }
+
+    /// Checks the `SendingTime` (`52`) of this message against the given `now`.
+    fn check_sending_time_skew_at(&self, now: SystemTime, max_skew: Duration) -> Result<(), Error> {
+        let sending_time = self
+            .fields()
+            .find_map(|field| match field {
+                Field::SendingTime(sending_time) => Some(SystemTime::from(sending_time)),
+                _ => None,
+            })
+            .ok_or(Error::MissingSendingTime)?;
+
+        let skew = now
+            .duration_since(sending_time)
+            .unwrap_or_else(|error| error.duration());
+
+        if skew > max_skew {
+            return Err(Error::SkewExceeded { skew, max_skew });
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::{Duration, SystemTime};
+
+    use super::Error;
+    use crate::message::{
+        Message,
+        field::{
+            Field,
+            value::{
+                FromFixBytes, begin_string::BeginString, msg_type::MsgType,
+                utc_timestamp::UtcTimestamp,
+            },
+        },
+    };
+
+    #[test]
+    fn sending_time_skew() {
+        let sending_time = UtcTimestamp::from_fix_bytes(b"20180920-18:14:19.508").unwrap();
+        let sent_at = SystemTime::from(&sending_time);
+
+        let msg = Message::builder(BeginString::FIX44, MsgType::Logon)
+            .with_header(Field::SendingTime(sending_time))
+            .with_field(Field::MsgSeqNum(1))
+            .build();
+
+        let max_skew = Duration::from_mins(2);
+
+        for now in [
+            sent_at,
+            sent_at + max_skew,
+            sent_at - max_skew,
+            sent_at + Duration::from_secs(5),
+        ] {
+            assert_eq!(msg.check_sending_time_skew_at(now, max_skew), Ok(()));
+        }
+
+        let skew = max_skew + Duration::from_millis(1);
+
+        for now in [sent_at + skew, sent_at - skew] {
+            assert_eq!(
+                msg.check_sending_time_skew_at(now, max_skew),
+                Err(Error::SkewExceeded { skew, max_skew })
+            );
+        }
+    }
+
+    #[test]
+    fn missing_sending_time() {
+        let msg = Message::builder(BeginString::FIX44, MsgType::Heartbeat)
+            .with_field(Field::MsgSeqNum(1))
+            .build();
+
+        assert_eq!(
+            msg.check_sending_time_skew(Duration::from_mins(2)),
+            Err(Error::MissingSendingTime)
+        );
+    }
+}